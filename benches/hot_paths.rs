@@ -0,0 +1,123 @@
+//! Criterion benchmarks for the code on the key-generation hot path
+//!
+//! Run with `cargo bench`; every group reports throughput in operations per second.
+
+use autoseed::crypto::blake2_512;
+use autoseed::crypto::pbkdf2::pbkdf2_hmac_sha512;
+use autoseed::search::{search_with_config, OptimizedSearchConfig};
+use autoseed::crypto::rng::ChaCha20Rng;
+use autoseed::wallet::{generate_mnemonic, generate_mnemonic_with_rng, hex_to_address_with_prefix, mnemonic_to_mini_secret};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+
+const AUTONOMYS_PREFIX: u16 = 6094;
+
+fn bench_search_with_config(c: &mut Criterion) {
+    let address = hex_to_address_with_prefix(&[42u8; 32], AUTONOMYS_PREFIX);
+    let mut group = c.benchmark_group("search_with_config");
+    group.throughput(Throughput::Elements(1));
+
+    // Patterns that never match, so every candidate position is compared
+    for pattern in ["x", "xyz", "xyzxyz"] {
+        let len = pattern.len();
+        let modes = [
+            ("prefix", OptimizedSearchConfig::new_with_prefix(pattern, false, false, false, 5, AUTONOMYS_PREFIX)),
+            ("suffix", OptimizedSearchConfig::new_with_prefix(pattern, false, true, false, len, AUTONOMYS_PREFIX)),
+            ("anywhere", OptimizedSearchConfig::new_with_prefix(pattern, false, false, true, 49, AUTONOMYS_PREFIX)),
+        ];
+        for (mode, config) in modes {
+            group.bench_with_input(BenchmarkId::new(mode, len), &config, |b, config| {
+                b.iter(|| search_with_config(config, black_box(&address)));
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_bitap_vs_sliding(c: &mut Criterion) {
+    let address = hex_to_address_with_prefix(&[42u8; 32], AUTONOMYS_PREFIX);
+    let mut group = c.benchmark_group("anywhere_6_chars");
+    group.throughput(Throughput::Elements(1));
+
+    // A 6-character pattern that never matches, so the whole address is scanned
+    let bitap = OptimizedSearchConfig::new_with_prefix("xyzxyz", false, false, true, 49, AUTONOMYS_PREFIX);
+    let mut sliding = bitap.clone();
+    sliding.dfa = None;
+    group.bench_function("bitap", |b| b.iter(|| search_with_config(&bitap, black_box(&address))));
+    group.bench_function("sliding_window", |b| b.iter(|| search_with_config(&sliding, black_box(&address))));
+    group.finish();
+}
+
+fn bench_mnemonic_to_mini_secret(c: &mut Criterion) {
+    let mnemonic = generate_mnemonic();
+    let mut group = c.benchmark_group("mnemonic_to_mini_secret");
+    group.throughput(Throughput::Elements(1));
+
+    for rounds in [2048u32, 4096] {
+        group.bench_with_input(BenchmarkId::from_parameter(rounds), &rounds, |b, &rounds| {
+            b.iter(|| mnemonic_to_mini_secret(black_box(&mnemonic), "", rounds));
+        });
+    }
+    group.finish();
+}
+
+fn bench_hex_to_address(c: &mut Criterion) {
+    let seed = [42u8; 32];
+    let mut group = c.benchmark_group("hex_to_address_with_prefix");
+    group.throughput(Throughput::Elements(1));
+
+    // One-byte (0, 42) and two-byte (6094) SS58 prefix encodings
+    for ss58_prefix in [0u16, 42, AUTONOMYS_PREFIX] {
+        group.bench_with_input(BenchmarkId::from_parameter(ss58_prefix), &ss58_prefix, |b, &ss58_prefix| {
+            b.iter(|| hex_to_address_with_prefix(black_box(&seed), ss58_prefix));
+        });
+    }
+    group.finish();
+}
+
+fn bench_blake2_512(c: &mut Criterion) {
+    let mut group = c.benchmark_group("blake2_512");
+    group.throughput(Throughput::Elements(1));
+
+    for size in [32usize, 1024] {
+        let data = vec![0xa5u8; size];
+        group.bench_with_input(BenchmarkId::from_parameter(size), &data, |b, data| {
+            b.iter(|| blake2_512(black_box(data)));
+        });
+    }
+    group.finish();
+}
+
+fn bench_pbkdf2_hmac_sha512(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pbkdf2_hmac_sha512");
+    group.throughput(Throughput::Elements(1));
+
+    for rounds in [2048u32, 4096] {
+        group.bench_with_input(BenchmarkId::from_parameter(rounds), &rounds, |b, &rounds| {
+            let mut output = [0u8; 64];
+            b.iter(|| pbkdf2_hmac_sha512(black_box(b"entropy bytes"), b"mnemonic", rounds, &mut output));
+        });
+    }
+    group.finish();
+}
+
+fn bench_generate_mnemonic(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generate_mnemonic");
+    group.throughput(Throughput::Elements(1));
+    // Workers hold one RNG each, so seeding stays out of the measured loop
+    let mut rng = ChaCha20Rng::from_seed([42u8; 32]);
+    group.bench_function("12_words", |b| b.iter(|| generate_mnemonic_with_rng(&mut rng)));
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_search_with_config,
+    bench_bitap_vs_sliding,
+    bench_mnemonic_to_mini_secret,
+    bench_hex_to_address,
+    bench_blake2_512,
+    bench_pbkdf2_hmac_sha512,
+    bench_generate_mnemonic,
+);
+criterion_main!(benches);
@@ -0,0 +1,32 @@
+//! Generates `autoseed.h` for the C bindings when the `ffi` feature is enabled
+//!
+//! The header is written to `$OUT_DIR/autoseed.h`, and `AUTOSEED_HEADER_DIR` is set
+//! at compile time so the C test can find it.
+
+fn main() {
+    #[cfg(feature = "ffi")]
+    generate_header();
+}
+
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    use std::path::PathBuf;
+
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo");
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    let header = out_dir.join("autoseed.h");
+
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let config = cbindgen::Config::from_file(PathBuf::from(&crate_dir).join("cbindgen.toml"))
+        .expect("cbindgen.toml is valid");
+    cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("failed to generate C bindings")
+        .write_to_file(&header);
+
+    println!("cargo:rustc-env=AUTOSEED_HEADER_DIR={}", out_dir.display());
+}
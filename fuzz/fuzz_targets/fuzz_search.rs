@@ -0,0 +1,63 @@
+//! Fuzz `search_with_config` with arbitrary patterns, addresses and search options
+//!
+//! Run with `cargo +nightly fuzz run fuzz_search` from the repository root. Besides
+//! catching panics (e.g. out-of-bounds reads in `compare_bytes_at_offset`), every
+//! reported match is checked against the address byte by byte.
+
+#![no_main]
+
+use autoseed::search::{search_with_config, OptimizedSearchConfig};
+use libfuzzer_sys::arbitrary::{Result, Unstructured};
+use libfuzzer_sys::fuzz_target;
+
+/// Search inputs carved out of the raw fuzz bytes
+struct SearchInput<'a> {
+    pattern: &'a str,
+    address: &'a str,
+    case_sensitive: bool,
+    suffix: bool,
+    anywhere: bool,
+    within: usize,
+}
+
+/// Split the raw bytes: option flags and `within` first, then two length-prefixed strings
+fn split_input<'a>(u: &mut Unstructured<'a>) -> Result<SearchInput<'a>> {
+    Ok(SearchInput {
+        case_sensitive: u.arbitrary()?,
+        suffix: u.arbitrary()?,
+        anywhere: u.arbitrary()?,
+        // Past the 49-character address length is enough to exercise the clamping
+        within: u.int_in_range(0..=64)?,
+        pattern: u.arbitrary()?,
+        address: u.arbitrary()?,
+    })
+}
+
+fn pattern_matches_at(input: &SearchInput, offset: usize) -> bool {
+    let Some(window) = input.address.as_bytes().get(offset..offset + input.pattern.len()) else {
+        return false;
+    };
+    input.pattern.bytes().zip(window).all(|(p, a)| {
+        p == b'?' || if input.case_sensitive { p == *a } else { p.eq_ignore_ascii_case(a) }
+    })
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = split_input(&mut Unstructured::new(data)) else {
+        return;
+    };
+
+    let config = OptimizedSearchConfig::new(input.pattern, input.case_sensitive, input.suffix, input.anywhere, input.within);
+    let result = search_with_config(&config, input.address);
+
+    // A match covers every byte of the pattern
+    if result.count == input.pattern.len() && !input.pattern.is_empty() {
+        assert!(
+            pattern_matches_at(&input, result.offset),
+            "reported match of {:?} at {} in {:?} does not match",
+            input.pattern,
+            result.offset,
+            input.address
+        );
+    }
+});
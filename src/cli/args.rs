@@ -20,13 +20,93 @@ pub struct Config {
     pub hex_mode: bool,
     pub ss58_prefix: u16,
     pub within: usize,
-    pub output_dir: String,
+    pub within_window: Option<(usize, usize)>, // --within-start/--within-end, end exclusive
+    pub output_dir: Option<String>, // --output or the default; None when only this run's type-specific directory was given
+    pub mnemonic_output_dir: Option<String>,
+    pub json_output_dir: Option<String>,
     pub password: Option<String>,
     pub suffix: bool,
     pub anywhere: bool,
     pub term: String,
     pub threads: usize,
     pub probability: bool,
+    pub monte_carlo_samples: Option<u64>,
+    pub name_template: Option<String>,
+    pub kdf: crate::wallet::Kdf,
+    pub genesis_hash: Option<String>, // `genesisHash` in JSON wallet metadata, 0x-prefixed
+    pub pbkdf2_rounds: u32,
+    pub split: Option<(u8, u8)>,
+    pub export_raw: bool, // Also write `<file>_raw.txt` with the unencrypted keypair
+    pub show_derivation: bool, // Print entropy, salt, mini secret and public key of each found mnemonic
+    pub append_to: Option<String>, // JSON Lines file that replaces the per-wallet JSON/mnemonic files
+    pub no_save: bool, // Only display found wallets; nothing is written to disk
+    pub redact_secrets: bool, // Print a placeholder instead of each found wallet's secret
+    pub statistics: bool,
+    pub advanced_stats: bool,
+    pub histogram: bool, // Print the attempts-per-wallet distribution after the run
+    pub metrics_port: Option<u16>,
+    pub metrics_bind: std::net::IpAddr, // Address the metrics server listens on
+    pub watchdog_timeout: u64, // Seconds without new attempts before warning of a stall; 0 = off
+    pub progress_interval: u64, // Milliseconds between progress updates
+    pub quiet: bool, // Only found wallets (ADDRESS<TAB>SECRET) and errors are printed
+    pub per_thread_stats: bool, // Append each thread's keys/s to the progress line
+}
+
+impl Config {
+    /// Directory this run's wallet files go to: the directory for the wallet type
+    /// (JSON in hex mode, mnemonic otherwise) when given, else `output_dir`
+    pub fn wallet_dir(&self) -> &str {
+        let type_dir = if self.hex_mode { &self.json_output_dir } else { &self.mnemonic_output_dir };
+        type_dir
+            .as_deref()
+            .or(self.output_dir.as_deref())
+            .expect("output_dir is set when the wallet type has no directory of its own")
+    }
+
+    /// Directory the manifest and run statistics go to: `output_dir`, else the
+    /// wallet directory
+    pub fn report_dir(&self) -> &str {
+        self.output_dir.as_deref().unwrap_or(self.wallet_dir())
+    }
+
+    /// Build the search configuration, using the explicit window when one was given
+    pub fn search_config(&self) -> crate::search::OptimizedSearchConfig {
+        self.search_config_for(&self.term)
+    }
+
+    /// Parameters for running this search with `generate_vanity_addresses`
+    pub fn search_params(&self) -> crate::runner::SearchParams {
+        crate::runner::SearchParams {
+            term: self.term.clone(),
+            count: self.count,
+            case_sensitive: self.case_sensitive,
+            hex_mode: self.hex_mode,
+            pbkdf2_rounds: self.pbkdf2_rounds,
+            ss58_prefix: self.ss58_prefix,
+            within: self.within,
+            within_window: self.within_window,
+            suffix: self.suffix,
+            anywhere: self.anywhere,
+            threads: self.threads,
+            progress_interval_ms: self.progress_interval,
+            watchdog_timeout_secs: self.watchdog_timeout,
+            stop: None,
+            rng_seed: None,
+        }
+    }
+
+    /// Build the search configuration for another term with the same search options
+    pub fn search_config_for(&self, term: &str) -> crate::search::OptimizedSearchConfig {
+        crate::runner::build_search_config(
+            term,
+            self.case_sensitive,
+            self.ss58_prefix,
+            self.within,
+            self.within_window,
+            self.suffix,
+            self.anywhere,
+        )
+    }
 }
 
 fn get_default_wallet_dir() -> String {
@@ -38,6 +118,7 @@ fn show_error_with_search_params(
     suffix: bool,
     anywhere: bool,
     within: usize,
+    within_window: Option<(usize, usize)>,
     case_sensitive: bool,
     hex_mode: bool,
     threads: usize,
@@ -45,8 +126,13 @@ fn show_error_with_search_params(
     count: usize,
     error_msg: &str,
 ) {
+    // Same config the search itself would use, so the header shows the real range
+    let search_range =
+        crate::runner::build_search_config(term, case_sensitive, ss58_prefix, within, within_window, suffix, anywhere)
+            .search_range;
+
     // Show search parameters like during normal execution
-    let header_length = print_header(term, suffix, anywhere, within, case_sensitive, hex_mode, threads);
+    let header_length = print_header(term, suffix, anywhere, within, case_sensitive, hex_mode, threads, ss58_prefix, search_range);
     
     let wallet_text = if count == 1 { "wallet" } else { "wallets" };
     
@@ -58,14 +144,7 @@ fn show_error_with_search_params(
     };
     
     // Calculate and display odds
-    let expected_attempts = calculate_expected_attempts(
-        term,
-        case_sensitive,
-        suffix,
-        false, // anywhere - this is for error display, use false as default
-        within,
-        ss58_prefix,
-    );
+    let expected_attempts = calculate_expected_attempts(term, case_sensitive, search_range, ss58_prefix);
     let odds_str = expected_attempts.to_formatted_string(&SystemLocale::default().unwrap());
     
     println!("Generating {} {} for {} Expected: ~{} searches per wallet", 
@@ -76,10 +155,10 @@ fn show_error_with_search_params(
     // Parse error message to separate ERROR and SUGGESTION parts
     if let Some(suggestion_pos) = error_msg.find("SUGGESTION:") {
         let (error_part, suggestion_part) = error_msg.split_at(suggestion_pos);
-        eprintln!("{}: {}", colors::red("ERROR"), error_part.trim());
-        eprintln!("{}: {}", colors::yellow("SUGGESTION"), &suggestion_part[11..].trim()); // Skip "SUGGESTION:"
+        // Keep the suggestion in the error event so it shows at the default level
+        tracing::error!("{}\n  {}: {}", error_part.trim(), colors::yellow("SUGGESTION"), suggestion_part[11..].trim()); // Skip "SUGGESTION:"
     } else {
-        eprintln!("{}: {}", colors::red("ERROR"), error_msg);
+        tracing::error!("{error_msg}");
     }
     
     crate::cli::terminal::reset_terminal();
@@ -92,6 +171,109 @@ fn build_cli() -> Command {
         .about("Vanity Address Generator for Substrate Networks\nSupports Autonomys, Polkadot, Substrate, and custom networks\n\nCreated by vexr (github.com/vexr)")
         .long_about(APP_HEADER)
         .author("vexr")
+        .arg(
+            Arg::new("anatomy")
+                .long("anatomy")
+                .value_name("ADDRESS")
+                .help("Show how an address decodes into prefix, public key and checksum bytes, then exit"),
+        )
+        .arg(
+            Arg::new("identify-address")
+                .long("identify-address")
+                .value_name("ADDRESS")
+                .help("Print which known network an address belongs to, judged by its first characters, then exit"),
+        )
+        .arg(
+            Arg::new("check-ss58")
+                .long("check-ss58")
+                .value_name("ADDRESS")
+                .help("Validate and decode an SS58 address (prefix, network, public key, checksum), then exit; status 1 if invalid"),
+        )
+        .arg(
+            Arg::new("generate-address")
+                .long("generate-address")
+                .value_name("SEED_HEX")
+                .help("Print the address a 32-byte hex seed has on the selected network, then exit")
+                .conflicts_with("mnemonic"),
+        )
+        .arg(
+            Arg::new("mnemonic")
+                .long("mnemonic")
+                .value_name("WORDS")
+                .help("Print the address a BIP39 mnemonic has on the selected network, then exit"),
+        )
+        .arg(
+            Arg::new("verify-mnemonic")
+                .long("verify-mnemonic")
+                .value_name("PHRASE")
+                .help("Check a BIP39 mnemonic, suggesting the closest words for any misspelled ones, then exit"),
+        )
+        .arg(
+            Arg::new("generate-mnemonic")
+                .long("generate-mnemonic")
+                .help("Print fresh mnemonics, --count of them (default 1), then exit; with --network or --ss58-prefix each is followed by its address")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["generate-address", "mnemonic"]),
+        )
+        .arg(
+            Arg::new("mnemonic-words")
+                .long("mnemonic-words")
+                .value_name("WORDS")
+                .help("Words per mnemonic printed by --generate-mnemonic (12, 15, 18, 21 or 24)")
+                .value_parser(["12", "15", "18", "21", "24"])
+                .default_value("12")
+                .requires("generate-mnemonic"),
+        )
+        .arg(
+            Arg::new("watch")
+                .long("watch")
+                .help("Print random addresses for the selected network as they are generated (up to 100 a second, nothing saved) until Ctrl+C")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("term"),
+        )
+        .arg(
+            Arg::new("script")
+                .long("script")
+                .help("Read searches as JSON lines from stdin, e.g. {\"term\":\"ai3\",\"count\":1,\"network\":\"Autonomys\"}, and print each wallet found (secret included) as a JSON line; nothing is saved")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["term", "watch"]),
+        )
+        .arg(
+            Arg::new("validate-dir")
+                .long("validate-dir")
+                .value_name("DIR")
+                .help("Check that every wallet in DIR restores its address (JSON wallets need --pass), then exit"),
+        )
+        .arg(
+            Arg::new("analyze-prefix-distribution")
+                .long("analyze-prefix-distribution")
+                .value_name("SS58_PREFIX")
+                .help("Sample 1,000,000 addresses for an SS58 prefix, print a per-position character heat map and save prefix_distribution.json to the output directory, then exit")
+                .value_parser(clap::value_parser!(u16)),
+        )
+        .arg(
+            Arg::new("suggest-pattern")
+                .long("suggest-pattern")
+                .help("List 2- and 3-character patterns that --attempts attempts find with a --luck-pct chance on the selected network and mode, then exit")
+                .action(ArgAction::SetTrue)
+                .requires_all(["luck-pct", "attempts"]),
+        )
+        .arg(
+            Arg::new("luck-pct")
+                .long("luck-pct")
+                .value_name("PERCENT")
+                .help("With --suggest-pattern, the chance (1-99%) of finding the pattern within --attempts")
+                .value_parser(clap::value_parser!(u8).range(1..=99))
+                .requires("suggest-pattern"),
+        )
+        .arg(
+            Arg::new("attempts")
+                .long("attempts")
+                .value_name("N")
+                .help("With --suggest-pattern, how many attempts the search may take")
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .requires("suggest-pattern"),
+        )
         .arg(
             Arg::new("anywhere")
                 .long("anywhere")
@@ -112,7 +294,7 @@ fn build_cli() -> Command {
                 .long("count")
                 .short('c')
                 .value_name("COUNT")
-                .help("Number of wallets to generate")
+                .help("Number of wallets to generate (0 = unlimited, until Ctrl+C)")
                 .value_parser(clap::value_parser!(usize))
                 .default_value("3"),
         )
@@ -127,21 +309,158 @@ fn build_cli() -> Command {
             Arg::new("network")
                 .long("network")
                 .value_name("NETWORK")
-                .help("Network to generate addresses for (Autonomys, Polkadot, Substrate)")
+                .help("Network to generate addresses for (Autonomys, Polkadot, Substrate, or one from --network-config)")
                 .conflicts_with("ss58-prefix"),
         )
+        .arg(
+            Arg::new("list-networks")
+                .long("list-networks")
+                .help("List the known networks with their token, SS58 prefix and address prefixes, then exit")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("network-config")
+                .long("network-config")
+                .value_name("FILE")
+                .help("JSON array of extra networks ({\"name\", \"ss58_prefix\", \"address_prefixes\", \"genesis_hash\"}); reloaded whenever the file changes"),
+        )
+        .arg(
+            Arg::new("kdf")
+                .long("kdf")
+                .value_name("KDF")
+                .help("Key derivation for encrypted JSON wallets (scrypt is supported by all wallets, argon2 uses Argon2id)")
+                .value_parser(["scrypt", "argon2"])
+                .default_value("scrypt"),
+        )
+        .arg(
+            Arg::new("scrypt-n")
+                .long("scrypt-n")
+                .value_name("N")
+                .help("scrypt CPU/memory cost for JSON wallets, a power of 2 from 32768 to 4194304 (default: 32768)")
+                .value_parser(clap::value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("scrypt-r")
+                .long("scrypt-r")
+                .value_name("R")
+                .help("scrypt block size for JSON wallets, 1 to 32 (default: 8)")
+                .value_parser(clap::value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("scrypt-p")
+                .long("scrypt-p")
+                .value_name("P")
+                .help("scrypt parallelization for JSON wallets, 1 to 16 (default: 1)")
+                .value_parser(clap::value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("genesis-hash")
+                .long("genesis-hash")
+                .value_name("HEX")
+                .help("Genesis hash stored in JSON wallet metadata for polkadot.js (default: the network's own, if known)"),
+        )
+        .arg(
+            Arg::new("log-level")
+                .long("log-level")
+                .value_name("LEVEL")
+                .help("Diagnostic output level on stderr (overrides RUST_LOG, default: error)")
+                .value_parser(crate::cli::logging::LOG_LEVELS),
+        )
+        .arg(
+            Arg::new("verbose")
+                .long("verbose")
+                .help("Shorthand for --log-level debug; debug builds also log one key per 100,000 per thread, the search configuration and the shared state every 10s")
+                .conflicts_with("log-level")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("quiet")
+                .long("quiet")
+                .short('q')
+                .help("Print only found wallets, one 'ADDRESS<TAB>SECRET' line each, and errors")
+                .conflicts_with("probability")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("progress-interval")
+                .long("progress-interval")
+                .value_name("MS")
+                .help("Milliseconds between progress updates (100-60000)")
+                .value_parser(clap::value_parser!(u64).range(100..=60000))
+                .default_value("1000"),
+        )
+        .arg(
+            Arg::new("per-thread-stats")
+                .long("per-thread-stats")
+                .help("Show each thread's keys/s on the progress line ([T0:45k T1:43k ...], first 8 threads); threads under 80% of the fastest are red")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("no-color")
+                .long("no-color")
+                .help("Disable colored output (also disabled when NO_COLOR is set)")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("metrics-port")
+                .long("metrics-port")
+                .value_name("PORT")
+                .help("Serve Prometheus metrics on http://127.0.0.1:PORT/metrics while searching (see --metrics-bind)")
+                .value_parser(clap::value_parser!(u16).range(1..)),
+        )
+        .arg(
+            Arg::new("metrics-bind")
+                .long("metrics-bind")
+                .value_name("ADDR")
+                .help("Address the --metrics-port server listens on; 0.0.0.0 exposes it to the network")
+                .value_parser(clap::value_parser!(std::net::IpAddr))
+                .default_value("127.0.0.1"),
+        )
+        .arg(
+            Arg::new("name-template")
+                .long("name-template")
+                .value_name("TEMPLATE")
+                .help("Wallet file name template (extension added automatically). Tokens: {address}, {address_short}, {index}, {timestamp}, {network}, {term}"),
+        )
+        .arg(
+            Arg::new("no-password")
+                .long("no-password")
+                .help("Skip the password prompt and save wallets unencrypted (mnemonic mode only, insecure for production use)")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["pass", "hex"]),
+        )
         .arg(
             Arg::new("output")
                 .long("output")
                 .value_name("DIR")
                 .help("Directory to save wallets"),
         )
+        .arg(
+            Arg::new("output-mnemonic-dir")
+                .long("output-mnemonic-dir")
+                .value_name("DIR")
+                .help("Directory for mnemonic wallet files, instead of --output"),
+        )
+        .arg(
+            Arg::new("output-json-dir")
+                .long("output-json-dir")
+                .value_name("DIR")
+                .help("Directory for encrypted JSON wallet files (--hex), instead of --output"),
+        )
         .arg(
             Arg::new("pass")
                 .long("pass")
                 .value_name("PASSWORD")
                 .help("Password for encrypting wallets (non-interactive mode)"),
         )
+        .arg(
+            Arg::new("pbkdf2-rounds")
+                .long("pbkdf2-rounds")
+                .value_name("N")
+                .help("PBKDF2 rounds for mnemonic key derivation (default: 2048, non-standard values are not supported by other wallets)")
+                .value_parser(clap::value_parser!(u32))
+                .default_value("2048"),
+        )
         .arg(
             Arg::new("prefix")
                 .long("prefix")
@@ -150,6 +469,45 @@ fn build_cli() -> Command {
                 .action(ArgAction::SetTrue)
                 .conflicts_with_all(["suffix", "anywhere"]),
         )
+        .arg(
+            Arg::new("export-raw")
+                .long("export-raw")
+                .help("Also save each keypair UNENCRYPTED as hex (<address>_raw.txt: SECRET_HEX, PUBLIC_HEX) for low-level tooling")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("show-derivation")
+                .long("show-derivation")
+                .help("Also print each found mnemonic's entropy, PBKDF2 salt, mini secret and public key, for debugging address mismatches (SECRET; not shown with --quiet)")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("hex"),
+        )
+        .arg(
+            Arg::new("append-to")
+                .long("append-to")
+                .value_name("FILE")
+                .help("Append each wallet (address, UNENCRYPTED secret, SS58 prefix, time found) as a JSON line to FILE instead of saving a file per wallet; FILE is created if missing and never truncated"),
+        )
+        .arg(
+            Arg::new("no-save")
+                .long("no-save")
+                .help("Only display found wallets; write no wallet files, manifest or statistics to disk")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["append-to", "split", "export-raw", "statistics"]),
+        )
+        .arg(
+            Arg::new("redact-secrets")
+                .long("redact-secrets")
+                .help("Show found addresses but print [REDACTED - saved to file only] instead of their secrets, e.g. while sharing your screen; wallets are still saved")
+                .action(ArgAction::SetTrue)
+                .conflicts_with_all(["no-save", "show-derivation"]),
+        )
+        .arg(
+            Arg::new("split")
+                .long("split")
+                .value_name("K/N")
+                .help("Also save each secret as N Shamir shares, any K of which recover it (e.g. 3/5)"),
+        )
         .arg(
             Arg::new("ss58-prefix")
                 .long("ss58-prefix")
@@ -181,6 +539,20 @@ fn build_cli() -> Command {
                 .help("Number of threads to use (default: number of CPU cores)")
                 .value_parser(clap::value_parser!(usize)),
         )
+        .arg(
+            Arg::new("version-check")
+                .long("version-check")
+                .help("Check GitHub for a newer release, then exit (needs the version-check build feature)")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("watchdog-timeout")
+                .long("watchdog-timeout")
+                .value_name("SECONDS")
+                .help("Warn when no new attempts are made for this many seconds (0 = off)")
+                .value_parser(clap::value_parser!(u64))
+                .default_value("10"),
+        )
         .arg(
             Arg::new("within")
                 .long("within")
@@ -188,6 +560,43 @@ fn build_cli() -> Command {
                 .help("Max characters from start (prefix) or end (suffix) to search within. Default: term length (suffix), 5 (prefix)")
                 .value_parser(clap::value_parser!(usize)),
         )
+        .arg(
+            Arg::new("within-end")
+                .long("within-end")
+                .value_name("N")
+                .help("End of an explicit search window (exclusive character index), used with --within-start")
+                .value_parser(clap::value_parser!(usize))
+                .requires("within-start"),
+        )
+        .arg(
+            Arg::new("within-start")
+                .long("within-start")
+                .value_name("N")
+                .help("Start of an explicit search window (character index), used with --within-end")
+                .value_parser(clap::value_parser!(usize))
+                .requires("within-end")
+                .conflicts_with_all(["within", "prefix", "suffix", "anywhere"]),
+        )
+        .arg(
+            Arg::new("statistics")
+                .long("statistics")
+                .help("Write run_stats.txt to the output directory comparing each wallet's attempts with the expected attempts")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("advanced-stats")
+                .long("advanced-stats")
+                .help("Add a Kolmogorov-Smirnov test against the geometric distribution to run_stats.txt")
+                .action(ArgAction::SetTrue)
+                .requires("statistics"),
+        )
+        .arg(
+            Arg::new("histogram")
+                .long("histogram")
+                .help("After the run, show a histogram of attempts per wallet (needs --count above 5)")
+                .conflicts_with("quiet")
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new("probability")
                 .long("probability")
@@ -195,6 +604,15 @@ fn build_cli() -> Command {
                 .help("Show detailed probability calculations and expected attempts")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("monte-carlo-samples")
+                .long("monte-carlo-samples")
+                .value_name("N")
+                .help("With --probability, cross-check expected attempts by searching N random addresses")
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .requires("probability")
+                .conflicts_with("within-start"),
+        )
 }
 
 pub fn parse_and_validate_args() -> Config {
@@ -209,18 +627,47 @@ pub fn parse_and_validate_args() -> Config {
     }
 
     let matches = cmd.get_matches();
+    crate::cli::terminal::init_color_mode(matches.get_flag("no-color"));
+    let log_level = matches.get_one::<String>("log-level").map(String::as_str);
+    crate::cli::logging::init_logging(log_level.or(matches.get_flag("verbose").then_some("debug")));
+
+    if matches.get_flag("version-check") {
+        crate::cli::version_check::print_version_check();
+        std::process::exit(0);
+    }
 
     let hex_mode = matches.get_flag("hex");
     let count = *matches.get_one::<usize>("count").unwrap();
 
+    // Load --network-config before any network lookup, then keep it up to date
+    if let Some(path) = matches.get_one::<String>("network-config") {
+        let path = std::path::Path::new(path);
+        let loaded = crate::networks::load_network_config(path).and_then(|networks| {
+            crate::networks::set_networks(networks);
+            #[cfg(not(target_arch = "wasm32"))]
+            crate::networks::watch_network_config(path)?;
+            Ok(())
+        });
+        if let Err(e) = loaded {
+            tracing::error!("{e}");
+            crate::cli::terminal::reset_terminal();
+            std::process::exit(1);
+        }
+    }
+
+    // --list-networks prints the registry (including --network-config) and exits
+    if matches.get_flag("list-networks") {
+        crate::cli::display::print_network_list(&crate::networks::networks());
+        std::process::exit(0);
+    }
+
     // Determine SS58 prefix from either --network or --ss58-prefix
     let ss58_prefix = if let Some(network_name) = matches.get_one::<String>("network") {
         // Validate network name and get its prefix
         if let Some(network_config) = crate::networks::find_network(network_name) {
             network_config.ss58_prefix
         } else {
-            eprintln!("{}: Unknown network '{}'. Available networks: Autonomys, Polkadot, Substrate", 
-                     colors::red("ERROR"), network_name);
+            tracing::error!("Unknown network '{network_name}'. Available networks: {}", crate::networks::network_names());
             crate::cli::terminal::reset_terminal();
             std::process::exit(1);
         }
@@ -232,24 +679,241 @@ pub fn parse_and_validate_args() -> Config {
         6094
     };
 
-    // Validate count
-    if count == 0 {
-        eprintln!("{}: Count must be at least 1", colors::red("ERROR"));
+    // --analyze-prefix-distribution samples a network's addresses and exits without searching
+    if let Some(&prefix) = matches.get_one::<u16>("analyze-prefix-distribution") {
+        use crate::cli::probability::{character_counts_by_position, DISTRIBUTION_SAMPLE_ADDRESSES};
+
+        let output_dir = matches
+            .get_one::<String>("output")
+            .map(|s| s.to_string())
+            .unwrap_or_else(get_default_wallet_dir);
+        let counts = character_counts_by_position(prefix, DISTRIBUTION_SAMPLE_ADDRESSES);
+        crate::cli::probability::print_prefix_distribution(prefix, &counts, DISTRIBUTION_SAMPLE_ADDRESSES);
+
+        let written = validate_output_directory(&output_dir).and_then(|()| {
+            crate::cli::probability::write_prefix_distribution(prefix, &counts, DISTRIBUTION_SAMPLE_ADDRESSES, &output_dir)
+        });
+        if let Err(e) = written {
+            tracing::error!("{e}");
+            crate::cli::terminal::reset_terminal();
+            std::process::exit(1);
+        }
+        println!("\nSaved to {}/{}", output_dir, crate::cli::probability::PREFIX_DISTRIBUTION_FILENAME);
+        std::process::exit(0);
+    }
+
+    // --identify-address names an address's network and exits without searching
+    if let Some(address) = matches.get_one::<String>("identify-address") {
+        let networks = crate::networks::find_networks_by_address_prefix(address.trim());
+        if networks.is_empty() {
+            println!("Unknown network.");
+        } else {
+            let names: Vec<&str> = networks.iter().map(|network| network.name).collect();
+            println!("{}", names.join(" or "));
+        }
+        std::process::exit(0);
+    }
+
+    // --check-ss58 decodes one address and exits without searching
+    if let Some(address) = matches.get_one::<String>("check-ss58") {
+        let valid = crate::cli::display::print_ss58_check(address.trim());
+        std::process::exit(if valid { 0 } else { 1 });
+    }
+
+    // --anatomy explains one address and exits without searching
+    if let Some(address) = matches.get_one::<String>("anatomy") {
+        if let Err(e) = crate::validation::explain_address(address, ss58_prefix) {
+            tracing::error!("Cannot explain address '{address}': {e}");
+            crate::cli::terminal::reset_terminal();
+            std::process::exit(1);
+        }
+        std::process::exit(0);
+    }
+
+    // --generate-address/--mnemonic derive one address and exit without searching
+    let secret = matches
+        .get_one::<String>("generate-address")
+        .map(|seed| (seed, true))
+        .or(matches.get_one::<String>("mnemonic").map(|words| (words, false)));
+    if let Some((secret, is_hex)) = secret {
+        match crate::validation::derive_address(secret.trim(), ss58_prefix, is_hex, crate::wallet::DEFAULT_PBKDF2_ROUNDS) {
+            Ok(address) => println!("{address}"),
+            Err(e) => {
+                tracing::error!("{e}");
+                crate::cli::terminal::reset_terminal();
+                std::process::exit(1);
+            }
+        }
+        std::process::exit(0);
+    }
+
+    // --verify-mnemonic checks a phrase and exits without searching
+    if let Some(phrase) = matches.get_one::<String>("verify-mnemonic") {
+        let valid = crate::cli::display::print_mnemonic_check(phrase);
+        std::process::exit(if valid { 0 } else { 1 });
+    }
+
+    // --generate-mnemonic prints fresh mnemonics and exits without searching
+    if matches.get_flag("generate-mnemonic") {
+        // --count defaults to 3 wallets for a search; one mnemonic unless asked
+        let count = if matches.value_source("count") == Some(clap::parser::ValueSource::CommandLine) { count } else { 1 };
+        let word_count = matches.get_one::<String>("mnemonic-words").unwrap().parse().unwrap();
+        let show_address = matches.contains_id("network") || matches.contains_id("ss58-prefix");
+        if !(1..=1000).contains(&count) {
+            tracing::error!("--generate-mnemonic prints 1 to 1000 mnemonics, not {count}");
+            crate::cli::terminal::reset_terminal();
+            std::process::exit(1);
+        }
+
+        match crate::wallet::generate_mnemonics(count, word_count) {
+            Ok(mnemonics) => {
+                for mnemonic in mnemonics {
+                    if show_address {
+                        println!("{}\t{}", mnemonic, crate::crypto::mnemonic_to_address_with_prefix(&mnemonic, ss58_prefix));
+                    } else {
+                        println!("{mnemonic}");
+                    }
+                }
+            }
+            Err(e) => {
+                tracing::error!("{e}");
+                crate::cli::terminal::reset_terminal();
+                std::process::exit(1);
+            }
+        }
+        std::process::exit(0);
+    }
+
+    // --watch streams random addresses until Ctrl+C without searching or saving
+    if matches.get_flag("watch") {
+        use crate::runner::{parallel::generate_vanity_addresses, SearchParams, WATCH_COUNT};
+
+        let pbkdf2_rounds = *matches.get_one::<u32>("pbkdf2-rounds").unwrap();
+        // One worker outpaces the 100 addresses a second that are printed
+        let params = SearchParams {
+            count: WATCH_COUNT,
+            hex_mode,
+            pbkdf2_rounds,
+            within: 0,
+            suffix: false,
+            anywhere: true,
+            threads: 1,
+            ..SearchParams::new("", ss58_prefix)
+        };
+        generate_vanity_addresses(
+            &params,
+            |_progress| {},
+            |result| {
+                use std::io::Write;
+                // Stop quietly once the reader goes away (e.g. `| head`)
+                if writeln!(std::io::stdout(), "{}", result.address).is_err() {
+                    std::process::exit(0);
+                }
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            },
+        );
+        std::process::exit(0);
+    }
+
+    // --script runs searches from stdin until it closes
+    if matches.get_flag("script") {
+        let threads = matches.get_one::<usize>("threads").map_or_else(num_cpus::get, |t| *t).max(1);
+        let pbkdf2_rounds = *matches.get_one::<u32>("pbkdf2-rounds").unwrap();
+        if let Err(e) = crate::cli::script::run_script(std::io::stdin().lock(), &mut std::io::stdout().lock(), threads, pbkdf2_rounds) {
+            tracing::error!("{e}");
+            crate::cli::terminal::reset_terminal();
+            std::process::exit(1);
+        }
+        std::process::exit(0);
+    }
+
+    // --validate-dir audits saved wallets and exits without searching
+    if let Some(dir) = matches.get_one::<String>("validate-dir") {
+        if !std::path::Path::new(dir).is_dir() {
+            tracing::error!("Cannot validate '{dir}': not a directory");
+            crate::cli::terminal::reset_terminal();
+            std::process::exit(1);
+        }
+        let password = matches.get_one::<String>("pass").map(String::as_str);
+        let results = crate::validation::validate_directory(dir, password);
+        let all_valid = crate::cli::display::print_directory_validation(dir, &results, password.is_some());
+        std::process::exit(if all_valid { 0 } else { 1 });
+    }
+
+    // Validate count; 0 keeps generating until Ctrl+C
+    if count > 1000 {
+        tracing::error!("Count too large (maximum: 1000)");
         crate::cli::terminal::reset_terminal();
         std::process::exit(1);
     }
-    if count > 1000 {
-        eprintln!("{}: Count too large (maximum: 1000)", colors::red("ERROR"));
+
+    let name_template = matches.get_one::<String>("name-template").map(|s| s.to_string());
+    if let Some(template) = &name_template
+        && let Err(e) = crate::wallet::validate_name_template(template, count)
+    {
+        tracing::error!("{e}");
         crate::cli::terminal::reset_terminal();
         std::process::exit(1);
     }
 
+    let pbkdf2_rounds = *matches.get_one::<u32>("pbkdf2-rounds").unwrap();
+    if pbkdf2_rounds < crate::wallet::DEFAULT_PBKDF2_ROUNDS {
+        tracing::error!("PBKDF2 rounds must be at least {} (BIP39 minimum)", crate::wallet::DEFAULT_PBKDF2_ROUNDS);
+        crate::cli::terminal::reset_terminal();
+        std::process::exit(1);
+    }
+
+    let split = matches.get_one::<String>("split").map(|value| {
+        parse_split(value).unwrap_or_else(|e| {
+            tracing::error!("{e}");
+            crate::cli::terminal::reset_terminal();
+            std::process::exit(1);
+        })
+    });
+
+    let kdf = match matches.get_one::<String>("kdf").map(String::as_str) {
+        Some("argon2") => {
+            if ["scrypt-n", "scrypt-r", "scrypt-p"].iter().any(|id| matches.contains_id(id)) {
+                tracing::error!("--scrypt-n, --scrypt-r and --scrypt-p only apply to --kdf scrypt");
+                crate::cli::terminal::reset_terminal();
+                std::process::exit(1);
+            }
+            crate::wallet::Kdf::Argon2
+        }
+        _ => {
+            let defaults = crate::wallet::ScryptParams::default();
+            let params = crate::wallet::ScryptParams {
+                n: matches.get_one::<u32>("scrypt-n").copied().unwrap_or(defaults.n),
+                r: matches.get_one::<u32>("scrypt-r").copied().unwrap_or(defaults.r),
+                p: matches.get_one::<u32>("scrypt-p").copied().unwrap_or(defaults.p),
+            };
+            if let Err(e) = params.validate() {
+                tracing::error!("{e}");
+                crate::cli::terminal::reset_terminal();
+                std::process::exit(1);
+            }
+            crate::wallet::Kdf::Scrypt(params)
+        }
+    };
+
+    // An explicit --genesis-hash wins; otherwise use the known network's, if any
+    let genesis_hash = match matches.get_one::<String>("genesis-hash") {
+        Some(hash) => Some(crate::networks::parse_genesis_hash(hash).unwrap_or_else(|e| {
+            tracing::error!("{e}");
+            crate::cli::terminal::reset_terminal();
+            std::process::exit(1);
+        })),
+        None => crate::networks::find_network_by_prefix(ss58_prefix)
+            .and_then(|network| network.genesis_hash)
+            .map(str::to_string),
+    };
+
     let threads = matches
         .get_one::<usize>("threads")
         .map_or_else(num_cpus::get, |t| *t);
     
     if threads == 0 {
-        eprintln!("{}: Thread count must be at least 1", colors::red("ERROR"));
+        tracing::error!("Thread count must be at least 1");
         crate::cli::terminal::reset_terminal();
         std::process::exit(1);
     }
@@ -267,8 +931,28 @@ pub fn parse_and_validate_args() -> Config {
         _ => unreachable!(), // clap prevents conflicting combinations
     };
 
+    let address_len = crate::validation::ss58_address_length_for_prefix(ss58_prefix);
+
+    // --suggest-pattern lists patterns of the requested difficulty and exits without searching
+    if matches.get_flag("suggest-pattern") {
+        use crate::cli::probability::{print_pattern_suggestions, suggest_patterns, target_expected_attempts};
+
+        let luck_pct = *matches.get_one::<u8>("luck-pct").unwrap();
+        let budget = *matches.get_one::<u64>("attempts").unwrap();
+        // Suffix patterns default to their own length, as --within does for --term
+        let within = matches.get_one::<usize>("within").copied().unwrap_or(match (suffix, anywhere) {
+            (true, _) => 0,
+            (_, true) => address_len,
+            _ => 5,
+        });
+        let target = target_expected_attempts(budget, f64::from(luck_pct));
+        let suggestions = suggest_patterns(target, ss58_prefix, within, suffix, anywhere);
+        print_pattern_suggestions(&suggestions, target, budget, luck_pct);
+        std::process::exit(0);
+    }
+
     let term = matches.get_one::<String>("term").unwrap().to_string();
-    
+
     // Calculate default within value based on mode
     let within = if let Some(within_value) = matches.get_one::<usize>("within") {
         *within_value
@@ -277,14 +961,36 @@ pub fn parse_and_validate_args() -> Config {
         if suffix {
             term.chars().count()
         } else if anywhere {
-            49 // Full SS58 address length
+            address_len // Full SS58 address length
         } else {
             5 // prefix mode
         }
     };
     
+    // Explicit window from --within-start/--within-end (clap requires both together)
+    let within_window = match (matches.get_one::<usize>("within-start"), matches.get_one::<usize>("within-end")) {
+        (Some(&start), Some(&end)) => {
+            let error = if start > end {
+                Some(format!("--within-start ({start}) must not exceed --within-end ({end})"))
+            } else if end > address_len {
+                Some(format!("--within-end ({end}) is past the end of a {address_len} character address"))
+            } else if end - start < term.chars().count() {
+                Some(format!("Search window {start}..{end} is too narrow for search term '{term}'"))
+            } else {
+                None
+            };
+            if let Some(e) = error {
+                tracing::error!("{e}");
+                crate::cli::terminal::reset_terminal();
+                std::process::exit(1);
+            }
+            Some((start, end))
+        }
+        _ => None,
+    };
+
     // Validate impossible prefix combinations early
-    if prefix && !suffix && !anywhere {
+    if prefix && !suffix && !anywhere && within_window.is_none() {
         if let Some(expected_prefixes) = crate::networks::find_network_by_prefix(ss58_prefix).map(|n| n.address_prefixes) {
             let max_prefix_len = expected_prefixes.iter().map(|p| p.len()).max().unwrap_or(0);
             
@@ -349,6 +1055,7 @@ pub fn parse_and_validate_args() -> Config {
                         suffix,
                         anywhere,
                         within,
+                        within_window,
                         case_sensitive,
                         matches.get_flag("hex"),
                         threads,
@@ -367,19 +1074,67 @@ pub fn parse_and_validate_args() -> Config {
         hex_mode,
         ss58_prefix,
         within,
-        output_dir: matches
-            .get_one::<String>("output")
-            .map(|s| s.to_string())
-            .unwrap_or_else(get_default_wallet_dir),
-        password: matches.get_one::<String>("pass").map(|s| s.to_string()),
+        within_window,
+        // A type-specific directory replaces the default output directory
+        output_dir: matches.get_one::<String>("output").cloned().or_else(|| {
+            let type_dir = if hex_mode { "output-json-dir" } else { "output-mnemonic-dir" };
+            (!matches.contains_id(type_dir)).then(get_default_wallet_dir)
+        }),
+        mnemonic_output_dir: matches.get_one::<String>("output-mnemonic-dir").cloned(),
+        json_output_dir: matches.get_one::<String>("output-json-dir").cloned(),
+        password: if matches.get_flag("no-password") {
+            Some(String::new())
+        } else {
+            matches.get_one::<String>("pass").map(|s| s.to_string())
+        },
         suffix,
         anywhere,
         term,
         threads,
         probability: matches.get_flag("probability"),
+        monte_carlo_samples: matches.get_one::<u64>("monte-carlo-samples").copied(),
+        name_template,
+        kdf,
+        genesis_hash,
+        pbkdf2_rounds,
+        split,
+        export_raw: matches.get_flag("export-raw"),
+        show_derivation: matches.get_flag("show-derivation"),
+        append_to: matches.get_one::<String>("append-to").cloned(),
+        no_save: matches.get_flag("no-save"),
+        redact_secrets: matches.get_flag("redact-secrets"),
+        statistics: matches.get_flag("statistics"),
+        advanced_stats: matches.get_flag("advanced-stats"),
+        histogram: matches.get_flag("histogram"),
+        metrics_port: matches.get_one::<u16>("metrics-port").copied(),
+        metrics_bind: *matches.get_one::<std::net::IpAddr>("metrics-bind").unwrap(),
+        watchdog_timeout: *matches.get_one::<u64>("watchdog-timeout").unwrap(),
+        progress_interval: *matches.get_one::<u64>("progress-interval").unwrap(),
+        quiet: matches.get_flag("quiet"),
+        per_thread_stats: matches.get_flag("per-thread-stats"),
     }
 }
 
+/// Parse a `--split` threshold of the form `K/N`
+fn parse_split(value: &str) -> Result<(u8, u8), String> {
+    let (k, n) = value
+        .split_once('/')
+        .ok_or_else(|| format!("Invalid split '{value}', expected K/N (e.g. 3/5)"))?;
+    let k: u8 = k.trim().parse()
+        .map_err(|_| format!("Invalid split threshold '{k}', expected a number from 2 to 255"))?;
+    let n: u8 = n.trim().parse()
+        .map_err(|_| format!("Invalid split share count '{n}', expected a number from 2 to 255"))?;
+
+    if k < 2 {
+        return Err("Split threshold K must be at least 2".to_string());
+    }
+    if k > n {
+        return Err(format!("Split threshold K ({k}) cannot exceed share count N ({n})"));
+    }
+
+    Ok((k, n))
+}
+
 pub fn validate_output_directory(dir: &str) -> Result<(), String> {
     use std::fs;
     use std::path::Path;
@@ -1,12 +1,35 @@
 use crate::validation::{validate_wallet, ValidationResult};
 use crate::cli::args::Config;
-use crate::cli::probability::{calculate_luck_factor, calculate_expected_attempts};
-use crate::cli::terminal::{format_runtime_with_nanos, get_luck_color, failed_indicator, colors};
+use crate::cli::probability::{
+    calculate_luck_factor, ks_statistic_geometric, luck_percentile,
+};
+use crate::cli::terminal::{format_runtime_with_nanos, get_luck_color, colors};
 use crate::runner::{VanityResult, GenerationStats};
+use crate::runner::parallel::{RollingHistogram, HISTOGRAM_BUCKETS};
 use crate::wallet;
+use crate::wallet::manifest::WalletStat;
+use crate::wallet::results_file::ResultsFile;
 use num_format::{SystemLocale, ToFormattedString};
 use std::io::{self, Write};
 
+/// Print each step from the wallet's mnemonic to its address (`--show-derivation`)
+fn print_derivation(result: &VanityResult, pbkdf2_rounds: u32) {
+    let Ok(mnemonic) = crate::crypto::bip39::Mnemonic::parse(&result.secret) else {
+        return;
+    };
+    let derivation = wallet::mnemonic_derivation(&mnemonic, result.ss58_prefix, pbkdf2_rounds);
+    let lines = [
+        format!("Entropy:     {}", hex::encode(&derivation.entropy)),
+        format!("PBKDF2 salt: \"{}\" ({} rounds)", derivation.salt, pbkdf2_rounds),
+        format!("Mini secret: {}", hex::encode(derivation.mini_secret)),
+        format!("Public key:  {}", hex::encode(derivation.public_key)),
+        format!("Address:     {}", derivation.address),
+    ];
+    for line in lines {
+        println!("  {} {}", colors::gray("└"), colors::gray(&line));
+    }
+}
+
 pub fn process_individual_wallet(
     result: &VanityResult, 
     config: &Config, 
@@ -14,70 +37,113 @@ pub fn process_individual_wallet(
     wallet_number: usize,
     elapsed_secs: u64,
     elapsed_nanos: u128,
-) {
-    // Calculate luck for this individual wallet using mean-based calculation
-    let expected_attempts = calculate_expected_attempts(
-        &config.term,
-        config.case_sensitive,
-        config.suffix,
-        config.anywhere,
-        config.within,
-        config.ss58_prefix,
-    );
-    let luck = calculate_luck_factor(result.attempts, expected_attempts);
+    results_file: Option<&mut ResultsFile>,
+) -> Result<WalletStat, String> {
+    // Luck for this individual wallet using the runner's expected attempts
+    let luck = calculate_luck_factor(result.attempts, result.expected_attempts);
+    let percentile = luck_percentile(result.attempts, result.expected_attempts);
     
-    // Validate wallet and get validation status
-    let validation_result = validate_wallet(&result.secret, &result.address, result.ss58_prefix, config.hex_mode);
-    let validation_status = match validation_result {
+    // Re-derive the address from the secret before saving it
+    let validation_result = validate_wallet(&result.secret, &result.address, result.ss58_prefix, config.hex_mode, config.pbkdf2_rounds);
+    let validation_status = match &validation_result {
+        ValidationResult::Valid if config.no_save => format!("{} {} {} {}",
+            colors::gray("Validated:"), colors::green(validation_result.status_symbol()),
+            colors::gray("Saved:"), colors::yellow("NO")),
         ValidationResult::Valid => format!("{} {} {} {}",
             colors::gray("Validated:"), colors::green(validation_result.status_symbol()),
             colors::gray("Saved:"), colors::green("OK")),
         ValidationResult::Mismatch => format!("{} {}",
             colors::gray("Validated:"), colors::red(validation_result.status_symbol())),
+        ValidationResult::WrongNetwork { expected_prefix, actual_address } => format!("{} {} {}",
+            colors::gray("Validated:"), colors::red(validation_result.status_symbol()),
+            colors::red(&format!("(address {} is not on SS58 prefix {})", actual_address, expected_prefix))),
         ValidationResult::Error => format!("{} {}",
             colors::gray("Validated:"), colors::yellow(validation_result.status_symbol())),
     };
 
-    crate::cli::terminal::print_result(result, config.hex_mode, wallet_number, elapsed_secs, elapsed_nanos, luck, &validation_status);
+    if config.quiet {
+        crate::cli::terminal::print_result_quiet(result, config.redact_secrets);
+    } else {
+        crate::cli::terminal::print_result(result, config.hex_mode, wallet_number, elapsed_secs, elapsed_nanos, luck, percentile, &validation_status, config.redact_secrets);
+        if config.show_derivation && !config.hex_mode {
+            print_derivation(result, config.pbkdf2_rounds);
+        }
+    }
     io::stdout().flush().unwrap();
 
-    // Save wallet files based on mode
-    if config.hex_mode {
+    // Resolve the file name from --name-template, defaulting to the address
+    let file_stem = match &config.name_template {
+        Some(template) => {
+            wallet::apply_name_template(template, result, wallet_number, &result.network_name, &config.term)
+        }
+        None => result.address.clone(),
+    };
+
+    // Save wallet files based on mode; each write is synced before returning
+    let appended_to = results_file.as_ref().map(|file| file.path().to_string());
+    let saved = if config.no_save {
+        // --no-save only displays the wallet
+        Ok(())
+    } else if let Some(results_file) = results_file {
+        // --append-to replaces the per-wallet file
+        results_file.append(result).and_then(|()| results_file.flush())
+    } else if config.hex_mode {
         // Save encrypted JSON for hex mode
-        if let Some(pwd) = password
-            && let Err(e) =
-                wallet::save_wallet_json(result, pwd, &config.term, &config.output_dir)
-        {
-            eprintln!("  {} Failed to save wallet: {e}", failed_indicator());
+        match password {
+            Some(pwd) => wallet::save_wallet_json(result, pwd, &config.term, config.wallet_dir(), &file_stem, config.kdf, config.genesis_hash.as_deref()),
+            None => Ok(()),
         }
     } else {
         // Save mnemonic as text file for mnemonic mode
-        if let Err(e) = wallet::save_wallet_mnemonic(result, &config.output_dir) {
-            eprintln!("  {} Failed to save mnemonic: {e}", failed_indicator());
+        wallet::save_wallet_mnemonic(result, config.wallet_dir(), &file_stem, config.pbkdf2_rounds)
+    };
+
+    // Save Shamir shares alongside the wallet when --split is set
+    let saved = saved.and_then(|()| match config.split {
+        Some((k, n)) => wallet::shares::save_wallet_shares(result, config.wallet_dir(), &file_stem, config.hex_mode, k, n),
+        None => Ok(()),
+    });
+
+    // Save the unencrypted keypair when --export-raw is set
+    let saved = saved.and_then(|()| {
+        if config.export_raw {
+            wallet::raw::save_wallet_raw(result, config.wallet_dir(), &file_stem, config.hex_mode, config.pbkdf2_rounds)
+        } else {
+            Ok(())
         }
-    }
+    });
     
     // Add line break before progress counter continues
-    println!();
+    if !config.quiet {
+        println!();
+        io::stdout().flush().unwrap();
+    }
+
+    saved?;
+
+    // Manifest paths are relative to the manifest's directory
+    let extension = if config.hex_mode { "json" } else { "txt" };
+    let file = if config.no_save {
+        String::new()
+    } else if let Some(path) = appended_to {
+        path
+    } else {
+        wallet::manifest::manifest_file_path(config.report_dir(), config.wallet_dir(), &format!("{}.{}", file_stem, extension))
+    };
+    Ok(WalletStat {
+        index: wallet_number,
+        file,
+        luck_pct: luck,
+        found_in_ms: (elapsed_nanos / 1_000_000) as u64,
+    })
 }
 
 pub fn display_statistics(
     results: &[VanityResult],
     total_stats: &GenerationStats,
-    config: &Config,
 ) {
     // Only show overall summary if we generated multiple wallets
     if results.len() > 1 {
-        // Calculate expected attempts for luck display using mean-based calculation
-        let expected_attempts = calculate_expected_attempts(
-            &config.term,
-            config.case_sensitive,
-            config.suffix,
-            config.anywhere,
-            config.within,
-            config.ss58_prefix,
-        );
-        
         // Calculate speed using nanoseconds for maximum precision
         // Even at 100M keys/s, we'd need 10ns per key, so nanosecond precision is sufficient
         let avg_speed = if total_stats.elapsed_nanos > 0 {
@@ -91,8 +157,7 @@ pub fn display_statistics(
         let found_in_str = format_runtime_with_nanos(total_stats.elapsed_secs, total_stats.elapsed_nanos);
         
         // Calculate overall luck based on total attempts vs expected total attempts
-        #[allow(clippy::cast_precision_loss)]
-        let expected_total_attempts = expected_attempts * results.len() as u64;
+        let expected_total_attempts: u64 = results.iter().map(|r| r.expected_attempts).sum();
         let overall_luck = calculate_luck_factor(total_stats.total_attempts, expected_total_attempts);
         let formatted_overall_luck = (overall_luck as u64).to_formatted_string(&locale);
         let luck_text = format!("{}%", formatted_overall_luck);
@@ -107,9 +172,216 @@ pub fn display_statistics(
             colors::gray("Speed:"), format!("{} keys/s", avg_speed_str),
             colors::gray("Overall Luck:"), overall_luck_color
         );
+        println!(
+            "Per Wallet: {} {} {} {} {} {} {} {:.2} wallets/s",
+            colors::gray("Min Attempts:"), total_stats.min_attempts.to_formatted_string(&locale),
+            colors::gray("Avg Attempts:"), total_stats.average_attempts_per_wallet.to_formatted_string(&locale),
+            colors::gray("Max Attempts:"), total_stats.max_attempts.to_formatted_string(&locale),
+            colors::gray("Rate:"), total_stats.wallets_per_second
+        );
     }
 }
 
-pub fn display_save_location(_config: &Config) {
-    // No longer display save location at the end since it's shown in the header
-}
\ No newline at end of file
+/// Wallets a run must find before `--histogram` is shown
+pub const HISTOGRAM_MIN_WALLETS: usize = 5;
+
+/// Print the attempts-per-wallet histogram of `results` (`--histogram`)
+pub fn display_histogram(results: &[VanityResult], expected_attempts: u64) {
+    let mut histogram = RollingHistogram::new(expected_attempts);
+    for result in results {
+        histogram.record(result.attempts);
+    }
+
+    let locale = SystemLocale::default().unwrap();
+    let labels: Vec<String> = (0..HISTOGRAM_BUCKETS)
+        .map(|i| {
+            let (start, end) = histogram.bucket_range(i);
+            let end = if i + 1 == HISTOGRAM_BUCKETS { "+".to_string() } else { format!("-{}", end.to_formatted_string(&locale)) };
+            format!("{}{}", start.to_formatted_string(&locale), end)
+        })
+        .collect();
+    let label_width = labels.iter().map(String::len).max().unwrap_or(0);
+
+    println!();
+    println!("Attempts per wallet {}", colors::gray(&format!("(expected {})", expected_attempts.to_formatted_string(&locale))));
+    for ((label, bar), count) in labels.iter().zip(histogram.as_ascii_bars()).zip(histogram.buckets()) {
+        println!("  {} {} {}", colors::gray(&format!("{label:>label_width$}")), colors::cyan(&bar), count);
+    }
+}
+
+/// Statistics report file name inside the output directory (`--statistics`)
+pub const RUN_STATS_FILENAME: &str = "run_stats.txt";
+
+/// Write `<output_dir>/run_stats.txt` comparing each wallet's attempts with the
+/// expected attempts, plus a Kolmogorov-Smirnov check with `--advanced-stats`
+pub fn write_run_statistics(results: &[VanityResult], stats: &[WalletStat], config: &Config) -> Result<(), String> {
+    use std::fmt::Write as _;
+
+    let locale = SystemLocale::default().unwrap();
+    // Every wallet in a run searches the same pattern, so they share one expectation
+    let expected_attempts = results.first().map_or(0, |r| r.expected_attempts);
+
+    let mut report = String::new();
+    let _ = writeln!(report, "Run statistics for '{}'", config.term);
+    let _ = writeln!(report, "Expected attempts per wallet: {}", expected_attempts.to_formatted_string(&locale));
+    let _ = writeln!(report);
+    let _ = writeln!(report, "{:>4}  {:<50} {:>15} {:>8} {:>11} {:>10}", "#", "Address", "Attempts", "Luck", "Percentile", "Time");
+
+    // Saved wallets only, keeping their position in the run
+    let saved: Vec<(&WalletStat, &VanityResult)> = stats
+        .iter()
+        .filter_map(|stat| Some((stat, results.get(stat.index.checked_sub(1)?)?)))
+        .collect();
+
+    for (stat, result) in &saved {
+        let found_in_nanos = u128::from(stat.found_in_ms) * 1_000_000;
+        let _ = writeln!(
+            report,
+            "{:>4}  {:<50} {:>15} {:>7.0}% {:>10.1}% {:>10}",
+            stat.index,
+            result.address,
+            result.attempts.to_formatted_string(&locale),
+            calculate_luck_factor(result.attempts, expected_attempts),
+            luck_percentile(result.attempts, expected_attempts),
+            format_runtime_with_nanos(stat.found_in_ms / 1000, found_in_nanos),
+        );
+    }
+
+    let total_attempts: u64 = saved.iter().map(|(_, r)| r.attempts).sum();
+    let total_ms: u64 = saved.iter().map(|(s, _)| s.found_in_ms).sum();
+    let expected_total = expected_attempts.saturating_mul(saved.len() as u64);
+    let _ = writeln!(report);
+    let _ = writeln!(report, "Summary");
+    let _ = writeln!(report, "  Wallets:         {}", saved.len());
+    let _ = writeln!(report, "  Total attempts:  {}", total_attempts.to_formatted_string(&locale));
+    let _ = writeln!(report, "  Expected total:  {}", expected_total.to_formatted_string(&locale));
+    let _ = writeln!(report, "  Overall luck:    {:.0}%", calculate_luck_factor(total_attempts, expected_total));
+    let _ = writeln!(report, "  Total time:      {}", format_runtime_with_nanos(total_ms / 1000, u128::from(total_ms) * 1_000_000));
+
+    if config.advanced_stats && !saved.is_empty() {
+        let attempts: Vec<u64> = saved.iter().map(|(_, r)| r.attempts).collect();
+        let d = ks_statistic_geometric(&attempts, expected_attempts);
+        // Asymptotic 5% critical value; conservative for small samples and discrete data
+        #[allow(clippy::cast_precision_loss)]
+        let critical = 1.36 / (attempts.len() as f64).sqrt();
+        let verdict = if d <= critical {
+            "consistent with a geometric distribution"
+        } else {
+            "deviates from a geometric distribution at the 5% level"
+        };
+        let _ = writeln!(report);
+        let _ = writeln!(report, "Kolmogorov-Smirnov test against the geometric distribution");
+        let _ = writeln!(report, "  D = {:.4} (n = {}, 5% critical value {:.4})", d, attempts.len(), critical);
+        let _ = writeln!(report, "  Result: {}", verdict);
+    }
+
+    let output_path = format!("{}/{}", config.report_dir(), RUN_STATS_FILENAME);
+    wallet::write_file_durable(&output_path, report)
+        .map_err(|e| format!("Failed to write run statistics: {}", e))
+}
+
+/// Print the `--list-networks` table
+pub fn print_network_list(networks: &[crate::networks::NetworkConfig]) {
+    let name_width = networks.iter().map(|network| network.name.len()).max().unwrap_or(0).max("Network".len());
+    let token_width = networks.iter().map(|network| network.token_symbol.len()).max().unwrap_or(0).max("Token".len());
+
+    println!("{:<name_width$}  {:<token_width$}  Decimals  SS58    Address prefixes", "Network", "Token");
+    println!("{}", "─".repeat(name_width + token_width + 42));
+    for network in networks {
+        println!(
+            "{:<name_width$}  {:<token_width$}  {:<8}  {:<6}  {}",
+            network.name,
+            network.token_symbol,
+            network.decimals,
+            network.ss58_prefix,
+            network.address_prefixes.join(", ")
+        );
+    }
+}
+
+/// Print the `--verify-mnemonic` report and return whether the phrase is valid
+pub fn print_mnemonic_check(phrase: &str) -> bool {
+    use crate::crypto::bip39::{Error, Mnemonic};
+
+    let unknown = crate::crypto::bip39_autocorrect::unknown_words(phrase);
+    for word in &unknown {
+        println!(
+            "{} Word {} '{}' is not a BIP39 word. Did you mean: {}?",
+            colors::red("✗"),
+            word.position,
+            word.word,
+            word.suggestions.join(" / ")
+        );
+    }
+
+    match Mnemonic::parse(phrase) {
+        Ok(_) => {
+            println!("{} Valid mnemonic", colors::green("✓"));
+            true
+        }
+        Err(Error::InvalidWordCount(count)) => {
+            println!("{} Expected 12, 15, 18, 21 or 24 words, got {}", colors::red("✗"), count);
+            false
+        }
+        Err(Error::InvalidChecksum) => {
+            println!("{} All words valid but checksum incorrect.", colors::red("✗"));
+            false
+        }
+        // Unknown words were reported above
+        Err(_) => false,
+    }
+}
+
+/// Print the `--check-ss58` report and return whether the address is valid
+pub fn print_ss58_check(address: &str) -> bool {
+    match crate::validation::decode_ss58_address(address) {
+        Ok(decoded) => {
+            let network = crate::networks::find_network_by_prefix(decoded.ss58_prefix);
+            println!("SS58 prefix: {}", decoded.ss58_prefix);
+            println!("Network:     {}", network.map(|n| n.name).unwrap_or("Unknown"));
+            println!("Public key:  0x{}", hex::encode(decoded.public_key));
+            println!("Checksum:    {} ({})", colors::green("valid"), hex::encode(decoded.checksum));
+            println!("{} Valid SS58 address", colors::green("✓"));
+            true
+        }
+        Err(e) => {
+            println!("{} Invalid SS58 address. {} failed: {}", colors::red("✗"), e.step(), e);
+            false
+        }
+    }
+}
+
+/// Print the `--validate-dir` table and return whether every wallet is valid
+pub fn print_directory_validation(dir: &str, results: &[(String, ValidationResult)], has_password: bool) -> bool {
+    if results.is_empty() {
+        println!("{}: no wallet files found in {}", colors::yellow("WARNING"), dir);
+        return true;
+    }
+
+    let rows: Vec<(&str, String, &ValidationResult)> = results
+        .iter()
+        .map(|(file, result)| {
+            let address = crate::validation::wallet_file_address(&std::path::Path::new(dir).join(file)).unwrap_or_default();
+            (file.as_str(), address, result)
+        })
+        .collect();
+    let file_width = rows.iter().map(|(file, _, _)| file.len()).max().unwrap_or(0).max("File".len());
+    let address_width = rows.iter().map(|(_, address, _)| address.len()).max().unwrap_or(0).max("Address".len());
+
+    println!("{:<file_width$}  {:<address_width$}  Status", "File", "Address");
+    println!("{}", "─".repeat(file_width + address_width + 10));
+    for (file, address, result) in &rows {
+        let line = format!("{:<file_width$}  {:<address_width$}  {}", file, address, result.status_symbol());
+        match result {
+            ValidationResult::Valid => println!("{}", line),
+            _ => println!("{}", colors::red(&line)),
+        }
+    }
+
+    let valid = results.iter().filter(|(_, result)| *result == ValidationResult::Valid).count();
+    println!("\n{}/{} wallets valid", valid, results.len());
+    if !has_password && results.iter().any(|(file, _)| file.ends_with(".json")) {
+        println!("SUGGESTION: JSON wallets can only be checked with --pass");
+    }
+    valid == results.len()
+}
@@ -0,0 +1,50 @@
+//! Diagnostic output through `tracing`
+//!
+//! Errors, warnings and debug traces go to stderr through a `tracing_subscriber`
+//! installed once at startup. Only errors are shown by default; `--log-level` or
+//! `RUST_LOG` raise the verbosity.
+//!
+//! Secrets (mnemonics, hex seeds, passwords) must never be passed to a logging
+//! macro, whatever the level.
+
+use tracing_subscriber::EnvFilter;
+
+/// Log levels accepted by `--log-level`
+pub const LOG_LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+
+/// Pick the filter: `--log-level` wins over `RUST_LOG`, which wins over `error`
+pub fn log_filter(log_level: Option<&str>, rust_log: Option<&str>) -> EnvFilter {
+    match (log_level, rust_log) {
+        (Some(level), _) => EnvFilter::new(level),
+        (None, Some(directives)) => EnvFilter::try_new(directives).unwrap_or_else(|_| EnvFilter::new("error")),
+        (None, None) => EnvFilter::new("error"),
+    }
+}
+
+/// Install the stderr subscriber; later calls are ignored
+///
+/// Level names are colored unless color was turned off by `init_color_mode`.
+pub fn init_logging(log_level: Option<&str>) {
+    let rust_log = std::env::var(EnvFilter::DEFAULT_ENV).ok();
+
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(log_filter(log_level, rust_log.as_deref()))
+        .with_writer(std::io::stderr)
+        .with_ansi(crate::cli::terminal::colors::enabled())
+        .with_target(false)
+        .without_time()
+        .try_init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_filter_precedence() {
+        assert_eq!(log_filter(None, None).to_string(), "error");
+        assert_eq!(log_filter(None, Some("debug")).to_string(), "debug");
+        assert_eq!(log_filter(Some("warn"), Some("debug")).to_string(), "warn");
+        assert_eq!(log_filter(None, Some("not a [valid filter")).to_string(), "error");
+    }
+}
@@ -0,0 +1,164 @@
+//! Prometheus metrics endpoint (`--metrics-port`)
+//!
+//! A background thread serves `GET /metrics` in the Prometheus text format. The
+//! counters are plain atomics fed from the runner's progress and wallet callbacks,
+//! so scraping never takes a lock the workers contend on. Only public run figures
+//! are exported; nothing derived from a secret.
+
+use crate::runner::ProgressInfo;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// How often the server thread checks the stop flag while idle
+pub const METRICS_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Live run figures exported on `/metrics`
+pub struct Metrics {
+    keys_total: AtomicU64,
+    keys_per_second: AtomicU64,
+    wallets_found_total: AtomicU64,
+    luck_ratio_bits: AtomicU64, // f64 bits; std has no AtomicF64
+    worker_threads: AtomicU64,
+}
+
+impl Metrics {
+    pub fn new(worker_threads: usize) -> Self {
+        Self {
+            keys_total: AtomicU64::new(0),
+            keys_per_second: AtomicU64::new(0),
+            wallets_found_total: AtomicU64::new(0),
+            luck_ratio_bits: AtomicU64::new(f64::NAN.to_bits()),
+            worker_threads: AtomicU64::new(worker_threads as u64),
+        }
+    }
+
+    /// Update the throughput figures from a progress report
+    pub fn record_progress(&self, info: &ProgressInfo) {
+        self.keys_total.store(info.total_attempts, Ordering::Relaxed);
+        self.keys_per_second.store(info.keys_per_second, Ordering::Relaxed);
+        if let Some(luck) = info.luck {
+            self.luck_ratio_bits.store((luck / 100.0).to_bits(), Ordering::Relaxed);
+        }
+    }
+
+    /// Count a found wallet
+    pub fn record_wallet(&self) {
+        self.wallets_found_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Render every metric in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let luck_ratio = f64::from_bits(self.luck_ratio_bits.load(Ordering::Relaxed));
+        let metrics = [
+            ("autoseed_keys_total", "counter", "Keys generated and checked", self.keys_total.load(Ordering::Relaxed).to_string()),
+            ("autoseed_keys_per_second", "gauge", "Average keys checked per second", self.keys_per_second.load(Ordering::Relaxed).to_string()),
+            ("autoseed_wallets_found_total", "counter", "Matching wallets found", self.wallets_found_total.load(Ordering::Relaxed).to_string()),
+            ("autoseed_luck_ratio", "gauge", "Expected over actual attempts for the current wallet (NaN until known)", luck_ratio.to_string()),
+            ("autoseed_worker_threads", "gauge", "Search worker threads", self.worker_threads.load(Ordering::Relaxed).to_string()),
+        ];
+
+        let mut out = String::new();
+        for (name, kind, help, value) in metrics {
+            out.push_str(&format!("# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {value}\n"));
+        }
+        out
+    }
+}
+
+/// Serve `metrics` on `<bind>:<port>` until `stop` is set
+///
+/// Returns an error if the address can't be bound. The thread finishes the request in
+/// hand and exits within `METRICS_POLL_INTERVAL` of `stop` being set.
+pub fn spawn_metrics_server(bind: IpAddr, port: u16, metrics: Arc<Metrics>, stop: Arc<AtomicBool>) -> Result<JoinHandle<()>, String> {
+    let server = tiny_http::Server::http((bind, port))
+        .map_err(|e| format!("Failed to start metrics server on {bind} port {port}: {e}"))?;
+
+    Ok(thread::spawn(move || {
+        while !stop.load(Ordering::Relaxed) {
+            let request = match server.recv_timeout(METRICS_POLL_INTERVAL) {
+                Ok(Some(request)) => request,
+                Ok(None) => continue,
+                Err(e) => {
+                    tracing::warn!("Metrics server stopped: {e}");
+                    break;
+                }
+            };
+
+            let response = if *request.method() == tiny_http::Method::Get && request.url() == "/metrics" {
+                let content_type = tiny_http::Header::from_bytes("Content-Type", "text/plain; version=0.0.4")
+                    .expect("static header is valid");
+                tiny_http::Response::from_string(metrics.render()).with_header(content_type)
+            } else {
+                tiny_http::Response::from_string("Not Found").with_status_code(404)
+            };
+            if let Err(e) = request.respond(response) {
+                tracing::debug!("Failed to answer metrics request: {e}");
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+
+    fn progress(total_attempts: u64, keys_per_second: u64, luck: Option<f64>) -> ProgressInfo {
+        ProgressInfo {
+            count: 1,
+            elapsed_secs: 1,
+            eta_next_wallet: None,
+            eta_all_remaining: None,
+            found_count: 0,
+            keys_per_second,
+            luck,
+            thread_keys_per_second: Vec::new(),
+            total_attempts,
+        }
+    }
+
+    #[test]
+    fn test_render_reports_recorded_values() {
+        let metrics = Metrics::new(8);
+        assert!(metrics.render().contains("autoseed_luck_ratio NaN\n"));
+
+        metrics.record_progress(&progress(12_345, 6_789, Some(150.0)));
+        metrics.record_wallet();
+        metrics.record_progress(&progress(20_000, 7_000, None));
+
+        let text = metrics.render();
+        assert!(text.contains("# TYPE autoseed_keys_total counter\nautoseed_keys_total 20000\n"));
+        assert!(text.contains("autoseed_keys_per_second 7000\n"));
+        assert!(text.contains("autoseed_wallets_found_total 1\n"));
+        assert!(text.contains("autoseed_luck_ratio 1.5\n"));
+        assert!(text.contains("autoseed_worker_threads 8\n"));
+    }
+
+    #[test]
+    fn test_server_serves_metrics_and_stops() {
+        let metrics = Arc::new(Metrics::new(2));
+        let stop = Arc::new(AtomicBool::new(false));
+        // Find a free port, then hand it to the server
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let handle = spawn_metrics_server(IpAddr::from([127, 0, 0, 1]), port, metrics, stop.clone()).unwrap();
+
+        let get = |path: &str| {
+            let mut stream = std::net::TcpStream::connect(("127.0.0.1", port)).unwrap();
+            write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n").unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+
+        let response = get("/metrics");
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.contains("autoseed_worker_threads 2"));
+        assert!(get("/other").starts_with("HTTP/1.1 404"));
+
+        stop.store(true, Ordering::Relaxed);
+        handle.join().unwrap();
+    }
+}
@@ -1,8 +1,19 @@
+#[cfg(not(target_arch = "wasm32"))]
 pub mod args;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod display;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod logging;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod metrics;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod password;
 pub mod probability;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod script;
 pub mod terminal;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod version_check;
 
 pub use probability::*;
-pub use terminal::*;
\ No newline at end of file
+pub use terminal::*;
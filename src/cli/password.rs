@@ -2,15 +2,61 @@ use crate::cli::terminal::{colors, terminal_codes, failed_indicator};
 use std::io::{self, Write};
 use zeroize::Zeroize;
 
-pub fn get_password_interactive() -> String {
+/// Coarse password strength shown during interactive entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordStrength {
+    Weak,
+    Fair,
+    Strong,
+}
+
+impl PasswordStrength {
+    /// Colored label for the strength indicator line
+    pub fn label(&self) -> String {
+        match self {
+            PasswordStrength::Weak => colors::red("Weak"),
+            PasswordStrength::Fair => colors::yellow("Fair"),
+            PasswordStrength::Strong => colors::green("Strong"),
+        }
+    }
+}
+
+/// Score a password one point each for length >= 8, length >= 12, uppercase,
+/// lowercase, digit and special character (0-2 Weak, 3-4 Fair, 5-6 Strong)
+pub fn password_strength(password: &str) -> PasswordStrength {
+    let length = password.chars().count();
+    let checks = [
+        length >= 8,
+        length >= 12,
+        password.chars().any(|c| c.is_uppercase()),
+        password.chars().any(|c| c.is_lowercase()),
+        password.chars().any(|c| c.is_ascii_digit()),
+        password.chars().any(|c| !c.is_alphanumeric()),
+    ];
+
+    match checks.iter().filter(|&&passed| passed).count() {
+        0..=2 => PasswordStrength::Weak,
+        3..=4 => PasswordStrength::Fair,
+        _ => PasswordStrength::Strong,
+    }
+}
+
+/// Attempts allowed to enter two matching passwords before giving up
+const MAX_CONFIRMATION_ATTEMPTS: usize = 3;
+
+/// Prompt for the wallet encryption password twice with echo disabled
+///
+/// Loops until both entries match, exiting after three mismatches.
+pub fn get_password_with_confirmation() -> String {
     println!("╔══════════════════════════════════════════════════════════════════════╗");
     println!("║ {}: If you lose this password, you will NOT be able to access   ║", colors::yellow("WARNING"));
     println!("║ your wallet! Make sure to store it in a safe place.                  ║");
     println!("╚══════════════════════════════════════════════════════════════════════╝");
     println!();
 
+    let mut mismatches = 0;
     loop {
-        print!("Enter a password to encrypt your wallets: ");
+        print!("Enter password: ");
         io::stdout().flush().unwrap();
         let mut pwd1 = match rpassword::read_password() {
             Ok(pwd) => {
@@ -28,11 +74,14 @@ pub fn get_password_interactive() -> String {
             Err(e) => {
                 print!("{}", terminal_codes::CURSOR_UP_CLEAR);
                 crate::cli::terminal::reset_terminal(); // Restore terminal state
-                eprintln!("{} Failed to read password: {e}. Exiting.", failed_indicator());
+                tracing::error!("Failed to read password: {e}. Exiting.");
                 std::process::exit(1);
             }
         };
 
+        // Warn about weak passwords without rejecting them
+        println!("Password strength: {}", password_strength(&pwd1).label());
+
         print!("Confirm password: ");
         io::stdout().flush().unwrap();
         let mut pwd2 = match rpassword::read_password() {
@@ -43,7 +92,7 @@ pub fn get_password_interactive() -> String {
             Err(e) => {
                 print!("{}", terminal_codes::CURSOR_UP_CLEAR);
                 crate::cli::terminal::reset_terminal(); // Restore terminal state
-                eprintln!("{} Failed to read password: {e}. Exiting.", failed_indicator());
+                tracing::error!("Failed to read password: {e}. Exiting.");
                 std::process::exit(1);
             }
         };
@@ -52,7 +101,14 @@ pub fn get_password_interactive() -> String {
             // Zeroize passwords before retry
             pwd1.zeroize();
             pwd2.zeroize();
-            print!("{}", colors::red("Passwords do not match. Please try again."));
+            mismatches += 1;
+            print!("{}", terminal_codes::CURSOR_UP_CLEAR); // Clear the strength line
+            if mismatches >= MAX_CONFIRMATION_ATTEMPTS {
+                crate::cli::terminal::reset_terminal(); // Restore terminal state
+                tracing::error!("Passwords did not match after {MAX_CONFIRMATION_ATTEMPTS} attempts. Exiting.");
+                std::process::exit(1);
+            }
+            print!("{}", colors::red("Passwords do not match, try again"));
             io::stdout().flush().unwrap();
             std::thread::sleep(std::time::Duration::from_secs(2));
             print!("{}", terminal_codes::CR_CLEAR_LINE);
@@ -66,4 +122,18 @@ pub fn get_password_interactive() -> String {
         println!(); // Add blank line after confirmation
         break pwd1;
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_password_strength() {
+        assert_eq!(password_strength(""), PasswordStrength::Weak);
+        assert_eq!(password_strength("password"), PasswordStrength::Weak);
+        assert_eq!(password_strength("Password1"), PasswordStrength::Fair);
+        assert_eq!(password_strength("Password1!"), PasswordStrength::Strong);
+        assert_eq!(password_strength("CorrectHorse9!"), PasswordStrength::Strong);
+    }
+}
@@ -1,5 +1,243 @@
+use crate::crypto::rng::{ChaCha12Rng, ChaCha20Rng};
+use crate::crypto::substrate::crypto::{AccountId32, Ss58AddressFormat, Ss58Codec};
+use crate::search::{search_with_config, OptimizedSearchConfig};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
 
+/// Addresses sampled when a network's character model is first needed
+///
+/// `test_address_model_matches_large_sample` checks the result against a sample of
+/// `CALIBRATION_SAMPLE_ADDRESSES`.
+const MODEL_SAMPLE_ADDRESSES: usize = 20_000;
 
+/// Addresses in the calibration sample the runtime model is tested against
+#[cfg(test)]
+const CALIBRATION_SAMPLE_ADDRESSES: usize = 1_000_000;
+
+/// Addresses sampled by `--analyze-prefix-distribution`
+pub const DISTRIBUTION_SAMPLE_ADDRESSES: usize = 1_000_000;
+
+/// Base58 characters in alphabet order, for tables and reports
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Prefix distribution report file name inside the output directory
+pub const PREFIX_DISTRIBUTION_FILENAME: &str = "prefix_distribution.json";
+
+/// Most patterns `--suggest-pattern` prints
+pub const MAX_PATTERN_SUGGESTIONS: usize = 20;
+
+/// How far a suggestion's expected attempts may be from the target, as a fraction
+const SUGGESTION_TOLERANCE: f64 = 0.1;
+
+/// Empirical Base58 character frequencies for one network's addresses
+///
+/// SS58 addresses are not uniform over the 58 Base58 characters: the compressed
+/// Ristretto point always has an even first byte and a clear top bit, and the
+/// prefix bytes shift which leading digits the encoding can produce. The model
+/// counts every character outside the fixed network prefix over a sample of
+/// addresses built from random points of that shape.
+pub struct AddressModel {
+    pub ss58_prefix: u16,
+    pub character_frequency: HashMap<char, f64>,
+}
+
+impl AddressModel {
+    /// Build a model from `addresses` sampled addresses
+    ///
+    /// The sample is seeded from the prefix, so the same arguments always produce
+    /// the same model.
+    pub fn sample(ss58_prefix: u16, addresses: usize) -> Self {
+        let mut rng = sample_rng(ss58_prefix, addresses);
+
+        // Characters covered by the network prefix never vary
+        let skip = crate::networks::find_network_by_prefix(ss58_prefix)
+            .and_then(|network| network.address_prefixes.iter().map(|p| p.len()).min())
+            .unwrap_or(0);
+
+        let mut counts: HashMap<char, u64> = HashMap::new();
+        let mut total = 0u64;
+        for _ in 0..addresses {
+            for ch in random_address(|bytes| rng.fill_bytes(bytes), ss58_prefix).chars().skip(skip) {
+                *counts.entry(ch).or_insert(0) += 1;
+                total += 1;
+            }
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let character_frequency = counts
+            .into_iter()
+            .map(|(ch, count)| (ch, count as f64 / total as f64))
+            .collect();
+
+        Self { ss58_prefix, character_frequency }
+    }
+
+    /// Shared model for a network, sampled on first use
+    pub fn for_prefix(ss58_prefix: u16) -> &'static AddressModel {
+        static MODELS: OnceLock<Mutex<HashMap<u16, &'static AddressModel>>> = OnceLock::new();
+
+        let mut models = MODELS
+            .get_or_init(|| Mutex::new(HashMap::new()))
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        models
+            .entry(ss58_prefix)
+            .or_insert_with(|| Box::leak(Box::new(Self::sample(ss58_prefix, MODEL_SAMPLE_ADDRESSES))))
+    }
+
+    /// Probability that one random address character matches `ch`
+    pub fn match_probability(&self, ch: char, case_sensitive: bool) -> f64 {
+        if ch == '?' {
+            return 1.0; // Wildcard matches any character
+        }
+
+        let frequency = |c: char| self.character_frequency.get(&c).copied().unwrap_or(0.0);
+        let upper = ch.to_ascii_uppercase();
+        let lower = ch.to_ascii_lowercase();
+        if case_sensitive || upper == lower {
+            frequency(ch)
+        } else {
+            // Only case variants that exist in Base58 have a frequency
+            frequency(upper) + frequency(lower)
+        }
+    }
+
+    /// Expected random characters needed to match `ch`, i.e. 1 / probability
+    pub fn odds(&self, ch: char, case_sensitive: bool) -> f64 {
+        1.0 / self.match_probability(ch, case_sensitive)
+    }
+}
+
+/// RNG for a reproducible address sample, seeded from the prefix and sample size
+fn sample_rng(ss58_prefix: u16, addresses: usize) -> ChaCha20Rng {
+    let mut seed = [0u8; 32];
+    seed[..2].copy_from_slice(&ss58_prefix.to_le_bytes());
+    seed[2..10].copy_from_slice(&(addresses as u64).to_le_bytes());
+    ChaCha20Rng::from_seed(seed)
+}
+
+/// How often each character appears at each address position over `addresses`
+/// sampled addresses
+///
+/// Index `i` maps every character observed at position `i` to its count. Like
+/// `AddressModel::sample`, the same arguments always give the same result.
+pub fn character_counts_by_position(ss58_prefix: u16, addresses: usize) -> Vec<HashMap<char, u64>> {
+    let mut rng = sample_rng(ss58_prefix, addresses);
+    let mut positions: Vec<HashMap<char, u64>> = Vec::new();
+
+    for _ in 0..addresses {
+        for (pos, ch) in random_address(|bytes| rng.fill_bytes(bytes), ss58_prefix).chars().enumerate() {
+            if pos == positions.len() {
+                positions.push(HashMap::new());
+            }
+            *positions[pos].entry(ch).or_insert(0) += 1;
+        }
+    }
+
+    positions
+}
+
+/// Heat-map cell for a character seen `count` times where a uniform distribution
+/// over the 58 Base58 characters expects `uniform`
+fn heat_cell(count: u64, uniform: f64) -> char {
+    if count == 0 {
+        return ' ';
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let ratio = count as f64 / uniform;
+    match ratio {
+        r if r < 0.5 => '·',
+        r if r < 0.9 => '░',
+        r if r < 1.1 => '▒',
+        r if r < 2.0 => '▓',
+        _ => '█',
+    }
+}
+
+/// Print a per-position heat map of character frequencies (`--analyze-prefix-distribution`)
+pub fn print_prefix_distribution(ss58_prefix: u16, counts: &[HashMap<char, u64>], addresses: usize) {
+    use crate::cli::terminal::colors;
+    use crate::cli::terminal::number_locale;
+    use num_format::ToFormattedString;
+
+    #[allow(clippy::cast_precision_loss)]
+    let uniform = addresses as f64 / 58.0;
+    let network_name = crate::networks::find_network_by_prefix(ss58_prefix)
+        .map(|n| n.name)
+        .unwrap_or("Custom");
+
+    println!("{}", colors::yellow("PREFIX DISTRIBUTION"));
+    println!("\n{} {} (SS58: {}), {} sampled addresses", colors::yellow("→ Network:"), network_name, ss58_prefix,
+             addresses.to_formatted_string(&number_locale()));
+    println!("{}", colors::gray("Frequency against uniform (1/58): ' ' never, '·' <0.5x, '░' <0.9x, '▒' ~1x, '▓' <2x, '█' ≥2x"));
+    println!();
+    println!("  Pos  {BASE58_ALPHABET}  Seen");
+
+    for (pos, position_counts) in counts.iter().enumerate() {
+        let row: String = BASE58_ALPHABET
+            .chars()
+            .map(|ch| heat_cell(position_counts.get(&ch).copied().unwrap_or(0), uniform))
+            .collect();
+        println!("  {pos:>3}  {row}  {:>4}", position_counts.len());
+    }
+
+    println!("\n{}", colors::yellow("→ Restricted Positions:"));
+    let mut any_restricted = false;
+    for (pos, position_counts) in counts.iter().enumerate() {
+        if position_counts.len() < 58 {
+            let seen: String = BASE58_ALPHABET.chars().filter(|ch| position_counts.contains_key(ch)).collect();
+            println!("  {pos:>3}  only {seen}");
+            any_restricted = true;
+        }
+    }
+    if !any_restricted {
+        println!("  Every character appears at every position");
+    }
+}
+
+/// Write `<output_dir>/prefix_distribution.json` with the raw per-position counts
+pub fn write_prefix_distribution(
+    ss58_prefix: u16,
+    counts: &[HashMap<char, u64>],
+    addresses: usize,
+    output_dir: &str,
+) -> Result<(), String> {
+    let positions: Vec<serde_json::Value> = counts
+        .iter()
+        .enumerate()
+        .map(|(pos, position_counts)| {
+            let counts: serde_json::Map<String, serde_json::Value> = BASE58_ALPHABET
+                .chars()
+                .filter_map(|ch| position_counts.get(&ch).map(|&count| (ch.to_string(), count.into())))
+                .collect();
+            serde_json::json!({ "position": pos, "counts": counts })
+        })
+        .collect();
+
+    let report = serde_json::json!({
+        "ss58_prefix": ss58_prefix,
+        "addresses": addresses,
+        "positions": positions,
+    });
+    let json_str = serde_json::to_string_pretty(&report)
+        .map_err(|e| format!("Failed to serialize prefix distribution: {}", e))?;
+
+    let output_path = format!("{}/{}", output_dir, PREFIX_DISTRIBUTION_FILENAME);
+    crate::wallet::write_file_durable(&output_path, json_str)
+        .map_err(|e| format!("Failed to write prefix distribution: {}", e))
+}
+
+/// Encode a random Ristretto-shaped public key (even first byte, clear top bit)
+/// as an SS58 address; `fill_bytes` supplies the random bytes
+fn random_address(fill_bytes: impl FnOnce(&mut [u8]), ss58_prefix: u16) -> String {
+    let mut public_key = [0u8; 32];
+    fill_bytes(&mut public_key);
+    public_key[0] &= 0xfe;
+    public_key[31] &= 0x7f;
+    AccountId32::from(public_key).to_ss58check_with_version(Ss58AddressFormat::custom(ss58_prefix))
+}
 
 /// Calculate how many characters overlap with the network prefix
 fn calculate_prefix_overlap(pattern: &str, ss58_prefix: u16, within: usize) -> usize {
@@ -42,223 +280,374 @@ fn calculate_prefix_overlap(pattern: &str, ss58_prefix: u16, within: usize) -> u
     max_overlap
 }
 
-/// Determine the effective character set size for a specific character in Base58
-fn get_character_set_size(ch: char, case_sensitive: bool) -> u64 {
-    if ch == '?' {
-        return 1; // Wildcard matches any character, so no difficulty
-    }
+/// Check whether a pattern starting at `pos` is compatible with the fixed network
+/// prefix characters (positions past every prefix are always compatible)
+pub fn pattern_fits_network_prefix(pattern: &str, pos: usize, case_sensitive: bool, ss58_prefix: u16) -> bool {
+    let network_prefixes = if let Some(network) = crate::networks::find_network_by_prefix(ss58_prefix) {
+        network.address_prefixes
+    } else {
+        return true; // Unknown network, no locked positions
+    };
     
-    if case_sensitive {
-        return 58; // All Base58 characters are distinct
+    let max_prefix_len = network_prefixes.iter().map(|p| p.len()).max().unwrap_or(0);
+    if pos >= max_prefix_len {
+        // Position is beyond any prefix constraints
+        return true;
     }
     
-    // For case-insensitive mode, check if the character has case variants
-    match ch {
-        // Digits (0-9) - but 0 is not in Base58, so only 1-9
-        '1'..='9' => 58, // Numbers have no case variants, must match exactly
+    let pattern_chars: Vec<char> = pattern.chars().collect();
+    
+    // Check if pattern at this position could match with the network prefix
+    for prefix in network_prefixes {
+        let prefix_len = prefix.len();
+        if pos >= prefix_len {
+            // This position is past this particular prefix
+            continue;
+        }
         
-        // Letters that exist in both cases in Base58
-        // Base58 includes: A-H, J-N, P-Z (uppercase) and a-k, m-z (lowercase)
-        // Missing: I, O, l (to avoid confusion)
-        'A' | 'a' => 33, // 'A' and 'a' are both valid
-        'B' | 'b' => 33, // 'B' and 'b' are both valid  
-        'C' | 'c' => 33, // 'C' and 'c' are both valid
-        'D' | 'd' => 33, // 'D' and 'd' are both valid
-        'E' | 'e' => 33, // 'E' and 'e' are both valid
-        'F' | 'f' => 33, // 'F' and 'f' are both valid
-        'G' | 'g' => 33, // 'G' and 'g' are both valid
-        'H' | 'h' => 33, // 'H' and 'h' are both valid
-        'J' | 'j' => 33, // 'J' and 'j' are both valid
-        'K' | 'k' => 33, // 'K' and 'k' are both valid
-        'M' | 'm' => 33, // 'M' and 'm' are both valid
-        'N' | 'n' => 33, // 'N' and 'n' are both valid
-        'P' | 'p' => 33, // 'P' and 'p' are both valid
-        'Q' | 'q' => 33, // 'Q' and 'q' are both valid
-        'R' | 'r' => 33, // 'R' and 'r' are both valid
-        'S' | 's' => 33, // 'S' and 's' are both valid
-        'T' | 't' => 33, // 'T' and 't' are both valid
-        'U' | 'u' => 33, // 'U' and 'u' are both valid
-        'V' | 'v' => 33, // 'V' and 'v' are both valid
-        'W' | 'w' => 33, // 'W' and 'w' are both valid
-        'X' | 'x' => 33, // 'X' and 'x' are both valid
-        'Y' | 'y' => 33, // 'Y' and 'y' are both valid
-        'Z' | 'z' => 33, // 'Z' and 'z' are both valid
+        let prefix_chars: Vec<char> = prefix.chars().collect();
+        let matches = (0..pattern_chars.len().min(prefix_len - pos)).all(|i| {
+            let pattern_char = pattern_chars[i];
+            let prefix_char = prefix_chars[pos + i];
+            
+            if pattern_char == '?' {
+                true // Wildcard always matches
+            } else if case_sensitive {
+                pattern_char == prefix_char
+            } else {
+                pattern_char.eq_ignore_ascii_case(&prefix_char)
+            }
+        });
         
-        // Characters that only exist in one case in Base58
-        // These must match exactly even in case-insensitive mode
-        _ => 58, // All other characters (or invalid ones) default to case-sensitive
+        if matches {
+            return true; // Pattern can match with at least one prefix variant
+        }
     }
+    
+    false // Pattern conflicts with all prefix requirements at this position
 }
 
-/// Calculate the probability for a pattern at a specific position
-fn calculate_probability_at_position(
-    pattern: &str,
-    position: usize,
-    case_sensitive: bool,
-    ss58_prefix: u16,
-) -> u64 {
-    let mut probability = 1u64;
-    
-    // Check if this position allows prefix overlap (position 0)
-    let prefix_overlap = if position == 0 {
-        calculate_prefix_overlap(pattern, ss58_prefix, pattern.len())
+/// Characters each address position can hold for `ss58_prefix`, derived from the
+/// prefix encoding instead of sampled
+///
+/// An address is the Base58 form of `prefix ++ key ++ checksum` read as one number.
+/// With the prefix bytes fixed, every payload lies in one interval, and over the
+/// numbers of an interval with the same digit count each position takes a run of
+/// consecutive digits. Key and checksum bytes are treated as unconstrained, so a set
+/// can hold a character no real address does but never misses one that it does.
+/// Leading zero bytes (prefix 0, and keys starting with zeros) encode as `1`s.
+pub fn possible_characters_by_position(ss58_prefix: u16) -> Vec<HashSet<char>> {
+    const KEY_AND_CHECKSUM_LEN: usize = 34;
+
+    let zero_key = AccountId32::from([0u8; 32]).to_ss58check_with_version(Ss58AddressFormat::custom(ss58_prefix));
+    let payload = crate::crypto::bs58::decode(&zero_key).into_vec().expect("SS58 encoding is valid Base58");
+    let prefix = &payload[..payload.len() - KEY_AND_CHECKSUM_LEN];
+
+    let mut positions = Vec::new();
+    let leading_zeros = prefix.iter().take_while(|&&byte| byte == 0).count();
+    (0..leading_zeros).for_each(|pos| {
+        position_mut(&mut positions, pos).insert('1');
+    });
+
+    let prefix = &prefix[leading_zeros..];
+    if prefix.is_empty() {
+        // Every leading zero byte of the key adds another '1' before the digits start
+        for key_zeros in 0..KEY_AND_CHECKSUM_LEN {
+            (0..key_zeros).for_each(|i| {
+                position_mut(&mut positions, leading_zeros + i).insert('1');
+            });
+            let len = KEY_AND_CHECKSUM_LEN - key_zeros;
+            let mut low = vec![0x00; len];
+            low[0] = 0x01;
+            add_interval_digits(&mut positions, leading_zeros + key_zeros, &low, &vec![0xff; len]);
+        }
     } else {
-        0
+        let low = [prefix, &[0x00; KEY_AND_CHECKSUM_LEN]].concat();
+        let high = [prefix, &[0xff; KEY_AND_CHECKSUM_LEN]].concat();
+        add_interval_digits(&mut positions, leading_zeros, &low, &high);
+    }
+
+    positions
+}
+
+/// The set for address position `pos`, adding empty sets up to it as needed
+fn position_mut(positions: &mut Vec<HashSet<char>>, pos: usize) -> &mut HashSet<char> {
+    if positions.len() <= pos {
+        positions.resize_with(pos + 1, HashSet::new);
+    }
+    &mut positions[pos]
+}
+
+/// Add the characters each Base58 digit takes over the numbers `low..=high`
+/// (big-endian, `low` with a non-zero first byte), starting at address position `offset`
+fn add_interval_digits(positions: &mut Vec<HashSet<char>>, offset: usize, low: &[u8], high: &[u8]) {
+    let alphabet = BASE58_ALPHABET.as_bytes();
+    let digits = |bytes: &[u8]| -> Vec<usize> {
+        crate::crypto::bs58::encode(bytes)
+            .into_string()
+            .bytes()
+            .map(|ch| alphabet.iter().position(|&a| a == ch).expect("Base58 output uses the Base58 alphabet"))
+            .collect()
     };
-    
-    // Calculate probability for each character
-    let mut processed_chars = 0;
-    for ch in pattern.chars() {
-        // Skip characters that overlap with network prefix at position 0
-        if position == 0 && processed_chars < prefix_overlap {
-            processed_chars += 1;
-            continue;
+    let (low, high) = (digits(low), digits(high));
+
+    // Split the interval by digit count: position `i` holds a different digit in each part
+    for len in low.len()..=high.len() {
+        let first = if len == low.len() { low.clone() } else { [vec![1], vec![0; len - 1]].concat() };
+        let last = if len == high.len() { high.clone() } else { vec![57; len] };
+
+        // How far apart `first` and `last` are in their leading i + 1 digits, capped
+        // once the run covers every digit
+        let mut spread = 0;
+        for i in 0..len {
+            spread = (spread * 58 + last[i] - first[i]).min(58);
+            let cell = position_mut(positions, offset + i);
+            for step in 0..=spread.min(57) {
+                cell.insert(char::from(alphabet[(first[i] + step) % 58]));
+            }
+        }
+    }
+}
+
+/// Start positions a pattern can match at and the odds of each, for one search
+///
+/// Shared by `calculate_expected_attempts` and `print_probability_breakdown`, so the
+/// number shown and the number used for ETA and luck always agree. `search_range` is
+/// the inclusive range of start positions the search checks
+/// (`OptimizedSearchConfig::search_range`); `None` means the pattern cannot fit.
+pub struct PositionCalculator<'a> {
+    pattern: &'a str,
+    case_sensitive: bool,
+    search_range: Option<(usize, usize)>,
+    ss58_prefix: u16,
+}
+
+impl<'a> PositionCalculator<'a> {
+    pub fn new(pattern: &'a str, case_sensitive: bool, search_range: Option<(usize, usize)>, ss58_prefix: u16) -> Self {
+        Self { pattern, case_sensitive, search_range, ss58_prefix }
+    }
+
+    /// Start positions in the search range not locked out by the network prefix
+    pub fn valid_positions(&self) -> Vec<usize> {
+        match self.search_range {
+            Some((start, end)) => (start..=end)
+                .filter(|&pos| pattern_fits_network_prefix(self.pattern, pos, self.case_sensitive, self.ss58_prefix))
+                .collect(),
+            None => vec![], // Pattern doesn't fit
+        }
+    }
+
+    /// Characters at the start of the pattern that the network prefix supplies when
+    /// it starts at `pos` (only position 0 overlaps the prefix)
+    pub fn prefix_overlap_at(&self, pos: usize) -> usize {
+        if pos == 0 {
+            calculate_prefix_overlap(self.pattern, self.ss58_prefix, self.pattern.len())
+        } else {
+            0
+        }
+    }
+
+    /// One-in-N odds of the pattern matching at `pos`
+    pub fn probability_at(&self, pos: usize) -> u64 {
+        let model = AddressModel::for_prefix(self.ss58_prefix);
+        // Characters overlapping the network prefix always match
+        let probability: f64 = self
+            .pattern
+            .chars()
+            .skip(self.prefix_overlap_at(pos))
+            .map(|ch| model.odds(ch, self.case_sensitive))
+            .product();
+
+        // Float-to-int casts saturate, so impossible characters map to u64::MAX
+        probability.round() as u64
+    }
+
+    /// Expected attempts over every valid position, via the harmonic mean
+    ///
+    /// Expected attempts = 1 / (sum of 1/probability for each position)
+    pub fn expected_attempts(&self) -> u64 {
+        let reciprocal_sum: f64 = self
+            .valid_positions()
+            .into_iter()
+            .map(|pos| self.probability_at(pos))
+            .filter(|&probability| probability > 0)
+            .map(|probability| 1.0 / probability as f64)
+            .sum();
+
+        if reciprocal_sum > 0.0 {
+            (1.0 / reciprocal_sum).round() as u64
+        } else {
+            u64::MAX // No valid positions, pattern can't be found
         }
-        
-        let char_set_size = get_character_set_size(ch, case_sensitive);
-        probability = probability.saturating_mul(char_set_size);
-        processed_chars += 1;
     }
-    
-    probability
 }
 
 /// Calculate expected attempts for finding a match using harmonic mean
+///
+/// `search_range` is the inclusive range of start positions the search checks
+/// (`OptimizedSearchConfig::search_range`); `None` means the pattern cannot fit.
 pub fn calculate_expected_attempts(
+    pattern: &str,
+    case_sensitive: bool,
+    search_range: Option<(usize, usize)>,
+    ss58_prefix: u16,
+) -> u64 {
+    PositionCalculator::new(pattern, case_sensitive, search_range, ss58_prefix).expected_attempts()
+}
+
+/// Estimate expected attempts by searching `samples` random addresses
+///
+/// Cross-checks `calculate_expected_attempts` against the real search code:
+/// returns samples / matches, or infinity when nothing matched.
+pub fn estimate_expected_attempts_monte_carlo(
     pattern: &str,
     case_sensitive: bool,
     suffix: bool,
     anywhere: bool,
     within: usize,
     ss58_prefix: u16,
-) -> u64 {
-    const SS58_ADDRESS_LENGTH: usize = 49;
-    let pattern_len = pattern.chars().count();
-    
-    // Get the network prefix to check for locked positions
-    let network_prefixes = if let Some(network) = crate::networks::find_network_by_prefix(ss58_prefix) {
-        network.address_prefixes
+    samples: u64,
+) -> f64 {
+    let config = OptimizedSearchConfig::new_with_prefix(pattern, case_sensitive, suffix, anywhere, within, ss58_prefix);
+    if !config.is_valid() {
+        return f64::INFINITY;
+    }
+
+    // Sampled public keys need no secrecy, so the faster 12-round ChaCha is enough
+    let mut rng = ChaCha12Rng::from_system_entropy().expect("Failed to initialize RNG from system entropy");
+    let matches = (0..samples)
+        .filter(|_| search_with_config(&config, &random_address(|bytes| rng.fill_bytes(bytes), ss58_prefix)).count > 0)
+        .count();
+
+    if matches == 0 {
+        f64::INFINITY
     } else {
-        &[] // Unknown network, no locked positions
-    };
-    
-    let max_prefix_len = network_prefixes.iter().map(|p| p.len()).max().unwrap_or(0);
-    
-    // Helper function to check if pattern can match at a given position
-    let can_match_at_position = |pos: usize| -> bool {
-        if pos >= max_prefix_len {
-            // Position is beyond any prefix constraints
-            return true;
-        }
-        
-        // Check if pattern at this position could match with the network prefix
-        for prefix in network_prefixes {
-            let prefix_len = prefix.len();
-            if pos >= prefix_len {
-                // This position is past this particular prefix
-                continue;
-            }
-            
-            // Check if pattern starting at 'pos' is compatible with the prefix
-            let mut matches = true;
-            let pattern_chars: Vec<char> = pattern.chars().collect();
-            let prefix_chars: Vec<char> = prefix.chars().collect();
-            
-            for i in 0..pattern_len.min(prefix_len - pos) {
-                let pattern_char = pattern_chars[i];
-                let prefix_char = prefix_chars[pos + i];
-                
-                if pattern_char == '?' {
-                    continue; // Wildcard always matches
-                }
-                
-                if case_sensitive {
-                    if pattern_char != prefix_char {
-                        matches = false;
-                        break;
-                    }
-                } else {
-                    if !pattern_char.eq_ignore_ascii_case(&prefix_char) {
-                        matches = false;
-                        break;
-                    }
-                }
-            }
-            
-            if matches {
-                return true; // Pattern can match with at least one prefix variant
-            }
-        }
-        
-        false // Pattern conflicts with all prefix requirements at this position
-    };
-    
-    // Determine valid positions based on mode
-    let positions: Vec<usize> = if anywhere {
-        let max_pos = SS58_ADDRESS_LENGTH.saturating_sub(pattern_len);
-        let positions_range = if within >= SS58_ADDRESS_LENGTH {
-            // Pattern can appear anywhere (but respect prefix locks)
-            0..=max_pos
-        } else if within >= pattern_len {
-            // Limited to within window
-            0..=within.saturating_sub(pattern_len).min(max_pos)
-        } else {
-            return u64::MAX; // Pattern doesn't fit
-        };
-        
-        // Filter out positions that conflict with network prefix
-        positions_range.filter(|&pos| can_match_at_position(pos)).collect()
-    } else if suffix {
-        if within >= SS58_ADDRESS_LENGTH {
-            // Can appear anywhere that ends within address
-            (0..=SS58_ADDRESS_LENGTH.saturating_sub(pattern_len)).collect()
-        } else if within >= pattern_len {
-            // Must END within the last 'within' characters
-            let start = SS58_ADDRESS_LENGTH.saturating_sub(within);
-            let end = SS58_ADDRESS_LENGTH.saturating_sub(pattern_len);
-            (start..=end).collect()
-        } else {
-            vec![] // Pattern doesn't fit
+        #[allow(clippy::cast_precision_loss)]
+        {
+            samples as f64 / matches as f64
         }
+    }
+}
+
+/// Print a Monte Carlo estimate next to the analytic expected attempts
+/// (`--probability --monte-carlo-samples <N>`)
+#[allow(clippy::too_many_arguments)]
+pub fn print_monte_carlo_comparison(
+    pattern: &str,
+    case_sensitive: bool,
+    suffix: bool,
+    anywhere: bool,
+    within: usize,
+    ss58_prefix: u16,
+    samples: u64,
+    expected_attempts: u64,
+) {
+    use crate::cli::terminal::colors;
+    use crate::cli::terminal::number_locale;
+    use num_format::ToFormattedString;
+
+    let locale = number_locale();
+
+    println!("{}", colors::yellow(&format!("→ MONTE CARLO CHECK ({} random addresses):", samples.to_formatted_string(&locale))));
+    let estimate = estimate_expected_attempts_monte_carlo(pattern, case_sensitive, suffix, anywhere, within, ss58_prefix, samples);
+    if estimate.is_infinite() {
+        println!("  {}", colors::gray("No matches in the sample - increase --monte-carlo-samples"));
     } else {
-        // Prefix mode: must START within first 'within' characters
-        if within >= pattern_len {
-            (0..=within.saturating_sub(pattern_len)).collect()
-        } else {
-            vec![] // Pattern doesn't fit
-        }
-    };
-    
-    if positions.is_empty() {
-        // No valid positions, pattern can't be found
-        return u64::MAX;
+        #[allow(clippy::cast_precision_loss)]
+        let deviation = (estimate - expected_attempts as f64) / expected_attempts as f64 * 100.0;
+        println!("  Estimated ~{} attempts ({:+.1}% vs analytic ~{})",
+                 (estimate.round() as u64).to_formatted_string(&locale),
+                 deviation,
+                 expected_attempts.to_formatted_string(&locale));
     }
-    
-    if positions.len() == 1 {
-        // Only one position, simple calculation
-        return calculate_probability_at_position(pattern, positions[0], case_sensitive, ss58_prefix);
+
+    println!();
+}
+
+/// Expected attempts a pattern may need for a search of `budget` attempts to find it
+/// with `luck_pct` percent probability
+///
+/// Inverts `luck_percentile`: `P = 1 - (1 - 1/E)^B ≈ 1 - e^(-B/E)`, so
+/// `E = -B / ln(1 - P)`.
+pub fn target_expected_attempts(budget: u64, luck_pct: f64) -> u64 {
+    #[allow(clippy::cast_precision_loss)]
+    let expected = -(budget as f64) / (-luck_pct / 100.0).ln_1p();
+    // Float-to-int casts saturate, so 0% maps to u64::MAX
+    expected.round() as u64
+}
+
+/// Every 2- and 3-character pattern whose expected attempts are within 10% of
+/// `target_attempts`, easiest first
+///
+/// Patterns are case-insensitive, so each is listed once in lowercase. A `within`
+/// shorter than a pattern is raised to its length; 0 gives a suffix search each
+/// pattern's exact length, as `--within` defaults to.
+pub fn suggest_patterns(target_attempts: u64, ss58_prefix: u16, within: usize, suffix: bool, anywhere: bool) -> Vec<(String, u64)> {
+    let mut alphabet: Vec<char> = BASE58_ALPHABET.chars().map(|c| c.to_ascii_lowercase()).collect();
+    alphabet.sort_unstable();
+    alphabet.dedup();
+
+    let two: Vec<String> = alphabet.iter().flat_map(|&a| alphabet.iter().map(move |&b| format!("{a}{b}"))).collect();
+    let three: Vec<String> = two.iter().flat_map(|ab| alphabet.iter().map(move |&c| format!("{ab}{c}"))).collect();
+
+    #[allow(clippy::cast_precision_loss)]
+    let tolerance = target_attempts as f64 * SUGGESTION_TOLERANCE;
+    let mut suggestions: Vec<(String, u64)> = two
+        .into_iter()
+        .chain(three)
+        .filter_map(|pattern| {
+            let within = within.max(pattern.len());
+            let config = OptimizedSearchConfig::new_with_prefix(&pattern, false, suffix, anywhere, within, ss58_prefix);
+            let expected = calculate_expected_attempts(&pattern, false, config.search_range, ss58_prefix);
+            #[allow(clippy::cast_precision_loss)]
+            let close = expected != u64::MAX && (expected as f64 - target_attempts as f64).abs() <= tolerance;
+            close.then_some((pattern, expected))
+        })
+        .collect();
+    suggestions.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    suggestions
+}
+
+/// Print the `--suggest-pattern` table
+///
+/// Shows the [`MAX_PATTERN_SUGGESTIONS`] patterns closest to the target, easiest first.
+pub fn print_pattern_suggestions(suggestions: &[(String, u64)], target_attempts: u64, budget: u64, luck_pct: u8) {
+    use crate::cli::terminal::colors;
+    use crate::cli::terminal::number_locale;
+    use num_format::ToFormattedString;
+
+    let locale = number_locale();
+    println!("{}", colors::yellow(&format!(
+        "→ PATTERNS WITH A {}% CHANCE IN {} ATTEMPTS (~{} expected attempts ±10%):",
+        luck_pct,
+        budget.to_formatted_string(&locale),
+        target_attempts.to_formatted_string(&locale)
+    )));
+    if suggestions.is_empty() {
+        println!("  {}", colors::gray("No 2- or 3-character pattern needs about that many attempts - try another --attempts or --luck-pct"));
+        return;
     }
-    
-    // Calculate harmonic mean for multiple positions with different probabilities
-    // Expected attempts = 1 / (sum of 1/probability for each position)
-    let mut reciprocal_sum = 0.0_f64;
-    
-    for &pos in &positions {
-        let probability = calculate_probability_at_position(pattern, pos, case_sensitive, ss58_prefix);
-        if probability > 0 {
-            reciprocal_sum += 1.0 / probability as f64;
-        }
+
+    let mut closest: Vec<&(String, u64)> = suggestions.iter().collect();
+    closest.sort_by_key(|(_, expected)| expected.abs_diff(target_attempts));
+    closest.truncate(MAX_PATTERN_SUGGESTIONS);
+    closest.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+
+    println!("  {:<8}  {:>18}  {:>7}  Difficulty", "Pattern", "Expected attempts", "Chance");
+    for (pattern, expected) in closest {
+        println!(
+            "  {:<8}  {:>18}  {:>6.1}%  {}",
+            pattern,
+            expected.to_formatted_string(&locale),
+            luck_percentile(budget, *expected),
+            difficulty_label(*expected).0
+        );
     }
-    
-    if reciprocal_sum > 0.0 {
-        // Return the harmonic mean
-        (1.0 / reciprocal_sum).round() as u64
-    } else {
-        u64::MAX
+    if suggestions.len() > MAX_PATTERN_SUGGESTIONS {
+        println!("  {}", colors::gray(&format!("{} more patterns fit", suggestions.len() - MAX_PATTERN_SUGGESTIONS)));
     }
 }
 
-
 /// Calculate the luck factor based on actual attempts vs median expected
 pub fn calculate_luck_factor(actual_attempts: u64, median_expected: u64) -> f64 {
     if actual_attempts == 0 {
@@ -270,6 +659,73 @@ pub fn calculate_luck_factor(actual_attempts: u64, median_expected: u64) -> f64
     }
 }
 
+/// Percentage of runs that would have found a match within `actual_attempts`
+///
+/// Attempts until success follow a geometric distribution with mean `expected`, so
+/// this is the CDF `1 - (1 - 1/E)^k`. Low percentiles are lucky, high ones unlucky.
+/// Computed via `ln_1p`/`exp_m1` so huge expected values keep their precision.
+pub fn luck_percentile(actual_attempts: u64, expected: u64) -> f64 {
+    if expected <= 1 {
+        return 100.0; // Every address matches
+    }
+    #[allow(clippy::cast_precision_loss)]
+    {
+        -(actual_attempts as f64 * (-1.0 / expected as f64).ln_1p()).exp_m1() * 100.0
+    }
+}
+
+/// Kolmogorov-Smirnov statistic between observed attempt counts and the geometric
+/// distribution with mean `expected`
+///
+/// Returns the largest gap between the empirical CDF and `luck_percentile`; values
+/// near 0 mean the attempts look like independent draws from a uniform RNG.
+pub fn ks_statistic_geometric(attempts: &[u64], expected: u64) -> f64 {
+    let mut sorted = attempts.to_vec();
+    sorted.sort_unstable();
+
+    #[allow(clippy::cast_precision_loss)]
+    let n = sorted.len() as f64;
+    sorted
+        .iter()
+        .enumerate()
+        .map(|(i, &k)| {
+            let theoretical = luck_percentile(k, expected) / 100.0;
+            #[allow(clippy::cast_precision_loss)]
+            let (below, at) = (i as f64 / n, (i + 1) as f64 / n);
+            (at - theoretical).max(theoretical - below)
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Expected time until `k` more wallets are found at `keys_per_sec`
+///
+/// Every thread searches the same stream of addresses and every match counts, so
+/// matches arrive as a Poisson process and the k-th is expected after `k × E`
+/// attempts. The order-statistic form `E·H_N − E·H_{N−k}` would only apply if each
+/// wallet had its own search running side by side at full speed, which would
+/// understate the wait here (H_5 ≈ 2.28 against 5 for five wallets).
+pub fn expected_time_kth_wallet(expected_per_wallet: u64, k: usize, keys_per_sec: u64) -> Duration {
+    if keys_per_sec == 0 {
+        return Duration::MAX;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let seconds = expected_per_wallet as f64 * k as f64 / keys_per_sec as f64;
+    Duration::try_from_secs_f64(seconds).unwrap_or(Duration::MAX)
+}
+
+/// Classify expected attempts into a difficulty label and a real-world analogy
+pub fn difficulty_label(expected: u64) -> (&'static str, &'static str) {
+    match expected {
+        u64::MAX => ("Impossible", "The pattern cannot appear in the searched positions"),
+        0..1_000 => ("Trivial (<1k attempts)", "This is like guessing a 3-digit PIN"),
+        1_000..100_000 => ("Easy (<100k attempts)", "This is like guessing a 5-digit ZIP code"),
+        100_000..10_000_000 => ("Moderate (<10M attempts)", "This is like guessing a 7-digit phone number"),
+        10_000_000..1_000_000_000 => ("Hard (<1B attempts)", "This is like guessing a 9-digit ID number"),
+        1_000_000_000..100_000_000_000 => ("Very Hard (<100B attempts)", "This is harder than winning the lottery (1 in 292 million)"),
+        _ => ("Extreme (≥100B attempts)", "This is harder than guessing a random 6-character Base58 string"),
+    }
+}
+
 /// Print detailed probability breakdown for debugging
 pub fn print_probability_breakdown(
     pattern: &str,
@@ -277,23 +733,26 @@ pub fn print_probability_breakdown(
     suffix: bool,
     anywhere: bool,
     within: usize,
+    search_range: Option<(usize, usize)>,
     ss58_prefix: u16,
 ) {
     use crate::cli::terminal::colors;
-    use num_format::{SystemLocale, ToFormattedString};
+    use crate::cli::terminal::number_locale;
+    use num_format::ToFormattedString;
     
-    const SS58_ADDRESS_LENGTH: usize = 49;
+    let address_len = crate::validation::ss58_address_length_for_prefix(ss58_prefix);
     let pattern_len = pattern.chars().count();
-    let locale = SystemLocale::default().unwrap();
+    let locale = number_locale();
     
     println!("{}", colors::yellow("PROBABILITY CALCULATION"));
 
     // Pattern Analysis
     println!("\n{} '{}'", colors::yellow("→ Pattern Analysis:"), colors::bright_yellow(pattern));
     
+    let model = AddressModel::for_prefix(ss58_prefix);
     let mut calculations = Vec::new();
     let mut calc_string = String::new();
-    let mut base_probability = 1u64;
+    let mut base_odds = 1.0_f64;
     
     // Check for prefix overlap in prefix mode
     let prefix_overlap = if !suffix && !anywhere {
@@ -304,42 +763,44 @@ pub fn print_probability_breakdown(
     
     let mut processed_chars = 0;
     for (i, ch) in pattern.chars().enumerate() {
-        let char_probability = if !suffix && !anywhere && processed_chars < prefix_overlap {
+        let char_odds = if !suffix && !anywhere && processed_chars < prefix_overlap {
             // This character overlaps with network prefix
             println!("├─ '{}' = {} (no randomness)", 
                      ch, colors::green("MATCHES network prefix ✓"));
             calculations.push("1".to_string());
-            1
+            1.0
         } else if ch == '?' {
             println!("├─ '{}' = {} (matches anything - no randomness!)", 
                      ch, colors::green("1"));
             calculations.push("1".to_string());
-            1
+            1.0
         } else {
-            let set_size = get_character_set_size(ch, case_sensitive);
+            let odds = model.odds(ch, case_sensitive);
+            let odds_str = format!("{:.1}", odds);
             if case_sensitive {
-                println!("├─ '{}' = {} possible matches (exact '{}' only in Base58)", 
-                         ch, colors::yellow("58"), ch);
+                println!("├─ '{}' = 1 in {} (exact '{}' only in Base58)", 
+                         ch, colors::yellow(&odds_str), ch);
             } else if ch.is_ascii_digit() {
-                println!("├─ '{}' = {} possible matches (digit - must match exactly)", 
-                         ch, colors::yellow("58"));
+                println!("├─ '{}' = 1 in {} (digit - must match exactly)", 
+                         ch, colors::yellow(&odds_str));
             } else {
                 let upper = ch.to_ascii_uppercase();
                 let lower = ch.to_ascii_lowercase();
-                println!("├─ '{}' = {} possible matches ({} or {} in Base58)", 
-                         ch, colors::yellow("33"), upper, lower);
+                println!("├─ '{}' = 1 in {} ({} or {} in Base58)", 
+                         ch, colors::yellow(&odds_str), upper, lower);
             }
-            calculations.push(set_size.to_string());
-            set_size
+            calculations.push(odds_str);
+            odds
         };
         
         if i > 0 {
             calc_string.push_str(" × ");
         }
         calc_string.push_str(&calculations[i]);
-        base_probability = base_probability.saturating_mul(char_probability);
+        base_odds *= char_odds;
         processed_chars += 1;
     }
+    let base_probability = base_odds.round() as u64;
     
     // Base Calculation
     println!("\n{}", colors::yellow("→ Base Calculation:"));
@@ -361,92 +822,11 @@ pub fn print_probability_breakdown(
     };
     
     println!("\n{}", colors::yellow(&format!("→ Position Analysis ({}):", mode_name)));
-    println!("{}", colors::gray("Address structure: [49 total characters]"));
-    
-    // Get the network prefix to check for locked positions
-    let network_prefixes = if let Some(network) = crate::networks::find_network_by_prefix(ss58_prefix) {
-        network.address_prefixes
-    } else {
-        &[]
-    };
+    println!("{}", colors::gray(&format!("Address structure: [{address_len} total characters]")));
     
-    let max_prefix_len = network_prefixes.iter().map(|p| p.len()).max().unwrap_or(0);
-    
-    // Helper to check if pattern can match at a position
-    let can_match_at_position = |pos: usize| -> bool {
-        if pos >= max_prefix_len {
-            return true;
-        }
-        
-        for prefix in network_prefixes {
-            let prefix_len = prefix.len();
-            if pos >= prefix_len {
-                continue;
-            }
-            
-            let mut matches = true;
-            let pattern_chars: Vec<char> = pattern.chars().collect();
-            let prefix_chars: Vec<char> = prefix.chars().collect();
-            
-            for i in 0..pattern_len.min(prefix_len - pos) {
-                let pattern_char = pattern_chars[i];
-                let prefix_char = prefix_chars[pos + i];
-                
-                if pattern_char == '?' {
-                    continue;
-                }
-                
-                if case_sensitive {
-                    if pattern_char != prefix_char {
-                        matches = false;
-                        break;
-                    }
-                } else {
-                    if !pattern_char.eq_ignore_ascii_case(&prefix_char) {
-                        matches = false;
-                        break;
-                    }
-                }
-            }
-            
-            if matches {
-                return true;
-            }
-        }
-        
-        false
-    };
-    
-    // Determine valid positions based on mode
-    let positions: Vec<usize> = if anywhere {
-        let max_pos = SS58_ADDRESS_LENGTH.saturating_sub(pattern_len);
-        let positions_range = if within >= SS58_ADDRESS_LENGTH {
-            0..=max_pos
-        } else if within >= pattern_len {
-            0..=within.saturating_sub(pattern_len).min(max_pos)
-        } else {
-            return; // Pattern doesn't fit
-        };
-        
-        positions_range.filter(|&pos| can_match_at_position(pos)).collect()
-    } else if suffix {
-        if within >= SS58_ADDRESS_LENGTH {
-            (0..=SS58_ADDRESS_LENGTH.saturating_sub(pattern_len)).collect()
-        } else if within >= pattern_len {
-            let start = SS58_ADDRESS_LENGTH.saturating_sub(within);
-            let end = SS58_ADDRESS_LENGTH.saturating_sub(pattern_len);
-            (start..=end).collect()
-        } else {
-            vec![]
-        }
-    } else {
-        // Prefix mode
-        if within >= pattern_len {
-            (0..=within.saturating_sub(pattern_len)).collect()
-        } else {
-            vec![]
-        }
-    };
+    // Positions the search actually checks, minus those locked by the network prefix
+    let calculator = PositionCalculator::new(pattern, case_sensitive, search_range, ss58_prefix);
+    let positions = calculator.valid_positions();
     
     let possible_positions = positions.len();
     
@@ -462,11 +842,11 @@ pub fn print_probability_breakdown(
     
     // Visual representation
     if suffix {
-        let search_start = SS58_ADDRESS_LENGTH.saturating_sub(within);
+        let search_start = address_len.saturating_sub(within);
         println!("  [{}{}{}]", 
                  display_prefix,
                  ".".repeat(search_start.saturating_sub(display_prefix.len())),
-                 colors::yellow(&"x".repeat(within.min(SS58_ADDRESS_LENGTH - display_prefix.len()))));
+                 colors::yellow(&"x".repeat(within.min(address_len - display_prefix.len()))));
         let prefix_info = if network_prefixes.len() > 1 {
             format!("└─ Network prefixes: {} (showing: {})                        └─ Search zone (last {} chars)", 
                     network_prefixes.join(", "), display_prefix, within)
@@ -494,14 +874,13 @@ pub fn print_probability_breakdown(
                     break;
                 }
                 
-                let prob = calculate_probability_at_position(pattern, pos, case_sensitive, ss58_prefix);
+                let prob = calculator.probability_at(pos);
                 let dots_before = pos.saturating_sub(display_prefix.len()).min(37);
-                let dashes_after = SS58_ADDRESS_LENGTH.saturating_sub(pos + pattern_len).min(10);
+                let dashes_after = address_len.saturating_sub(pos + pattern_len).min(10);
                 
                 if pos == 0 && has_position_zero {
                     // Special case for position 0 with prefix overlap
-                    let prefix_overlap = calculate_prefix_overlap(pattern, ss58_prefix, pattern_len);
-                    if prefix_overlap > 0 {
+                    if calculator.prefix_overlap_at(0) > 0 {
                         println!("Position {}: [{}{}{}{}]   Probability: {} {}", 
                                  pos,
                                  colors::green(display_prefix),
@@ -532,7 +911,7 @@ pub fn print_probability_breakdown(
         }
     } else if anywhere {
         println!("  Pattern can appear {} in the address", colors::yellow("anywhere"));
-        if within < SS58_ADDRESS_LENGTH {
+        if within < address_len {
             println!("  Limited to a window of {} characters", within);
         }
         
@@ -542,20 +921,19 @@ pub fn print_probability_breakdown(
             
             let has_position_zero = positions.contains(&0);
             let pos0_prob = if has_position_zero {
-                Some(calculate_probability_at_position(pattern, 0, case_sensitive, ss58_prefix))
+                Some(calculator.probability_at(0))
             } else {
                 None
             };
             
             let other_prob = if positions.len() > 1 {
-                Some(calculate_probability_at_position(pattern, positions[1], case_sensitive, ss58_prefix))
+                Some(calculator.probability_at(positions[1]))
             } else {
                 None
             };
             
             if let Some(p0) = pos0_prob {
-                let prefix_overlap = calculate_prefix_overlap(pattern, ss58_prefix, pattern_len);
-                if prefix_overlap > 0 {
+                if calculator.prefix_overlap_at(0) > 0 {
                     println!("Position  0: Probability {} {}", 
                              p0.to_formatted_string(&locale), colors::green("(network prefix overlap!)"));
                 } else {
@@ -567,7 +945,7 @@ pub fn print_probability_breakdown(
                 let remaining = positions.len() - (if has_position_zero { 1 } else { 0 });
                 if remaining > 0 {
                     println!("Positions {}: Probability {} each ({} positions)",
-                             if has_position_zero { "1-46" } else { "all" },
+                             if has_position_zero { format!("{}-{}", positions[1], positions[positions.len() - 1]) } else { "all".to_string() },
                              op.to_formatted_string(&locale),
                              remaining);
                 }
@@ -576,8 +954,8 @@ pub fn print_probability_breakdown(
     } else {
         // Prefix mode
         println!("  [{}{}]", 
-                 colors::yellow(&"x".repeat(within.min(SS58_ADDRESS_LENGTH))),
-                 ".".repeat(SS58_ADDRESS_LENGTH.saturating_sub(within)));
+                 colors::yellow(&"x".repeat(within.min(address_len))),
+                 ".".repeat(address_len.saturating_sub(within)));
         println!("   {}", 
                  colors::yellow(&format!("└─ Search zone (first {} chars)", within)));
         
@@ -591,11 +969,10 @@ pub fn print_probability_breakdown(
                     break;
                 }
                 
-                let prob = calculate_probability_at_position(pattern, pos, case_sensitive, ss58_prefix);
+                let prob = calculator.probability_at(pos);
                 
                 if pos == 0 {
-                    let prefix_overlap = calculate_prefix_overlap(pattern, ss58_prefix, pattern_len);
-                    if prefix_overlap > 0 {
+                    if calculator.prefix_overlap_at(0) > 0 {
                         println!("Position {}: Probability {} {}", 
                                  pos,
                                  prob.to_formatted_string(&locale),
@@ -621,23 +998,18 @@ pub fn print_probability_breakdown(
         println!("{}", colors::red("No valid positions found - pattern cannot match!"));
         u64::MAX
     } else if positions.len() == 1 {
-        let prob = calculate_probability_at_position(pattern, positions[0], case_sensitive, ss58_prefix);
+        let prob = calculator.probability_at(positions[0]);
         println!("  {}", colors::gray(&format!("Only 1 position available (position {})", positions[0])));
         prob
     } else {
         // Multiple positions - use harmonic mean
         println!("  Using harmonic mean for {} positions:", positions.len());
         
-        let mut reciprocal_sum = 0.0_f64;
         let mut unique_probs = std::collections::HashMap::new();
         
-        // Calculate and group probabilities
+        // Group probabilities
         for &pos in &positions {
-            let prob = calculate_probability_at_position(pattern, pos, case_sensitive, ss58_prefix);
-            *unique_probs.entry(prob).or_insert(0) += 1;
-            if prob > 0 {
-                reciprocal_sum += 1.0 / prob as f64;
-            }
+            *unique_probs.entry(calculator.probability_at(pos)).or_insert(0) += 1;
         }
         
         // Show unique probabilities and their counts
@@ -680,13 +1052,7 @@ pub fn print_probability_breakdown(
             println!("{}", calc_str);
         }
         
-        let result = if reciprocal_sum > 0.0 {
-            (1.0 / reciprocal_sum).round() as u64
-        } else {
-            u64::MAX
-        };
-        
-        result
+        calculator.expected_attempts()
     };
     
     // Show probability as percentage
@@ -711,6 +1077,10 @@ pub fn print_probability_breakdown(
              format!("1 in {}", expected_attempts.to_formatted_string(&locale)),
              percentage_str);
     
+    let (label, analogy) = difficulty_label(expected_attempts);
+    println!("{} {}", colors::yellow("→ DIFFICULTY:"), colors::bright_yellow(label));
+    println!("  {}", colors::gray(analogy));
+    
     println!();
     println!();
 }
@@ -719,88 +1089,282 @@ pub fn print_probability_breakdown(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_position_calculator() {
+        // Prefix search for "su1" on Autonomys: "su" comes free from the network prefix at 0
+        let search_range = OptimizedSearchConfig::new_with_prefix("su1", false, false, false, 5, 6094).search_range;
+        let calculator = PositionCalculator::new("su1", false, search_range, 6094);
+        let positions = calculator.valid_positions();
+        assert_eq!(positions[0], 0);
+        assert_eq!(calculator.prefix_overlap_at(0), 2);
+        assert_eq!(calculator.prefix_overlap_at(1), 0);
+        assert!(calculator.probability_at(0) < calculator.probability_at(positions[1]));
+
+        let reciprocal_sum: f64 = positions.iter().map(|&pos| 1.0 / calculator.probability_at(pos) as f64).sum();
+        assert_eq!(calculator.expected_attempts(), (1.0 / reciprocal_sum).round() as u64);
+        assert_eq!(calculate_expected_attempts("su1", false, search_range, 6094), calculator.expected_attempts());
+
+        assert_eq!(PositionCalculator::new("su1", false, None, 6094).expected_attempts(), u64::MAX);
+    }
+
+    #[test]
+    fn test_suggest_patterns() {
+        // A 50% chance in 1,000 attempts needs E = 1000 / ln 2
+        assert_eq!(target_expected_attempts(1000, 50.0), 1443);
+        assert!(luck_percentile(1000, target_expected_attempts(1000, 90.0)) > 89.9);
+
+        let target = 1500;
+        let suggestions = suggest_patterns(target, 42, 0, true, false);
+        assert!(!suggestions.is_empty());
+        for (pattern, expected) in &suggestions {
+            assert!((2..=3).contains(&pattern.len()));
+            assert_eq!(pattern, &pattern.to_lowercase());
+            assert!(expected.abs_diff(target) <= target / 10, "{pattern}: {expected}");
+            let config = OptimizedSearchConfig::new_with_prefix(pattern, false, true, false, pattern.len(), 42);
+            assert_eq!(calculate_expected_attempts(pattern, false, config.search_range, 42), *expected);
+        }
+        assert!(suggestions.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+
+        assert!(suggest_patterns(u64::MAX / 2, 42, 0, true, false).is_empty());
+    }
+
+    /// Odds of matching every character of `pattern` at an unlocked position
+    fn pattern_odds(pattern: &str, case_sensitive: bool, ss58_prefix: u16) -> f64 {
+        let model = AddressModel::for_prefix(ss58_prefix);
+        pattern.chars().map(|ch| model.odds(ch, case_sensitive)).product()
+    }
+
     #[test]
     fn test_expected_attempts_calculation() {
-        // Test mixed pattern: letters + number (case-insensitive) in prefix mode
-        let attempts = calculate_expected_attempts("ai3", false, false, false, 5, 6094);
-        // Base: 'a' = 33, 'i' = 33, '3' = 58 -> 33 * 33 * 58 = 63,162
-        // Within 5, pattern len 3, so 5 - 3 + 1 = 3 positions
-        // 63162 / 3 = 21,054
-        assert_eq!(attempts, (33 * 33 * 58) / 3);
-
-        // Test suffix mode with same pattern
-        let attempts_suffix = calculate_expected_attempts("ai3", false, true, false, 3, 6094);
-        // Same base probability, but suffix with within=3 (pattern length)
-        // Only 1 position where it fits exactly at the end
-        assert_eq!(attempts_suffix, 33 * 33 * 58);
-
-        // Test suffix mode with larger within
-        let attempts_suffix_5 = calculate_expected_attempts("ai3", false, true, false, 5, 6094);
-        // Within 5, pattern len 3, so 5 - 3 + 1 = 3 positions in the last 5 chars
-        assert_eq!(attempts_suffix_5, (33 * 33 * 58) / 3);
-
-        // Test anywhere mode - most positions available
-        let attempts_anywhere = calculate_expected_attempts("ai3", false, false, true, 49, 6094);
-        // 49 - 3 + 1 = 47 possible positions
-        assert_eq!(attempts_anywhere, (33 * 33 * 58) / 47);
-        
+        // Mixed pattern: letters + number (case-insensitive), roughly 29 * 29 * 58
+        let base = pattern_odds("ab3", false, 6094).round() as u64;
+        assert!((40_000..60_000).contains(&base), "unexpected base odds {base}");
+
+        // Three start positions past the network prefix
+        assert_eq!(calculate_expected_attempts("ab3", false, Some((2, 4)), 6094), (base as f64 / 3.0).round() as u64);
+
+        // A single position, e.g. suffix mode with within equal to the pattern length
+        assert_eq!(calculate_expected_attempts("ab3", false, Some((46, 46)), 6094), base);
+
+        // Positions 0 and 1 are locked by "su" and don't count
+        assert_eq!(
+            calculate_expected_attempts("ab3", false, Some((0, 4)), 6094),
+            calculate_expected_attempts("ab3", false, Some((2, 4)), 6094)
+        );
+
+        // Anywhere mode: 47 start positions, 45 usable
+        let attempts_anywhere = calculate_expected_attempts("ab3", false, Some((0, 46)), 6094);
+        assert_eq!(attempts_anywhere, (base as f64 / 45.0).round() as u64);
+
         // Test with wildcards - should be easier
-        let attempts_wildcard = calculate_expected_attempts("ai?", false, false, false, 5, 6094);
-        // 'a' = 33, 'i' = 33, '?' = 1 -> base = 1089
-        // Within 5, pattern len 3, so 3 positions
-        assert_eq!(attempts_wildcard, (33 * 33) / 3);
-        assert!(attempts_wildcard < attempts);
+        let attempts_wildcard = calculate_expected_attempts("ab?", false, Some((2, 4)), 6094);
+        let wildcard_base = pattern_odds("ab", false, 6094).round() as u64;
+        assert_eq!(attempts_wildcard, (wildcard_base as f64 / 3.0).round() as u64);
 
         // Test case-sensitive - should be harder
-        let attempts_case_sens = calculate_expected_attempts("ai3", true, false, false, 5, 6094);
-        // Base: 58^3, with 3 positions
-        assert_eq!(attempts_case_sens, 58_u64.pow(3) / 3);
-        assert!(attempts_case_sens > attempts);
+        let attempts_case_sens = calculate_expected_attempts("ab3", true, Some((2, 4)), 6094);
+        assert!(attempts_case_sens > calculate_expected_attempts("ab3", false, Some((2, 4)), 6094));
+
+        // No range means the pattern cannot fit
+        assert_eq!(calculate_expected_attempts("ab3", false, None, 6094), u64::MAX);
     }
 
     #[test]
     fn test_prefix_overlap() {
+        let b_odds = AddressModel::for_prefix(6094).odds('b', false);
+
         // Test Autonomys prefix overlap
-        let attempts_su = calculate_expected_attempts("su", false, false, false, 2, 6094);
+        let attempts_su = calculate_expected_attempts("su", false, Some((0, 0)), 6094);
         assert_eq!(attempts_su, 1); // Complete overlap, no random chars, 1 position
 
-        let attempts_sub = calculate_expected_attempts("sub", false, false, false, 3, 6094);
-        assert_eq!(attempts_sub, 33); // "su" overlaps, "b" is random, exactly 1 position
+        let attempts_sub = calculate_expected_attempts("sub", false, Some((0, 0)), 6094);
+        assert_eq!(attempts_sub, b_odds.round() as u64); // "su" overlaps, "b" is random, exactly 1 position
 
-        // Test with larger within allowing multiple positions
-        let attempts_sub_within5 = calculate_expected_attempts("sub", false, false, false, 5, 6094);
-        // "su" overlaps, "b" is random (base = 33)
-        // Within 5, pattern len 3, so 5 - 3 + 1 = 3 positions
-        assert_eq!(attempts_sub_within5, 33 / 3);
+        // Position 1 conflicts with "su"; position 2 costs the full odds of "sub"
+        let attempts_sub_within5 = calculate_expected_attempts("sub", false, Some((0, 2)), 6094);
+        let full = pattern_odds("sub", false, 6094).round();
+        assert_eq!(attempts_sub_within5, (1.0 / (1.0 / b_odds.round() + 1.0 / full)).round() as u64);
 
-        // Test no overlap with within
-        let attempts_within = calculate_expected_attempts("test", false, false, false, 5, 6094);
-        // No overlap, base = 33^4
-        // Within 5, pattern len 4, so 5 - 4 + 1 = 2 positions
-        assert_eq!(attempts_within, 33_u64.pow(4) / 2);
+        // Every start position conflicts with the network prefix
+        let attempts_within = calculate_expected_attempts("test", false, Some((0, 1)), 6094);
+        assert_eq!(attempts_within, u64::MAX);
     }
 
     #[test]
-    fn test_character_set_size() {
-        // Test wildcard
-        assert_eq!(get_character_set_size('?', false), 1);
-        assert_eq!(get_character_set_size('?', true), 1);
+    fn test_luck_percentile() {
+        assert_eq!(luck_percentile(0, 1000), 0.0);
+        assert_eq!(luck_percentile(1, 1), 100.0);
 
-        // Test numbers (no case variants)
-        assert_eq!(get_character_set_size('1', false), 58);
-        assert_eq!(get_character_set_size('5', false), 58);
-        assert_eq!(get_character_set_size('9', false), 58);
+        // Finding a match at exactly the expected attempts is ~63rd percentile
+        let at_mean = luck_percentile(1_000_000, 1_000_000);
+        assert!((at_mean - 63.212).abs() < 0.01, "got {at_mean}");
 
-        // Test letters with case variants
-        assert_eq!(get_character_set_size('a', false), 33);
-        assert_eq!(get_character_set_size('A', false), 33);
-        assert_eq!(get_character_set_size('z', false), 33);
-        assert_eq!(get_character_set_size('Z', false), 33);
+        // Matches the direct formula for small expected values
+        let direct = (1.0 - (1.0 - 1.0 / 50.0_f64).powf(20.0)) * 100.0;
+        assert!((luck_percentile(20, 50) - direct).abs() < 1e-9);
 
-        // Test case-sensitive mode (all characters = 58)
-        assert_eq!(get_character_set_size('a', true), 58);
-        assert_eq!(get_character_set_size('1', true), 58);
-        assert_eq!(get_character_set_size('?', true), 1); // Wildcard is special
+        // Monotonic in attempts
+        assert!(luck_percentile(10, 100) < luck_percentile(200, 100));
     }
 
+    #[test]
+    fn test_expected_time_kth_wallet() {
+        assert_eq!(expected_time_kth_wallet(1000, 1, 100), Duration::from_secs(10));
+        assert_eq!(expected_time_kth_wallet(1000, 5, 100), Duration::from_secs(50));
+        assert_eq!(expected_time_kth_wallet(1000, 0, 100), Duration::ZERO);
+        assert_eq!(expected_time_kth_wallet(1000, 1, 0), Duration::MAX);
+        assert_eq!(expected_time_kth_wallet(u64::MAX, usize::MAX, 1), Duration::MAX);
+    }
+
+    #[test]
+    fn test_difficulty_label() {
+        assert_eq!(difficulty_label(1).0, "Trivial (<1k attempts)");
+        assert_eq!(difficulty_label(999).0, "Trivial (<1k attempts)");
+        assert_eq!(difficulty_label(1_000).0, "Easy (<100k attempts)");
+        assert_eq!(difficulty_label(63_162).0, "Easy (<100k attempts)");
+        assert_eq!(difficulty_label(100_000).0, "Moderate (<10M attempts)");
+        assert_eq!(difficulty_label(10_000_000).0, "Hard (<1B attempts)");
+        assert_eq!(difficulty_label(1_000_000_000).0, "Very Hard (<100B attempts)");
+        assert_eq!(difficulty_label(100_000_000_000).0, "Extreme (≥100B attempts)");
+        assert_eq!(difficulty_label(u64::MAX).0, "Impossible");
+        assert!(difficulty_label(500).1.contains("PIN"));
+    }
+
+    #[test]
+    fn test_ks_statistic_geometric() {
+        assert_eq!(ks_statistic_geometric(&[], 100), 0.0);
+
+        // Attempts at the geometric quantiles give a small statistic
+        let expected = 1000;
+        let quantiles: Vec<u64> = (1..=99)
+            .map(|i| (-(1.0 - f64::from(i) / 100.0).ln() * expected as f64).round() as u64)
+            .collect();
+        assert!(ks_statistic_geometric(&quantiles, expected) < 0.05);
+
+        // Every wallet found immediately is far from geometric
+        assert!(ks_statistic_geometric(&[1; 20], expected) > 0.9);
+    }
+
+    #[test]
+    fn test_pattern_fits_network_prefix() {
+        // Autonomys addresses always start with "su"
+        assert!(pattern_fits_network_prefix("sub", 0, false, 6094));
+        assert!(!pattern_fits_network_prefix("abc", 0, false, 6094));
+        assert!(pattern_fits_network_prefix("ubc", 1, false, 6094));
+        assert!(!pattern_fits_network_prefix("Ubc", 1, true, 6094));
+        assert!(pattern_fits_network_prefix("?bc", 1, true, 6094));
+        assert!(pattern_fits_network_prefix("abc", 2, false, 6094));
+
+        // Unknown networks lock no positions
+        assert!(pattern_fits_network_prefix("abc", 0, false, 9999));
+    }
+
+    #[test]
+    fn test_possible_characters_by_position() {
+        let as_string = |set: &HashSet<char>| {
+            let mut chars: Vec<char> = set.iter().copied().collect();
+            chars.sort_unstable();
+            chars.into_iter().collect::<String>()
+        };
+
+        // Autonomys: "su" is fixed and the prefix bytes confine the third character
+        let autonomys = possible_characters_by_position(6094);
+        assert_eq!(autonomys.len(), 49);
+        assert_eq!(as_string(&autonomys[0]), "s");
+        assert_eq!(as_string(&autonomys[1]), "u");
+        assert_eq!(as_string(&autonomys[2]), "bcdefg");
+        assert_eq!(autonomys[3].len(), 58);
+        assert_eq!(as_string(&possible_characters_by_position(2)[0]), "CDEFGHJ");
+        assert_eq!(as_string(&possible_characters_by_position(0)[0]), "1");
+
+        // Every character real addresses hold is allowed, shorter addresses included
+        for ss58_prefix in [0, 2, 42, 6094] {
+            let possible = possible_characters_by_position(ss58_prefix);
+            for (pos, counts) in character_counts_by_position(ss58_prefix, 2000).iter().enumerate() {
+                assert!(counts.keys().all(|ch| possible[pos].contains(ch)), "prefix {ss58_prefix} position {pos}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_character_counts_by_position() {
+        let counts = character_counts_by_position(6094, 1000);
+        assert_eq!(counts.len(), 49);
+        assert_eq!(counts[0].get(&'s'), Some(&1000));
+        assert!(counts.iter().all(|position| position.values().sum::<u64>() == 1000));
+
+        assert_eq!(heat_cell(0, 10.0), ' ');
+        assert_eq!(heat_cell(10, 10.0), '▒');
+        assert_eq!(heat_cell(1000, 10.0), '█');
+    }
+
+    #[test]
+    fn test_address_model() {
+        let model = AddressModel::for_prefix(6094);
+
+        // Frequencies cover the 58 Base58 characters and sum to one
+        assert_eq!(model.character_frequency.len(), 58);
+        let total: f64 = model.character_frequency.values().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        // Wildcards always match
+        assert_eq!(model.match_probability('?', false), 1.0);
+        assert_eq!(model.match_probability('?', true), 1.0);
+
+        // Case-insensitive letters match either case; digits and single-case
+        // letters ('i' has no uppercase in Base58) do not get the bonus
+        let a_odds = model.odds('a', false);
+        assert_eq!(a_odds, model.odds('A', false));
+        assert!((25.0..35.0).contains(&a_odds), "unexpected odds for 'a': {a_odds}");
+        assert_eq!(model.match_probability('i', false), model.match_probability('i', true));
+        assert_eq!(model.match_probability('5', false), model.match_probability('5', true));
+
+        // Characters outside Base58 never match
+        assert_eq!(model.match_probability('0', true), 0.0);
+    }
+
+    #[test]
+    fn test_monte_carlo_agrees_with_analytic() {
+        // (pattern, case_sensitive, suffix, anywhere, within, ss58_prefix)
+        let cases = [
+            ("a", false, true, false, 1, 6094),
+            ("a3", true, false, true, 49, 6094),
+            ("xy", false, false, true, 49, 0),
+            ("ab", false, false, true, 49, 42),
+        ];
+
+        for (pattern, case_sensitive, suffix, anywhere, within, ss58_prefix) in cases {
+            let search_range = OptimizedSearchConfig::new_with_prefix(pattern, case_sensitive, suffix, anywhere, within, ss58_prefix)
+                .search_range;
+            let analytic = calculate_expected_attempts(pattern, case_sensitive, search_range, ss58_prefix) as f64;
+            let estimate = estimate_expected_attempts_monte_carlo(pattern, case_sensitive, suffix, anywhere, within, ss58_prefix, 60_000);
+            assert!(
+                (estimate - analytic).abs() / analytic < 0.10,
+                "'{}' (prefix {}): Monte Carlo {:.1} vs analytic {}",
+                pattern, ss58_prefix, estimate, analytic
+            );
+        }
+
+        // Patterns that cannot fit never match
+        assert!(estimate_expected_attempts_monte_carlo("abc", false, false, false, 2, 6094, 10).is_infinite());
+    }
+
+    #[test]
+    #[ignore = "samples 1,000,000 addresses per network; run with --ignored"]
+    fn test_address_model_matches_large_sample() {
+        for network in crate::networks::NETWORKS {
+            let model = AddressModel::for_prefix(network.ss58_prefix);
+            let large = AddressModel::sample(network.ss58_prefix, CALIBRATION_SAMPLE_ADDRESSES);
+            assert_eq!(large.ss58_prefix, model.ss58_prefix);
+
+            for (ch, &frequency) in &large.character_frequency {
+                let sampled = model.character_frequency[ch];
+                assert!(
+                    (sampled - frequency).abs() / frequency < 0.05,
+                    "{}: '{}' frequency {:.5} differs from large sample {:.5}",
+                    network.name, ch, sampled, frequency
+                );
+            }
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,280 @@
+//! `--script`: run searches queued as JSON lines on stdin
+//!
+//! Each input line is one command, e.g. `{"term":"ai3","count":1,"network":"Autonomys"}`.
+//! Every wallet found is written to stdout as one JSON object per line, secret
+//! included; nothing is saved to disk. A command that can't run produces an
+//! `{"error": ...}` line and the script carries on with the next one.
+//!
+//! One [`SearchPool`] serves the whole script, so the worker threads and their RNGs
+//! are set up once rather than for every command.
+
+use crate::runner::{parallel::SearchPool, SearchParams, VanityResult};
+use serde_json::{json, Map, Value};
+use std::io::{BufRead, Write};
+
+/// Most wallets a single command may ask for
+pub const MAX_SCRIPT_COUNT: usize = 1000;
+
+/// Keys a command object may contain
+const COMMAND_KEYS: [&str; 8] = ["term", "count", "network", "ss58_prefix", "mode", "within", "case_sensitive", "hex"];
+
+/// One search read from the script
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptCommand {
+    pub term: String,
+    /// Wallets to find, 1 to [`MAX_SCRIPT_COUNT`] (default 1)
+    pub count: usize,
+    /// From `network` or `ss58_prefix` (default Autonomys)
+    pub ss58_prefix: u16,
+    /// `"mode"`: `"suffix"` (default), `"prefix"` or `"anywhere"`
+    pub suffix: bool,
+    pub anywhere: bool,
+    /// Same default as `--within` for the mode
+    pub within: usize,
+    pub case_sensitive: bool,
+    pub hex: bool,
+}
+
+impl ScriptCommand {
+    /// Parameters for running this command
+    pub fn search_params(&self, threads: usize, pbkdf2_rounds: u32) -> SearchParams {
+        SearchParams {
+            term: self.term.clone(),
+            count: self.count,
+            case_sensitive: self.case_sensitive,
+            hex_mode: self.hex,
+            pbkdf2_rounds,
+            ss58_prefix: self.ss58_prefix,
+            within: self.within,
+            within_window: None,
+            suffix: self.suffix,
+            anywhere: self.anywhere,
+            threads,
+            progress_interval_ms: crate::runner::DEFAULT_PROGRESS_INTERVAL_MS,
+            watchdog_timeout_secs: crate::runner::DEFAULT_WATCHDOG_TIMEOUT_SECS,
+            stop: None,
+            rng_seed: None,
+        }
+    }
+}
+
+fn bool_field(object: &Map<String, Value>, key: &str) -> Result<bool, String> {
+    match object.get(key) {
+        None => Ok(false),
+        Some(value) => value.as_bool().ok_or_else(|| format!("'{key}' must be true or false")),
+    }
+}
+
+fn usize_field(object: &Map<String, Value>, key: &str) -> Result<Option<usize>, String> {
+    object
+        .get(key)
+        .map(|value| {
+            value
+                .as_u64()
+                .and_then(|n| usize::try_from(n).ok())
+                .ok_or_else(|| format!("'{key}' must be a non-negative integer"))
+        })
+        .transpose()
+}
+
+/// Parse one line of the script
+pub fn parse_command(line: &str) -> Result<ScriptCommand, String> {
+    let value: Value = serde_json::from_str(line).map_err(|e| format!("Invalid JSON: {e}"))?;
+    let object = value.as_object().ok_or("Command must be a JSON object")?;
+    if let Some(key) = object.keys().find(|key| !COMMAND_KEYS.contains(&key.as_str())) {
+        return Err(format!("Unknown key '{key}'. Valid keys: {}", COMMAND_KEYS.join(", ")));
+    }
+
+    let term = object
+        .get("term")
+        .and_then(Value::as_str)
+        .ok_or("'term' is required and must be a string")?
+        .to_string();
+
+    let count = usize_field(object, "count")?.unwrap_or(1);
+    if !(1..=MAX_SCRIPT_COUNT).contains(&count) {
+        return Err(format!("'count' must be 1 to {MAX_SCRIPT_COUNT}, not {count}"));
+    }
+
+    let ss58_prefix = match (object.get("network"), object.get("ss58_prefix")) {
+        (Some(_), Some(_)) => return Err("Give 'network' or 'ss58_prefix', not both".to_string()),
+        (Some(network), None) => {
+            let name = network.as_str().ok_or("'network' must be a string")?;
+            crate::networks::find_network(name)
+                .ok_or_else(|| format!("Unknown network '{name}'. Available networks: {}", crate::networks::network_names()))?
+                .ss58_prefix
+        }
+        (None, Some(prefix)) => prefix
+            .as_u64()
+            .and_then(|n| u16::try_from(n).ok())
+            .ok_or("'ss58_prefix' must be an integer from 0 to 65535")?,
+        (None, None) => 6094,
+    };
+
+    let (suffix, anywhere) = match object.get("mode").map(|mode| mode.as_str()) {
+        None | Some(Some("suffix")) => (true, false),
+        Some(Some("prefix")) => (false, false),
+        Some(Some("anywhere")) => (false, true),
+        Some(_) => return Err("'mode' must be \"suffix\", \"prefix\" or \"anywhere\"".to_string()),
+    };
+
+    // Same defaults as --within
+    let within = match usize_field(object, "within")? {
+        Some(within) => within,
+        None if suffix => term.chars().count(),
+        None if anywhere => crate::validation::ss58_address_length_for_prefix(ss58_prefix),
+        None => 5,
+    };
+
+    Ok(ScriptCommand {
+        term,
+        count,
+        ss58_prefix,
+        suffix,
+        anywhere,
+        within,
+        case_sensitive: bool_field(object, "case_sensitive")?,
+        hex: bool_field(object, "hex")?,
+    })
+}
+
+/// Reject terms the search would never find; the runner exits the process on them
+fn check_command(command: &ScriptCommand) -> Result<(), String> {
+    crate::validation::validate_search_term_with_prefix(
+        &command.term,
+        command.case_sensitive,
+        command.suffix,
+        command.within,
+        command.ss58_prefix,
+    )?;
+
+    let search_config = command.search_params(1, crate::wallet::DEFAULT_PBKDF2_ROUNDS).search_config();
+    crate::validation::theoretical_impossibility_check(
+        &command.term,
+        command.case_sensitive,
+        search_config.search_range,
+        command.ss58_prefix,
+    )?;
+    if !search_config.is_valid() {
+        return Err(format!("Pattern '{}' cannot be found with these settings", command.term));
+    }
+    Ok(())
+}
+
+/// Output line for one found wallet
+fn result_json(command: &ScriptCommand, result: &VanityResult) -> Value {
+    json!({
+        "term": command.term,
+        "address": result.address,
+        "secret": result.secret,
+        "hex": command.hex,
+        "network": result.network_name,
+        "ss58_prefix": result.ss58_prefix,
+        "attempts": result.attempts,
+    })
+}
+
+/// Run every command from `input`, writing results and errors to `output`
+///
+/// Returns once `input` ends; only failing to read or write, or losing a worker
+/// thread, is an error.
+pub fn run_script(input: impl BufRead, output: &mut impl Write, threads: usize, pbkdf2_rounds: u32) -> Result<(), String> {
+    run_script_with_pool(input, output, &SearchPool::new(threads, None), pbkdf2_rounds)
+}
+
+/// [`run_script`] on an existing pool
+fn run_script_with_pool(input: impl BufRead, output: &mut impl Write, pool: &SearchPool, pbkdf2_rounds: u32) -> Result<(), String> {
+    for line in input.lines() {
+        let line = line.map_err(|e| format!("Failed to read script: {e}"))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let command = match parse_command(&line).and_then(|command| check_command(&command).map(|()| command)) {
+            Ok(command) => command,
+            Err(e) => {
+                writeln!(output, "{}", json!({ "error": e, "command": line.trim() }))
+                    .map_err(|e| format!("Failed to write script output: {e}"))?;
+                continue;
+            }
+        };
+
+        // Write each wallet as soon as it's found; keep the first write error for later
+        let mut written = Ok(());
+        pool.search(&command.search_params(pool.threads(), pbkdf2_rounds), |result| {
+            if written.is_ok() {
+                written = writeln!(output, "{}", result_json(&command, result)).and_then(|()| output.flush());
+            }
+        })?;
+        written.map_err(|e| format!("Failed to write script output: {e}"))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_command() {
+        let command = parse_command(r#"{"term":"ai3","count":2,"network":"Polkadot"}"#).unwrap();
+        assert_eq!(command.term, "ai3");
+        assert_eq!(command.count, 2);
+        assert_eq!(command.ss58_prefix, 0);
+        assert!(command.suffix && !command.anywhere && !command.hex);
+        assert_eq!(command.within, 3);
+
+        let command = parse_command(r#"{"term":"ab","mode":"anywhere","ss58_prefix":42,"hex":true}"#).unwrap();
+        assert_eq!((command.count, command.ss58_prefix), (1, 42));
+        assert!(command.anywhere && command.hex);
+        assert_eq!(command.within, crate::validation::ss58_address_length_for_prefix(42));
+
+        assert!(parse_command("ai3").is_err());
+        assert!(parse_command(r#"{"count":1}"#).is_err());
+        assert!(parse_command(r#"{"term":"ai3","count":0}"#).is_err());
+        assert!(parse_command(r#"{"term":"ai3","network":"Kusama"}"#).is_err());
+        assert!(parse_command(r#"{"term":"ai3","mode":"middle"}"#).is_err());
+        assert!(parse_command(r#"{"term":"ai3","Count":1}"#).unwrap_err().contains("Unknown key 'Count'"));
+    }
+
+    #[test]
+    fn test_run_script() {
+        let input = "{\"term\":\"a\",\"mode\":\"anywhere\",\"hex\":true,\"network\":\"Substrate\"}\n\n{\"term\":\"l0\"}\n";
+        let mut output = Vec::new();
+        run_script(input.as_bytes(), &mut output, 1, crate::wallet::DEFAULT_PBKDF2_ROUNDS).unwrap();
+
+        let lines: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        let address = lines[0]["address"].as_str().unwrap();
+        assert!(address.to_lowercase().contains('a'));
+        let seed: [u8; 32] = hex::decode(lines[0]["secret"].as_str().unwrap()).unwrap().try_into().unwrap();
+        assert_eq!(crate::wallet::hex_to_address_with_prefix(&seed, 42), address);
+        assert!(lines[1]["error"].is_string());
+    }
+
+    #[test]
+    fn test_script_reuses_pool_rng() {
+        // With one seeded worker, a fresh RNG per command would find the same wallet twice
+        let command = "{\"term\":\"a\",\"mode\":\"anywhere\",\"hex\":true,\"network\":\"Substrate\"}\n";
+        let run = |input: &str| {
+            let mut output = Vec::new();
+            let pool = SearchPool::new(1, Some([7; 32]));
+            run_script_with_pool(input.as_bytes(), &mut output, &pool, crate::wallet::DEFAULT_PBKDF2_ROUNDS).unwrap();
+            String::from_utf8(output)
+                .unwrap()
+                .lines()
+                .map(|line| serde_json::from_str::<Value>(line).unwrap()["secret"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let secrets = run(&command.repeat(2));
+        assert_eq!(secrets.len(), 2);
+        assert_ne!(secrets[0], secrets[1]);
+        // The first command starts from the seed like a fresh pool would
+        assert_eq!(run(command), secrets[..1]);
+    }
+}
@@ -1,6 +1,10 @@
 use crate::runner::VanityResult;
-use num_format::{SystemLocale, ToFormattedString};
+use crate::search::MatchType;
+use num_format::ToFormattedString;
 use std::io::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(unix)]
+use std::sync::{atomic::AtomicBool, Arc, OnceLock};
 
 #[cfg(windows)]
 use winapi::um::consoleapi::{GetConsoleMode, SetConsoleMode};
@@ -11,7 +15,7 @@ use winapi::um::processenv::GetStdHandle;
 #[cfg(windows)]
 use winapi::um::winbase::STD_OUTPUT_HANDLE;
 #[cfg(windows)]
-use winapi::um::wincon::ENABLE_VIRTUAL_TERMINAL_PROCESSING;
+use winapi::um::wincon::{GetConsoleScreenBufferInfo, CONSOLE_SCREEN_BUFFER_INFO, ENABLE_VIRTUAL_TERMINAL_PROCESSING};
 
 // ===== Terminal Control =====
 
@@ -35,37 +39,186 @@ pub fn enable_ansi_support() {
     // No-op on Unix-like systems
 }
 
+/// Decide whether output is colored: off with `--no-color` or a non-empty `NO_COLOR`
+/// (see no-color.org)
+pub fn init_color_mode(no_color_flag: bool) {
+    let no_color_env = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    colors::set_enabled(!(no_color_flag || no_color_env));
+}
+
+/// Digit grouping for counts: the system locale, or plain `en` on wasm32 where
+/// num-format can't read it
+#[cfg(not(target_arch = "wasm32"))]
+pub fn number_locale() -> num_format::SystemLocale {
+    num_format::SystemLocale::default().unwrap()
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn number_locale() -> num_format::Locale {
+    num_format::Locale::en
+}
+
+/// Width assumed when the terminal size can't be determined (output not a terminal)
+pub const DEFAULT_TERMINAL_WIDTH: usize = 80;
+
+/// Last known terminal width in columns
+static TERMINAL_WIDTH: AtomicUsize = AtomicUsize::new(DEFAULT_TERMINAL_WIDTH);
+
+/// Set by the SIGWINCH handler; the width is re-read on the next progress update
+#[cfg(unix)]
+fn resize_flag() -> &'static Arc<AtomicBool> {
+    static RESIZED: OnceLock<Arc<AtomicBool>> = OnceLock::new();
+    RESIZED.get_or_init(|| Arc::new(AtomicBool::new(false)))
+}
+
+/// Query the width of the terminal on stdout
+#[cfg(unix)]
+fn query_terminal_width() -> Option<usize> {
+    // SAFETY: TIOCGWINSZ only writes a winsize struct into `size`
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    let rc = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) };
+    (rc == 0 && size.ws_col > 0).then_some(usize::from(size.ws_col))
+}
+
+/// Query the width of the console window on stdout
+#[cfg(windows)]
+fn query_terminal_width() -> Option<usize> {
+    unsafe {
+        let handle = GetStdHandle(STD_OUTPUT_HANDLE);
+        let mut info: CONSOLE_SCREEN_BUFFER_INFO = std::mem::zeroed();
+        if handle == INVALID_HANDLE_VALUE || GetConsoleScreenBufferInfo(handle, &mut info) == 0 {
+            return None;
+        }
+        usize::try_from(info.srWindow.Right - info.srWindow.Left + 1).ok()
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn query_terminal_width() -> Option<usize> {
+    None
+}
+
+/// Read the terminal width now and keep it current as the terminal is resized
+///
+/// On Unix a SIGWINCH handler flags each resize. Windows has no resize signal, so
+/// `terminal_width` queries the console on every call instead.
+pub fn watch_terminal_width() {
+    TERMINAL_WIDTH.store(query_terminal_width().unwrap_or(DEFAULT_TERMINAL_WIDTH), Ordering::Relaxed);
+
+    #[cfg(unix)]
+    if let Err(e) = signal_hook::flag::register(signal_hook::consts::SIGWINCH, Arc::clone(resize_flag())) {
+        tracing::debug!("Failed to watch terminal resizes: {e}");
+    }
+}
+
+/// Current terminal width in columns, `DEFAULT_TERMINAL_WIDTH` if unknown
+pub fn terminal_width() -> usize {
+    #[cfg(unix)]
+    if resize_flag().swap(false, Ordering::Relaxed) {
+        TERMINAL_WIDTH.store(query_terminal_width().unwrap_or(DEFAULT_TERMINAL_WIDTH), Ordering::Relaxed);
+    }
+    #[cfg(windows)]
+    TERMINAL_WIDTH.store(query_terminal_width().unwrap_or(DEFAULT_TERMINAL_WIDTH), Ordering::Relaxed);
+
+    TERMINAL_WIDTH.load(Ordering::Relaxed)
+}
+
+/// Cut `line` to at most `width` visible characters
+///
+/// ANSI escape sequences take no columns and are kept whole; a truncated line gets
+/// a color reset so the cut doesn't leave a color running.
+pub fn truncate_to_width(line: &str, width: usize) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut visible = 0;
+    let mut chars = line.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch == '\x1b' {
+            // Copy the escape sequence through its final byte (a letter)
+            out.push(ch);
+            for next in chars.by_ref() {
+                out.push(next);
+                if next.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else if ch == '\r' {
+            out.push(ch);
+        } else if visible < width {
+            out.push(ch);
+            visible += 1;
+        } else {
+            out.push_str("\x1b[0m");
+            break;
+        }
+    }
+    out
+}
 
 /// Cross-platform color helper functions
+///
+/// Every helper returns the text unchanged when color is off (`--no-color` or
+/// `NO_COLOR`), so callers never need to check.
 pub mod colors {
-    use colored::Colorize;
+    use colored::{ColoredString, Colorize};
+    use std::cell::Cell;
+
+    thread_local! {
+        static COLOR_ENABLED: Cell<bool> = const { Cell::new(true) };
+    }
+
+    /// Turn color codes on or off for the calling thread
+    pub fn set_enabled(enabled: bool) {
+        COLOR_ENABLED.set(enabled);
+    }
+
+    /// Whether the helpers on the calling thread emit color codes
+    pub fn enabled() -> bool {
+        COLOR_ENABLED.get()
+    }
+
+    fn paint(text: &str, style: impl FnOnce(&str) -> ColoredString) -> String {
+        if enabled() {
+            style(text).to_string()
+        } else {
+            text.to_string()
+        }
+    }
     
     pub fn red(text: &str) -> String {
-        text.red().to_string()
+        paint(text, |t| t.red())
     }
     
     pub fn green(text: &str) -> String {
-        text.green().to_string()
+        paint(text, |t| t.green())
     }
     
     pub fn yellow(text: &str) -> String {
-        text.yellow().to_string()
+        paint(text, |t| t.yellow())
     }
     
     pub fn gray(text: &str) -> String {
-        text.bright_black().to_string()
+        paint(text, |t| t.bright_black())
     }
     
     pub fn bright_yellow(text: &str) -> String {
-        text.bright_yellow().to_string()
+        paint(text, |t| t.bright_yellow())
     }
     
     pub fn orange(text: &str) -> String {
-        text.truecolor(255, 165, 0).to_string()
+        paint(text, |t| t.truecolor(255, 165, 0))
     }
     
     pub fn white(text: &str) -> String {
-        text.white().to_string()
+        paint(text, |t| t.white())
+    }
+    
+    pub fn cyan(text: &str) -> String {
+        paint(text, |t| t.cyan())
+    }
+
+    pub fn blue(text: &str) -> String {
+        paint(text, |t| t.blue())
     }
     
 }
@@ -104,15 +257,32 @@ pub fn clear_screen_completely() {
 }
 
 /// Format ETA showing the two largest time components
-pub fn format_eta(seconds: u64) -> String {
-    use num_format::{SystemLocale, ToFormattedString};
+///
+/// ETAs under 10 seconds keep one decimal place (e.g. "~3.2s") so quick finds
+/// don't all collapse to a handful of whole seconds. Past 10 years only the years
+/// are shown, and past 1,000 years the search is flagged as impractical.
+pub fn format_eta(seconds: f64) -> String {
     
-    if seconds == 0 {
+    if seconds <= 0.0 {
         return "~0s".to_string();
     }
 
-    let locale = SystemLocale::default().unwrap();
+    // Values that would round up to 10.0 use the whole-second format
+    if seconds < 9.95 {
+        return format!("~{:.1}s", seconds);
+    }
+
+    // Float-to-int casts saturate, so infinite ETAs show as the largest duration
+    let seconds = (seconds as u64).max(10);
+
+    let locale = number_locale();
     let years = seconds / (365 * 24 * 3600);
+    if years > 1000 {
+        return colors::red("> 1,000 years (impractical)");
+    }
+    if years > 10 {
+        return format!("~{}y", years.to_formatted_string(&locale));
+    }
     let months = (seconds % (365 * 24 * 3600)) / (30 * 24 * 3600);
     let weeks = (seconds % (30 * 24 * 3600)) / (7 * 24 * 3600);
     let days = (seconds % (7 * 24 * 3600)) / (24 * 3600);
@@ -157,13 +327,12 @@ pub fn format_eta(seconds: u64) -> String {
 
 /// Format negative ETA (how long we've been over the expected time)
 pub fn format_eta_negative(seconds: u64) -> String {
-    use num_format::{SystemLocale, ToFormattedString};
     
     if seconds == 0 {
         return "-1s".to_string();
     }
 
-    let locale = SystemLocale::default().unwrap();
+    let locale = number_locale();
     let years = seconds / (365 * 24 * 3600);
     let months = (seconds % (365 * 24 * 3600)) / (30 * 24 * 3600);
     let weeks = (seconds % (30 * 24 * 3600)) / (7 * 24 * 3600);
@@ -255,19 +424,55 @@ pub fn format_runtime_with_nanos(elapsed_secs: u64, elapsed_nanos: u128) -> Stri
     }
 }
 
+/// Threads `--per-thread-stats` shows on the progress line
+pub const MAX_PROGRESS_THREADS: usize = 8;
+
+/// Compact rate for the per-thread breakdown: 950, 45k, 1.2M
+fn format_compact_rate(rate: u64) -> String {
+    match rate {
+        0..1_000 => rate.to_string(),
+        1_000..1_000_000 => format!("{}k", rate / 1_000),
+        #[allow(clippy::cast_precision_loss)]
+        _ => format!("{:.1}M", rate as f64 / 1_000_000.0),
+    }
+}
+
+/// `[T0:45k T1:43k ...]` for the first [`MAX_PROGRESS_THREADS`] threads; threads under
+/// 80% of the fastest one's rate are red
+pub fn format_thread_breakdown(thread_keys_per_second: &[u64]) -> String {
+    let fastest = thread_keys_per_second.iter().copied().max().unwrap_or(0);
+    let mut parts: Vec<String> = thread_keys_per_second
+        .iter()
+        .take(MAX_PROGRESS_THREADS)
+        .enumerate()
+        .map(|(i, &rate)| {
+            let part = format!("T{i}:{}", format_compact_rate(rate));
+            // rate < 0.8 * fastest, in integers
+            if rate * 5 < fastest * 4 { colors::red(&part) } else { part }
+        })
+        .collect();
+    if thread_keys_per_second.len() > MAX_PROGRESS_THREADS {
+        parts.push("...".to_string());
+    }
+    format!("[{}]", parts.join(" "))
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn print_progress(
     keys_per_second: u64,
     total_attempts: u64,
     elapsed_secs: u64,
-    eta: Option<&str>,
+    eta_next_wallet: Option<&str>,
+    eta_all_remaining: Option<&str>,
     luck: Option<f64>,
     found_count: usize,
     count: usize,
+    thread_keys_per_second: Option<&[u64]>,
 ) {
     let time_str = format_runtime(elapsed_secs);
 
     // Build progress string - ALWAYS show full progress info during search
-    let locale = SystemLocale::default().unwrap();
+    let locale = number_locale();
     let mut progress = format!(
         "\r{}{} {} · {} {} keys/s · {} {}",
         terminal_codes::CLEAR_LINE,
@@ -279,8 +484,15 @@ pub fn print_progress(
         time_str
     );
 
-    // Add batch progress if generating multiple wallets
-    if count > 1 {
+    // Add batch progress if generating multiple wallets; unlimited runs just count
+    if count == 0 {
+        use std::fmt::Write;
+        let _ = write!(
+            progress,
+            " · {} {found_count}",
+            colors::gray("Found:")
+        );
+    } else if count > 1 {
         use std::fmt::Write;
         let _ = write!(
             progress,
@@ -289,14 +501,26 @@ pub fn print_progress(
         );
     }
 
-    // Add ETA if provided
-    if let Some(eta_str) = eta {
-        use std::fmt::Write;
-        let _ = write!(
-            progress, 
-            " · {} {eta_str}",
-            colors::gray("ETA:")
-        );
+    // Multi-wallet runs show the next wallet and the whole remaining batch side by side
+    match (eta_next_wallet, eta_all_remaining) {
+        (Some(eta_str), Some(eta_all_str)) => {
+            use std::fmt::Write;
+            let _ = write!(
+                progress,
+                " · {} {eta_str} | {} {eta_all_str}",
+                colors::gray("Next:"),
+                colors::gray("Total:")
+            );
+        }
+        (Some(eta_str), None) => {
+            use std::fmt::Write;
+            let _ = write!(
+                progress,
+                " · {} {eta_str}",
+                colors::gray("ETA:")
+            );
+        }
+        _ => {}
     }
 
     // Add luck factor if provided
@@ -313,44 +537,71 @@ pub fn print_progress(
         );
     }
 
+    // Per-thread breakdown for --per-thread-stats
+    if let Some(rates) = thread_keys_per_second.filter(|rates| !rates.is_empty()) {
+        progress.push(' ');
+        progress.push_str(&format_thread_breakdown(rates));
+    }
+
+    // Stay one column short of the edge so the cursor never wraps to a new line
+    let progress = truncate_to_width(&progress, terminal_width().saturating_sub(1));
+
     let mut stdout = io::stdout();
     let _ = stdout.write_all(progress.as_bytes());
     let _ = stdout.flush();
 }
 
-pub fn print_result(result: &VanityResult, hex_mode: bool, wallet_number: usize, elapsed_secs: u64, elapsed_nanos: u128, luck: f64, validation_status: &str) {
-    use num_format::{SystemLocale, ToFormattedString};
+/// Shown instead of the secret with `--redact-secrets`
+pub const REDACTED_SECRET: &str = "[REDACTED - saved to file only]";
+
+/// The secret as it may appear on screen
+fn displayed_secret(result: &VanityResult, redact_secrets: bool) -> &str {
+    if redact_secrets { REDACTED_SECRET } else { &result.secret }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn print_result(result: &VanityResult, hex_mode: bool, wallet_number: usize, elapsed_secs: u64, elapsed_nanos: u128, luck: f64, percentile: f64, validation_status: &str, redact_secrets: bool) {
     
     let address = &result.address;
     let highlighted_address = if result.matches > 0 {
         let start = result.offset;
         let end = start + result.matches;
-        format!(
-            "{}{}{}",
-            &address[..start],
-            colors::bright_yellow(&address[start..end]),
-            &address[end..]
-        )
+        let mut highlighted = address[..start].to_string();
+        // Color each matched character by how it matched (literal if no detail was recorded)
+        for (i, ch) in address[start..end].char_indices() {
+            let ch = ch.to_string();
+            highlighted.push_str(&match result.match_detail.get(i) {
+                Some(MatchType::Wildcard) => colors::cyan(&ch),
+                Some(MatchType::CaseInsensitive) => colors::yellow(&ch),
+                Some(MatchType::Literal) | None => colors::bright_yellow(&ch),
+            });
+        }
+        highlighted.push_str(&address[end..]);
+        highlighted
     } else {
         address.clone()
     };
 
-    println!("{} Address {}: {}", colors::white("•"), wallet_number, highlighted_address);
+    println!("{} Address {} {}: {}", colors::white("•"), wallet_number,
+             colors::gray(&format!("({})", result.network_name)), highlighted_address);
 
     let secret_label = if hex_mode { "Private Key" } else { "Mnemonic" };
-    println!("  {} {}: {}", colors::gray("└"), secret_label, result.secret);
-    
+    println!("  {} {}: {}", colors::gray("└"), secret_label, displayed_secret(result, redact_secrets));
+
+    // None of the stats are derived from the secret, so --redact-secrets keeps them
     let found_in_str = format_runtime_with_nanos(elapsed_secs, elapsed_nanos);
-    let locale = SystemLocale::default().unwrap();
+    let locale = number_locale();
     let attempts_str = result.attempts.to_formatted_string(&locale);
     let formatted_luck = (luck as u64).to_formatted_string(&locale);
     let luck_text = format!("{}%", formatted_luck);
     let colored_luck = get_luck_color(luck, &luck_text);
     println!(
-        "  {} Stats: {} {} {} {} {} {} {}",
+        "  {} Stats: {} {} {} {:.1}% {} {} {} {} {}",
         colors::gray("└"),
         colors::gray("Luck:"),
         colored_luck,
+        colors::gray("Percentile:"),
+        percentile,
         colors::gray("Found in:"),
         found_in_str,
         colors::gray("Attempts:"),
@@ -359,6 +610,41 @@ pub fn print_result(result: &VanityResult, hex_mode: bool, wallet_number: usize,
     );
 }
 
+/// One tab-separated `ADDRESS<TAB>SECRET` line for `--quiet` runs
+pub fn print_result_quiet(result: &VanityResult, redact_secrets: bool) {
+    let template = if redact_secrets { format!("{{address}}\t{REDACTED_SECRET}") } else { "{address}\t{secret}".to_string() };
+    println!("{}", result.format_with(&template));
+}
+
+/// Draw the address as a template with the fixed network prefix and the search zone
+///
+/// `search_range` holds the first and last start positions of the pattern, so the zone
+/// runs to `end + pattern_len`. Zone positions are drawn as `X`, everything else the
+/// search can't affect as `-`; e.g. `[suXX-----...]` for a two-character prefix search.
+/// The plain text is always `ss58_len + 2` characters wide.
+pub fn visualize_search_position(prefix: &str, ss58_len: usize, search_range: Option<(usize, usize)>, pattern_len: usize) -> String {
+    let prefix_len = prefix.chars().count().min(ss58_len);
+    let prefix: String = prefix.chars().take(prefix_len).collect();
+
+    // The zone never covers the prefix, which is the same in every address
+    let (zone_start, zone_end) = match search_range {
+        Some((start, end)) => {
+            let zone_end = (end + pattern_len).min(ss58_len);
+            (start.clamp(prefix_len, zone_end), zone_end.max(prefix_len))
+        }
+        None => (ss58_len, ss58_len),
+    };
+
+    format!(
+        "[{}{}{}{}]",
+        colors::blue(&prefix),
+        "-".repeat(zone_start - prefix_len),
+        colors::bright_yellow(&"X".repeat(zone_end - zone_start)),
+        "-".repeat(ss58_len - zone_end)
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn print_header(
     target: &str,
     suffix: bool,
@@ -367,6 +653,8 @@ pub fn print_header(
     case_sensitive: bool,
     hex_mode: bool,
     threads: usize,
+    ss58_prefix: u16,
+    search_range: Option<(usize, usize)>,
 ) -> usize {
     let position_str = if anywhere {
         "anywhere"
@@ -410,24 +698,70 @@ pub fn print_header(
         )
     };
 
-    let length = plain_header.len();
+    // Fixed characters shared by every address of the network
+    let network_prefix = crate::networks::find_network_by_prefix(ss58_prefix)
+        .and_then(|network| network.address_prefixes.iter().min_by_key(|p| p.len()))
+        .copied()
+        .unwrap_or("");
+    let ss58_len = crate::validation::ss58_address_length_for_prefix(ss58_prefix);
+
     println!("{header}");
-    length
+    println!("{}", visualize_search_position(network_prefix, ss58_len, search_range, target.chars().count()));
+    plain_header.len().max(ss58_len + 2)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_thread_breakdown() {
+        colors::set_enabled(false);
+        assert_eq!(format_thread_breakdown(&[45_300, 43_000, 950]), "[T0:45k T1:43k T2:950]");
+        assert_eq!(format_thread_breakdown(&[1_250_000]), "[T0:1.2M]");
+        assert_eq!(format_thread_breakdown(&[1; 10]), "[T0:1 T1:1 T2:1 T3:1 T4:1 T5:1 T6:1 T7:1 ...]");
+
+        // 80 is exactly 80% of the fastest, 79 is below it
+        colors::set_enabled(true);
+        assert_eq!(format_thread_breakdown(&[100, 80, 79]), format!("[T0:100 T1:80 {}]", colors::red("T2:79")));
+    }
+
+
+    #[test]
+    fn test_displayed_secret() {
+        let result = VanityResult::for_test("suAbC", "abandon ability able");
+        assert_eq!(displayed_secret(&result, false), "abandon ability able");
+        assert_eq!(displayed_secret(&result, true), REDACTED_SECRET);
+    }
 
     #[test]
     fn test_format_eta() {
-        assert_eq!(format_eta(45), "~45s");
-        assert_eq!(format_eta(125), "~2m 5s");
-        assert_eq!(format_eta(3_665), "~1h 1m");
-        assert_eq!(format_eta(90_000), "~1d 1h");
+        assert_eq!(format_eta(45.0), "~45s");
+        assert_eq!(format_eta(125.0), "~2m 5s");
+        assert_eq!(format_eta(3_665.0), "~1h 1m");
+        assert_eq!(format_eta(90_000.0), "~1d 1h");
         // Exactly 1 year shows as 1y 1d due to calculation
-        assert_eq!(format_eta(31_536_000), "~1y 1d");
+        assert_eq!(format_eta(31_536_000.0), "~1y 1d");
+
+        // Beyond 10 years only the years matter, beyond 1,000 the search is hopeless
+        const YEAR: f64 = 31_536_000.0;
+        assert_eq!(format_eta(25.5 * YEAR), "~25y");
+        colors::set_enabled(false);
+        assert_eq!(format_eta(1001.0 * YEAR), "> 1,000 years (impractical)");
+        assert_eq!(format_eta(f64::INFINITY), "> 1,000 years (impractical)");
+    }
+
+    #[test]
+    fn test_format_eta_sub_second_precision() {
+        assert_eq!(format_eta(0.5), "~0.5s");
+        assert_eq!(format_eta(3.7), "~3.7s");
+        assert_eq!(format_eta(9.9), "~9.9s");
+        assert_eq!(format_eta(0.0), "~0s");
+
+        // From 10 seconds on, whole seconds only
+        assert_eq!(format_eta(9.96), "~10s");
+        assert_eq!(format_eta(10.0), "~10s");
+        assert_eq!(format_eta(12.8), "~12s");
     }
     
     #[test]
@@ -443,4 +777,47 @@ mod tests {
         assert_eq!(format_runtime_with_nanos(125, 125_000_000_000), "02:05");
         assert_eq!(format_runtime_with_nanos(3665, 3_665_000_000_000), "01:01:05");
     }
+
+    #[test]
+    fn test_visualize_search_position() {
+        colors::set_enabled(false);
+
+        // Prefix search right after the fixed "su"
+        let line = visualize_search_position("su", 49, Some((2, 2)), 2);
+        assert_eq!(line, format!("[suXX{}]", "-".repeat(45)));
+
+        // Suffix search runs to the end of the address
+        let line = visualize_search_position("5", 48, Some((45, 45)), 3);
+        assert_eq!(line, format!("[5{}XXX]", "-".repeat(44)));
+
+        // Anywhere search from the first free position; never drawn over the prefix
+        let line = visualize_search_position("su", 49, Some((0, 46)), 3);
+        assert_eq!(line, format!("[su{}]", "X".repeat(47)));
+
+        // No valid range draws no zone
+        assert_eq!(visualize_search_position("", 48, None, 4), format!("[{}]", "-".repeat(48)));
+    }
+
+    #[test]
+    fn test_truncate_to_width() {
+        assert_eq!(truncate_to_width("\rshort", 10), "\rshort");
+
+        // Escape sequences take no columns and are never cut in half
+        let line = format!("\r{}Speed: \x1b[90m1,234\x1b[0m keys/s", terminal_codes::CLEAR_LINE);
+        assert_eq!(truncate_to_width(&line, 9), format!("\r{}Speed: \x1b[90m1,\x1b[0m", terminal_codes::CLEAR_LINE));
+
+        assert_eq!(truncate_to_width("a · b · c", 3), "a ·\x1b[0m");
+        assert_eq!(truncate_to_width("abc", 0), "\x1b[0m");
+    }
+
+    #[test]
+    fn test_colors_disabled_returns_plain_text() {
+        colors::set_enabled(false);
+        assert_eq!(colors::red("error"), "error");
+        assert_eq!(colors::orange("luck"), "luck");
+        assert!(!colors::enabled());
+
+        // The flag is per thread; other threads keep their own setting
+        std::thread::spawn(|| assert!(colors::enabled())).join().unwrap();
+    }
 }
\ No newline at end of file
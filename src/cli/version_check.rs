@@ -0,0 +1,103 @@
+//! `--version-check`: compare this build against the latest GitHub release
+//!
+//! The HTTP client is only compiled with the `version-check` feature, so offline
+//! builds don't pull in a TLS stack. Failures are reported as warnings; the check
+//! never stops the tool.
+
+use crate::cli::terminal::colors;
+
+/// GitHub API endpoint for the newest published release
+pub const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/vexr/autoseed/releases/latest";
+
+/// How long to wait for GitHub before giving up
+pub const VERSION_CHECK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Parse `v1.2.3` or `1.2.3` into numeric components; pre-release suffixes are ignored
+fn parse_version(version: &str) -> Option<Vec<u64>> {
+    let version = version.trim().trim_start_matches('v');
+    let core = version.split(['-', '+']).next()?;
+    core.split('.').map(|part| part.parse().ok()).collect()
+}
+
+/// Whether release `latest` is newer than `current`, or `None` if either doesn't parse
+pub fn is_outdated(current: &str, latest: &str) -> Option<bool> {
+    let (mut current, mut latest) = (parse_version(current)?, parse_version(latest)?);
+    // Compare 1.2 and 1.2.0 as equal
+    let len = current.len().max(latest.len());
+    current.resize(len, 0);
+    latest.resize(len, 0);
+    Some(latest > current)
+}
+
+/// Fetch the latest release tag from GitHub
+#[cfg(feature = "version-check")]
+fn fetch_latest_tag() -> Result<String, String> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(VERSION_CHECK_TIMEOUT)
+        // GitHub's API rejects requests without a User-Agent
+        .user_agent(concat!("autoseed/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+    let body = client
+        .get(LATEST_RELEASE_URL)
+        .send()
+        .and_then(reqwest::blocking::Response::error_for_status)
+        .and_then(reqwest::blocking::Response::text)
+        .map_err(|e| format!("Failed to reach GitHub: {e}"))?;
+
+    let release: serde_json::Value =
+        serde_json::from_str(&body).map_err(|e| format!("Invalid release response: {e}"))?;
+    release["tag_name"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| "Release response has no tag_name".to_string())
+}
+
+#[cfg(not(feature = "version-check"))]
+fn fetch_latest_tag() -> Result<String, String> {
+    Err("this build has no HTTP client\nSUGGESTION: Rebuild with `--features version-check`".to_string())
+}
+
+/// Print whether this build is the latest release; problems only produce a warning
+pub fn print_version_check() {
+    let current = env!("CARGO_PKG_VERSION");
+    let latest = match fetch_latest_tag() {
+        Ok(tag) => tag,
+        Err(e) => {
+            println!("{}: Version check failed: {}", colors::yellow("WARNING"), e);
+            return;
+        }
+    };
+
+    match is_outdated(current, &latest) {
+        Some(true) => println!(
+            "{} autoseed {} is outdated, the latest release is {}",
+            colors::yellow("→"),
+            current,
+            latest
+        ),
+        Some(false) => println!("{} autoseed {} is up to date", colors::green("✓"), current),
+        None => println!(
+            "{}: Can't compare version {} with release tag '{}'",
+            colors::yellow("WARNING"),
+            current,
+            latest
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_outdated() {
+        assert_eq!(is_outdated("0.1.2", "v0.1.3"), Some(true));
+        assert_eq!(is_outdated("0.1.2", "v0.2.0"), Some(true));
+        assert_eq!(is_outdated("0.1.2", "v0.1.2"), Some(false));
+        assert_eq!(is_outdated("0.1.10", "0.1.9"), Some(false));
+        assert_eq!(is_outdated("1.2", "v1.2.0"), Some(false));
+        assert_eq!(is_outdated("1.2.0", "v1.2.0-rc.1"), Some(false));
+        assert_eq!(is_outdated("0.1.2", "nightly"), None);
+    }
+}
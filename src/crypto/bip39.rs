@@ -9,6 +9,7 @@
 //! - BIP39 specification: https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki
 //! - English wordlist: 2048 words as defined in BIP39
 
+use crate::crypto::rng::{ChaCha20Rng, WALLET_NONCE};
 
 /// BIP39 mnemonic error types
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -163,35 +164,35 @@ impl std::str::FromStr for Mnemonic {
     }
 }
 
-/// Generate a random mnemonic with 12 words (128 bits entropy)
+/// Generate a random mnemonic with 12 words (128 bits entropy) from a fresh
+/// system-entropy RNG
 pub fn generate_mnemonic() -> Mnemonic {
-    use crate::crypto::rng::ChaCha20Rng;
-    
-    thread_local! {
-        static RNG: std::cell::RefCell<Option<ChaCha20Rng>> = std::cell::RefCell::new(None);
-    }
-    
+    let mut rng = ChaCha20Rng::from_system_entropy_with_nonce(WALLET_NONCE)
+        .expect("Failed to initialize cryptographically secure RNG");
+    generate_mnemonic_with_rng(&mut rng)
+}
+
+/// Generate a random 12-word mnemonic from the caller's RNG
+pub fn generate_mnemonic_with_rng(rng: &mut ChaCha20Rng) -> Mnemonic {
     let mut entropy = [0u8; 16]; // 128 bits = 12 words
-    
-    // Use thread-local cryptographically secure RNG
-    RNG.with(|rng_cell| {
-        let mut rng_opt = rng_cell.borrow_mut();
-        
-        // Initialize RNG if not already done
-        if rng_opt.is_none() {
-            *rng_opt = Some(ChaCha20Rng::from_system_entropy()
-                .expect("Failed to initialize cryptographically secure RNG"));
-        }
-        
-        // Generate cryptographically secure entropy
-        if let Some(ref mut rng) = *rng_opt {
-            rng.fill_bytes(&mut entropy);
-        }
-    });
-    
+    rng.fill_bytes(&mut entropy);
     Mnemonic::from_entropy(&entropy).expect("Valid entropy length")
 }
 
+/// Generate a random mnemonic of `word_count` words (12, 15, 18, 21 or 24) from the
+/// caller's RNG
+pub fn generate_mnemonic_with_word_count(rng: &mut ChaCha20Rng, word_count: usize) -> Result<Mnemonic, Error> {
+    if !matches!(word_count, 12 | 15 | 18 | 21 | 24) {
+        return Err(Error::InvalidWordCount(word_count));
+    }
+
+    // Every 3 words carry 32 bits of entropy (plus one checksum bit)
+    let mut entropy = [0u8; 32];
+    let entropy_len = word_count / 3 * 4;
+    rng.fill_bytes(&mut entropy[..entropy_len]);
+    Mnemonic::from_entropy(&entropy[..entropy_len])
+}
+
 
 /// Find word index in the English wordlist
 fn find_word_index(word: &str) -> Option<u16> {
@@ -0,0 +1,87 @@
+//! Suggestions for mistyped BIP39 words (`--verify-mnemonic`)
+//!
+//! Every word of a phrase is looked up in the English wordlist; unknown words get the
+//! closest list words by Levenshtein distance. Ties keep wordlist order.
+
+use crate::crypto::bip39::ENGLISH_WORDS;
+
+/// Suggestions shown for each unknown word
+pub const SUGGESTION_COUNT: usize = 3;
+
+/// A phrase word that isn't in the wordlist
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownWord {
+    /// 1-based position in the phrase
+    pub position: usize,
+    pub word: String,
+    /// Closest wordlist entries, best first
+    pub suggestions: Vec<&'static str>,
+}
+
+/// Edit distance (insertions, deletions, substitutions) between `a` and `b`
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // One row of the DP table at a time
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// The `k` words of `wordlist` closest to `word`, nearest first
+pub fn find_closest_words<'a>(word: &str, wordlist: &[&'a str], k: usize) -> Vec<&'a str> {
+    let word = word.to_lowercase();
+    let mut scored: Vec<(usize, &'a str)> = wordlist.iter().map(|&candidate| (levenshtein(&word, candidate), candidate)).collect();
+    // Stable, so equally close words stay in wordlist order
+    scored.sort_by_key(|&(distance, _)| distance);
+    scored.into_iter().take(k).map(|(_, candidate)| candidate).collect()
+}
+
+/// Every word of `phrase` missing from the English wordlist, with suggestions
+pub fn unknown_words(phrase: &str) -> Vec<UnknownWord> {
+    phrase
+        .split_whitespace()
+        .enumerate()
+        .filter(|(_, word)| !ENGLISH_WORDS.contains(word))
+        .map(|(i, word)| UnknownWord {
+            position: i + 1,
+            word: word.to_string(),
+            suggestions: find_closest_words(word, &ENGLISH_WORDS, SUGGESTION_COUNT),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("abandon", "abandon"), 0);
+        assert_eq!(levenshtein("abandn", "abandon"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "able"), 4);
+    }
+
+    #[test]
+    fn test_find_closest_words() {
+        assert_eq!(find_closest_words("abandn", &ENGLISH_WORDS, 1), ["abandon"]);
+        assert_eq!(find_closest_words("ZOO", &ENGLISH_WORDS, 1), ["zoo"]);
+        assert_eq!(find_closest_words("x", &["ab", "x", "xy"], 5), ["x", "xy", "ab"]);
+
+        let unknown = unknown_words("abandon abandn abandon abandon abandon abandon abandon abandon abandon abandon abandon abuot");
+        assert_eq!(unknown.len(), 2);
+        assert_eq!((unknown[0].position, unknown[0].word.as_str()), (2, "abandn"));
+        assert_eq!(unknown[0].suggestions.len(), SUGGESTION_COUNT);
+        assert_eq!(unknown[0].suggestions[0], "abandon");
+        assert_eq!(unknown[1].position, 12);
+        assert!(unknown[1].suggestions.contains(&"about"));
+    }
+}
@@ -58,6 +58,16 @@ pub struct Blake2b512 {
 impl Blake2b512 {
     /// Create a new BLAKE2b-512 hasher
     pub fn new() -> Self {
+        Self::with_digest_length(BLAKE2B_OUTBYTES)
+    }
+
+    /// Create a BLAKE2b hasher for a shorter digest (e.g. 32 for BLAKE2b-256)
+    ///
+    /// The length is part of the parameter block, so this is not a truncated
+    /// BLAKE2b-512. `finalize` still returns 64 bytes; the digest is the first
+    /// `digest_length` of them.
+    pub fn with_digest_length(digest_length: usize) -> Self {
+        assert!((1..=BLAKE2B_OUTBYTES).contains(&digest_length), "BLAKE2b digest length must be 1-64 bytes");
         let mut state = Blake2b512 {
             h: BLAKE2B_IV,
             t: [0; 2],
@@ -66,9 +76,9 @@ impl Blake2b512 {
             buflen: 0,
         };
 
-        // Create parameter block for BLAKE2b-512
+        // Create parameter block
         let mut param_block = [0u8; 64];
-        param_block[0] = BLAKE2B_OUTBYTES as u8; // digest_length
+        param_block[0] = digest_length as u8; // digest_length
         param_block[1] = 0; // key_length  
         param_block[2] = 1; // fanout
         param_block[3] = 1; // depth
@@ -267,4 +277,19 @@ mod tests {
         
         assert_eq!(result, expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_blake2b_256_empty() {
+        let result = Blake2b512::with_digest_length(32).finalize();
+
+        // BLAKE2b-256 of empty string
+        let expected = [
+            0x0e, 0x57, 0x51, 0xc0, 0x26, 0xe5, 0x43, 0xb2,
+            0xe8, 0xab, 0x2e, 0xb0, 0x60, 0x99, 0xda, 0xa1,
+            0xd1, 0xe5, 0xdf, 0x47, 0x77, 0x8f, 0x77, 0x87,
+            0xfa, 0xab, 0x45, 0xcd, 0xf1, 0x2f, 0xe3, 0xa8,
+        ];
+
+        assert_eq!(result[..32], expected);
+    }
+}
@@ -1,8 +1,8 @@
 //! Base58 Encoding Implementation for SS58 Addresses
 //!
-//! This module provides Base58 encoding functionality specifically for
-//! encoding Substrate SS58 addresses in the vanity address generator.
-//! This is a minimal implementation that includes only the encoding functions
+//! This module provides Base58 encoding and decoding specifically for
+//! Substrate SS58 addresses in the vanity address generator.
+//! This is a minimal implementation that includes only the functions
 //! needed by our codebase.
 //!
 //! ## Attribution
@@ -13,13 +13,14 @@
 //! - Copyright: Steven Fackler
 //!
 //! ## Usage
-//! This implementation supports only the encoding operations needed for SS58:
+//! This implementation supports only the operations needed for SS58:
 //!
 //! ```rust
 //! use crate::crypto::bs58;
 //! 
 //! let data = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
 //! let encoded = bs58::encode(data).into_string();
+//! let decoded = bs58::decode(&encoded).into_vec().unwrap();
 //! ```
 //!
 //! ## References
@@ -31,17 +32,27 @@ use std::string::String;
 /// Bitcoin Base58 alphabet used for SS58 addresses
 const BITCOIN_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
 
-/// Errors that can occur during Base58 encoding
+/// Errors that can occur during Base58 encoding or decoding
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Error {
     /// The output buffer was too small to contain the entire input
     BufferTooSmall,
+    /// The input contained a character outside the Base58 alphabet
+    InvalidCharacter {
+        /// The offending character
+        character: char,
+        /// Byte offset of the character in the input
+        index: usize,
+    },
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Error::BufferTooSmall => write!(f, "output buffer too small for base58 encoding"),
+            Error::InvalidCharacter { character, index } => {
+                write!(f, "invalid base58 character {character:?} at index {index}")
+            }
         }
     }
 }
@@ -161,6 +172,73 @@ fn encode_into(input: &[u8], output: &mut [u8]) -> Result<usize, Error> {
     Ok(index)
 }
 
+/// A builder for Base58 decoding operations
+pub struct DecodeBuilder<'a> {
+    input: &'a str,
+}
+
+impl DecodeBuilder<'_> {
+    /// Decode the input into bytes
+    ///
+    /// Each leading '1' becomes a leading zero byte, mirroring the encoder.
+    pub fn into_vec(self) -> Result<Vec<u8>, Error> {
+        // Every Base58 digit carries less than a byte, so the input length bounds the output
+        let mut output = vec![0u8; self.input.len()];
+        let len = decode_into(self.input, &mut output)?;
+        output.truncate(len);
+        Ok(output)
+    }
+}
+
+/// Create a Base58 decoder for the given string
+pub fn decode(input: &str) -> DecodeBuilder<'_> {
+    DecodeBuilder { input }
+}
+
+/// Core Base58 decoding algorithm, the inverse of `encode_into`
+///
+/// Digits are accumulated little-endian into `output` (multiply by 58, add the
+/// digit), then the leading zero bytes are appended and the result is reversed.
+fn decode_into(input: &str, output: &mut [u8]) -> Result<usize, Error> {
+    let mut index = 0;
+
+    for (position, character) in input.char_indices() {
+        let digit = BITCOIN_ALPHABET
+            .iter()
+            .position(|&c| c as char == character)
+            .ok_or(Error::InvalidCharacter { character, index: position })?;
+        let mut carry = digit;
+
+        // Multiply existing bytes by 58 and add the new digit
+        for byte in &mut output[..index] {
+            carry += (*byte as usize) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+
+        while carry > 0 {
+            if index == output.len() {
+                return Err(Error::BufferTooSmall);
+            }
+            output[index] = (carry & 0xff) as u8;
+            index += 1;
+            carry >>= 8;
+        }
+    }
+
+    // Leading '1' characters encode leading zero bytes
+    for _ in input.bytes().take_while(|&b| b == BITCOIN_ALPHABET[0]) {
+        if index == output.len() {
+            return Err(Error::BufferTooSmall);
+        }
+        output[index] = 0;
+        index += 1;
+    }
+
+    output[..index].reverse();
+    Ok(index)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,4 +270,15 @@ mod tests {
         let result = encode(input).into_string();
         assert_eq!("2", result);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode("he11owor1d").into_vec(), Ok(vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]));
+        assert_eq!(decode("112").into_vec(), Ok(vec![0x00, 0x00, 0x01]));
+        assert_eq!(decode("").into_vec(), Ok(vec![]));
+        assert_eq!(
+            decode("he0").into_vec(),
+            Err(Error::InvalidCharacter { character: '0', index: 2 })
+        );
+    }
+}
@@ -1,8 +1,10 @@
 pub mod blake2b;
 pub mod bip39;
+pub mod bip39_autocorrect;
 pub mod bs58;
 pub mod pbkdf2;
 pub mod rng;
+pub mod shamir;
 pub mod substrate;
 
 // External crate re-exports for substrate compatibility
@@ -14,12 +16,44 @@ pub mod subtle;
 use crate::crypto::bip39::Mnemonic;
 use crate::crypto::substrate::sr25519::{Pair, IdentifyAccount};
 use crate::crypto::substrate::crypto::{Ss58Codec, Ss58AddressFormat};
-use crate::wallet::mnemonic_to_mini_secret;
+use crate::wallet::{mnemonic_to_mini_secret, DEFAULT_PBKDF2_ROUNDS};
 
 
 pub fn mnemonic_to_address_with_prefix(mnemonic: &Mnemonic, ss58_prefix: u16) -> String {
-    let mini_secret = mnemonic_to_mini_secret(mnemonic, "");
-    let pair = Pair::from_seed_slice(&mini_secret).expect("Failed to create pair");
+    mnemonic_to_address_with_rounds(mnemonic, ss58_prefix, DEFAULT_PBKDF2_ROUNDS)
+}
+
+/// Derive an address using a non-standard PBKDF2 round count (`--pbkdf2-rounds`)
+pub fn mnemonic_to_address_with_rounds(mnemonic: &Mnemonic, ss58_prefix: u16, pbkdf2_rounds: u32) -> String {
+    let mini_secret = mnemonic_to_mini_secret(mnemonic, "", pbkdf2_rounds);
+    mini_secret_to_address(&mini_secret, ss58_prefix)
+}
+
+/// Derive the address of every mnemonic, spread over Rayon's thread pool
+///
+/// Results are in input order. Meant for checking a list of known mnemonics against
+/// addresses without running a search.
+pub fn mnemonic_batch_to_addresses(mnemonics: &[&Mnemonic], ss58_prefix: u16) -> Vec<String> {
+    use rayon::prelude::*;
+
+    mnemonics
+        .par_iter()
+        .map(|mnemonic| {
+            let mini_secret = crate::wallet::mnemonic_to_mini_secret(mnemonic, "", DEFAULT_PBKDF2_ROUNDS);
+            mini_secret_to_address(&mini_secret, ss58_prefix)
+        })
+        .collect()
+}
+
+/// Derive four addresses at once using the AVX2 batched PBKDF2
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+pub fn mnemonics_to_addresses_batch_4(mnemonics: &[Mnemonic; 4], ss58_prefix: u16, pbkdf2_rounds: u32) -> [String; 4] {
+    let mini_secrets = crate::wallet::mnemonics_to_mini_secrets_batch_4(mnemonics, "", pbkdf2_rounds);
+    mini_secrets.map(|mini_secret| mini_secret_to_address(&mini_secret, ss58_prefix))
+}
+
+pub(crate) fn mini_secret_to_address(mini_secret: &[u8; 32], ss58_prefix: u16) -> String {
+    let pair = Pair::from_seed_slice(mini_secret).expect("Failed to create pair");
     let account_id = pair.public().into_account();
     account_id.to_ss58check_with_version(Ss58AddressFormat::custom(ss58_prefix))
 }
@@ -37,6 +71,17 @@ pub fn blake2_512(data: &[u8]) -> [u8; 64] {
 mod tests {
     use super::*;
     
+    #[test]
+    fn test_mnemonic_batch_to_addresses() {
+        let mnemonics: Vec<Mnemonic> = (0u8..6).map(|i| Mnemonic::from_entropy(&[i; 16]).unwrap()).collect();
+        let refs: Vec<&Mnemonic> = mnemonics.iter().collect();
+
+        let addresses = mnemonic_batch_to_addresses(&refs, 6094);
+        let expected: Vec<String> = mnemonics.iter().map(|mnemonic| mnemonic_to_address_with_prefix(mnemonic, 6094)).collect();
+        assert_eq!(addresses, expected);
+        assert!(mnemonic_batch_to_addresses(&[], 6094).is_empty());
+    }
+
     #[test]
     fn test_mnemonic_to_address_integration() {
         // Test with known mnemonic and Autonomys Network (6094)
@@ -15,6 +15,8 @@
 use sha2::{Sha512, Digest};
 use core::cmp;
 
+const HASH_LEN: usize = 64; // SHA512 output length
+
 /// PBKDF2 key derivation using HMAC-SHA512
 /// 
 /// Derives cryptographic keys from passwords using PBKDF2 as specified in RFC 2898,
@@ -22,8 +24,6 @@ use core::cmp;
 pub fn pbkdf2_hmac_sha512(password: &[u8], salt: &[u8], rounds: u32, res: &mut [u8]) {
     assert!(rounds > 0, "rounds must be greater than 0");
     
-    const HASH_LEN: usize = 64; // SHA512 output length
-    
     // Create HMAC state once and reuse (key optimization from RustCrypto)
     let prf = HmacSha512Core::new(password);
     
@@ -149,6 +149,214 @@ impl HmacSha512Core {
 }
 
 
+/// SHA-512 round constants (FIPS 180-4, section 4.2.3)
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+const K64: [u64; 80] = [
+    0x428a2f98d728ae22, 0x7137449123ef65cd, 0xb5c0fbcfec4d3b2f, 0xe9b5dba58189dbbc,
+    0x3956c25bf348b538, 0x59f111f1b605d019, 0x923f82a4af194f9b, 0xab1c5ed5da6d8118,
+    0xd807aa98a3030242, 0x12835b0145706fbe, 0x243185be4ee4b28c, 0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f, 0x80deb1fe3b1696b1, 0x9bdc06a725c71235, 0xc19bf174cf692694,
+    0xe49b69c19ef14ad2, 0xefbe4786384f25e3, 0x0fc19dc68b8cd5b5, 0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275, 0x4a7484aa6ea6e483, 0x5cb0a9dcbd41fbd4, 0x76f988da831153b5,
+    0x983e5152ee66dfab, 0xa831c66d2db43210, 0xb00327c898fb213f, 0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2, 0xd5a79147930aa725, 0x06ca6351e003826f, 0x142929670a0e6e70,
+    0x27b70a8546d22ffc, 0x2e1b21385c26c926, 0x4d2c6dfc5ac42aed, 0x53380d139d95b3df,
+    0x650a73548baf63de, 0x766a0abb3c77b2a8, 0x81c2c92e47edaee6, 0x92722c851482353b,
+    0xa2bfe8a14cf10364, 0xa81a664bbc423001, 0xc24b8b70d0f89791, 0xc76c51a30654be30,
+    0xd192e819d6ef5218, 0xd69906245565a910, 0xf40e35855771202a, 0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8, 0x1e376c085141ab53, 0x2748774cdf8eeb99, 0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63, 0x4ed8aa4ae3418acb, 0x5b9cca4f7763e373, 0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc, 0x78a5636f43172f60, 0x84c87814a1f0ab72, 0x8cc702081a6439ec,
+    0x90befffa23631e28, 0xa4506cebde82bde9, 0xbef9a3f7b2c67915, 0xc67178f2e372532b,
+    0xca273eceea26619c, 0xd186b8c721c0c207, 0xeada7dd6cde0eb1e, 0xf57d4f7fee6ed178,
+    0x06f067aa72176fba, 0x0a637dc5a2c898a6, 0x113f9804bef90dae, 0x1b710b35131c471b,
+    0x28db77f523047d84, 0x32caab7b40c72493, 0x3c9ebe0a15c9bebc, 0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6, 0x597f299cfc657e2a, 0x5fcb6fab3ad6faec, 0x6c44198c4a475817,
+];
+
+/// SHA-512 initial hash value (FIPS 180-4, section 5.3.5)
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+const H512: [u64; 8] = [
+    0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+    0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179,
+];
+
+/// Four-lane PBKDF2-HMAC-SHA512 using AVX2
+///
+/// Each 256-bit register holds the same SHA-512 word for four independent passwords,
+/// so one pass of the compression function advances all four derivations. The salt
+/// is shared across lanes, which matches BIP39 where it is always "mnemonic" plus the
+/// optional passphrase. Produces the first 32 bytes of output per lane, identical to
+/// calling `pbkdf2_hmac_sha512` four times.
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+pub fn pbkdf2_hmac_sha512_batch_4(passwords: [&[u8]; 4], salt: &[u8], rounds: u32, results: &mut [[u8; 32]; 4]) {
+    assert!(rounds > 0, "rounds must be greater than 0");
+
+    // SAFETY: this function is only compiled when AVX2 is enabled for the target
+    unsafe { pbkdf2_batch_4_avx2(passwords, salt, rounds, results) }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+#[target_feature(enable = "avx2")]
+fn pbkdf2_batch_4_avx2(passwords: [&[u8]; 4], salt: &[u8], rounds: u32, results: &mut [[u8; 32]; 4]) {
+    use core::arch::x86_64::*;
+
+    const BLOCK_SIZE: usize = 128; // SHA512 block size
+    const IPAD: u8 = 0x36;
+    const OPAD: u8 = 0x5c;
+
+    type Lanes = [__m256i; 8];
+
+    macro_rules! rotr {
+        ($x:expr, $n:literal) => {
+            _mm256_or_si256(_mm256_srli_epi64($x, $n), _mm256_slli_epi64($x, 64 - $n))
+        };
+    }
+
+    /// One SHA-512 compression across all four lanes
+    #[target_feature(enable = "avx2")]
+    fn compress4(state: &mut Lanes, block: &[__m256i; 16]) {
+        let mut w = [_mm256_setzero_si256(); 80];
+        w[..16].copy_from_slice(block);
+        for i in 16..80 {
+            let s0 = _mm256_xor_si256(
+                _mm256_xor_si256(rotr!(w[i - 15], 1), rotr!(w[i - 15], 8)),
+                _mm256_srli_epi64(w[i - 15], 7),
+            );
+            let s1 = _mm256_xor_si256(
+                _mm256_xor_si256(rotr!(w[i - 2], 19), rotr!(w[i - 2], 61)),
+                _mm256_srli_epi64(w[i - 2], 6),
+            );
+            w[i] = _mm256_add_epi64(_mm256_add_epi64(w[i - 16], s0), _mm256_add_epi64(w[i - 7], s1));
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+        for i in 0..80 {
+            let s1 = _mm256_xor_si256(_mm256_xor_si256(rotr!(e, 14), rotr!(e, 18)), rotr!(e, 41));
+            let ch = _mm256_xor_si256(_mm256_and_si256(e, f), _mm256_andnot_si256(e, g));
+            let k = _mm256_set1_epi64x(K64[i] as i64);
+            let temp1 = _mm256_add_epi64(
+                _mm256_add_epi64(_mm256_add_epi64(h, s1), _mm256_add_epi64(ch, k)),
+                w[i],
+            );
+            let s0 = _mm256_xor_si256(_mm256_xor_si256(rotr!(a, 28), rotr!(a, 34)), rotr!(a, 39));
+            let maj = _mm256_xor_si256(
+                _mm256_xor_si256(_mm256_and_si256(a, b), _mm256_and_si256(a, c)),
+                _mm256_and_si256(b, c),
+            );
+            let temp2 = _mm256_add_epi64(s0, maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = _mm256_add_epi64(d, temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = _mm256_add_epi64(temp1, temp2);
+        }
+
+        for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *s = _mm256_add_epi64(*s, v);
+        }
+    }
+
+    /// Load the sixteen big-endian words of a 128-byte block from each lane
+    #[target_feature(enable = "avx2")]
+    fn load_words(blocks: &[[u8; BLOCK_SIZE]; 4]) -> [__m256i; 16] {
+        let mut words = [_mm256_setzero_si256(); 16];
+        for (i, word) in words.iter_mut().enumerate() {
+            let mut lanes = [0i64; 4];
+            for (l, lane) in lanes.iter_mut().enumerate() {
+                *lane = u64::from_be_bytes(blocks[l][i * 8..i * 8 + 8].try_into().unwrap()) as i64;
+            }
+            *word = _mm256_set_epi64x(lanes[3], lanes[2], lanes[1], lanes[0]);
+        }
+        words
+    }
+
+    /// Pad a 64-byte value following one 128-byte key block (HMAC inner/outer message)
+    #[target_feature(enable = "avx2")]
+    fn pad64(x: &Lanes) -> [__m256i; 16] {
+        let mut block = [_mm256_setzero_si256(); 16];
+        block[..8].copy_from_slice(x);
+        block[8] = _mm256_set1_epi64x(i64::MIN); // 0x80 terminator
+        block[15] = _mm256_set1_epi64x(((BLOCK_SIZE + 64) * 8) as i64);
+        block
+    }
+
+    // Pre-compute the IPAD and OPAD states for every lane
+    let mut ipad_blocks = [[0u8; BLOCK_SIZE]; 4];
+    let mut opad_blocks = [[0u8; BLOCK_SIZE]; 4];
+    for (lane, key) in passwords.iter().enumerate() {
+        let mut key_block = [0u8; BLOCK_SIZE];
+        if key.len() <= BLOCK_SIZE {
+            key_block[..key.len()].copy_from_slice(key);
+        } else {
+            // Hash long keys
+            let hash = Sha512::digest(key);
+            key_block[..hash.len()].copy_from_slice(&hash);
+        }
+        for i in 0..BLOCK_SIZE {
+            ipad_blocks[lane][i] = key_block[i] ^ IPAD;
+            opad_blocks[lane][i] = key_block[i] ^ OPAD;
+        }
+    }
+
+    let mut initial = [_mm256_setzero_si256(); 8];
+    for (lane, h) in initial.iter_mut().zip(H512) {
+        *lane = _mm256_set1_epi64x(h as i64);
+    }
+    let mut inner_state = initial;
+    compress4(&mut inner_state, &load_words(&ipad_blocks));
+    let mut outer_state = initial;
+    compress4(&mut outer_state, &load_words(&opad_blocks));
+
+    // First iteration: U_1 = PRF(password, salt || 1), message shared by all lanes
+    let mut message = Vec::with_capacity(salt.len() + 4 + 1 + BLOCK_SIZE + 16);
+    message.extend_from_slice(salt);
+    message.extend_from_slice(&1u32.to_be_bytes());
+    let bit_len = ((BLOCK_SIZE + message.len()) * 8) as u128;
+    message.push(0x80);
+    while message.len() % BLOCK_SIZE != BLOCK_SIZE - 16 {
+        message.push(0);
+    }
+    message.extend_from_slice(&bit_len.to_be_bytes());
+
+    let mut inner = inner_state;
+    for chunk in message.chunks_exact(BLOCK_SIZE) {
+        let block: [u8; BLOCK_SIZE] = chunk.try_into().unwrap();
+        compress4(&mut inner, &load_words(&[block; 4]));
+    }
+    let mut u = outer_state;
+    compress4(&mut u, &pad64(&inner));
+    let mut t = u;
+
+    // Subsequent iterations: U_j = PRF(password, U_{j-1})
+    for _ in 1..rounds {
+        let mut inner = inner_state;
+        compress4(&mut inner, &pad64(&u));
+        u = outer_state;
+        compress4(&mut u, &pad64(&inner));
+        for (acc, v) in t.iter_mut().zip(u.iter()) {
+            *acc = _mm256_xor_si256(*acc, *v);
+        }
+    }
+
+    // Only the first four words (32 bytes) of T_1 are needed per lane
+    for (i, word) in t.iter().take(4).enumerate() {
+        let lanes = [
+            _mm256_extract_epi64(*word, 0),
+            _mm256_extract_epi64(*word, 1),
+            _mm256_extract_epi64(*word, 2),
+            _mm256_extract_epi64(*word, 3),
+        ];
+        for (result, lane) in results.iter_mut().zip(lanes) {
+            result[i * 8..i * 8 + 8].copy_from_slice(&(lane as u64).to_be_bytes());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,35 +386,56 @@ mod tests {
     fn test_optimized_performance() {
         let password = b"test_password_for_performance";
         let salt = b"mnemonic";
-        let iterations = 2048; // BIP39 standard
         let test_runs = 10;
         
-        // Test our optimized implementation
-        let mut our_times = Vec::new();
-        let mut our_output = [0u8; 32];
-        
-        // Warm up
-        for _ in 0..3 {
-            pbkdf2_hmac_sha512(password, salt, iterations, &mut our_output);
+        // BIP39 standard plus stretched round counts selectable via --pbkdf2-rounds
+        for iterations in [2048, 4096, 16384] {
+            let mut our_times = Vec::new();
+            let mut our_output = [0u8; 32];
+            
+            // Warm up
+            for _ in 0..3 {
+                pbkdf2_hmac_sha512(password, salt, iterations, &mut our_output);
+            }
+            
+            // Benchmark
+            for _ in 0..test_runs {
+                let start = Instant::now();
+                pbkdf2_hmac_sha512(password, salt, iterations, &mut our_output);
+                our_times.push(start.elapsed());
+            }
+            
+            let our_avg = our_times.iter().sum::<std::time::Duration>() / our_times.len() as u32;
+            let our_keys_per_sec = 1.0 / our_avg.as_secs_f64();
+            
+            println!("PBKDF2 performance test ({} rounds):", iterations);
+            println!("  Average time: {:?}", our_avg);
+            println!("  Keys per second: {:.1}", our_keys_per_sec);
+            
+            // Basic performance validation: at least 1 key/s at the BIP39 standard, with
+            // the budget scaled by the round count
+            let budget_ms = 1000 * u128::from(iterations / 2048);
+            let min_keys_per_sec = 2048.0 / f64::from(iterations);
+            assert!(our_avg.as_millis() < budget_ms, "PBKDF2 execution took too long at {} rounds: {:?}", iterations, our_avg);
+            assert!(our_keys_per_sec > min_keys_per_sec, "Performance too low at {} rounds: {:.1} keys/s", iterations, our_keys_per_sec);
         }
+    }
+    
+    #[test]
+    #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+    fn test_batch_4_matches_scalar() {
+        let passwords: [&[u8]; 4] = [b"password", &[0x12; 16], &[0xab; 32], &[0x5c; 200]];
         
-        // Benchmark
-        for _ in 0..test_runs {
-            let start = Instant::now();
-            pbkdf2_hmac_sha512(password, salt, iterations, &mut our_output);
-            our_times.push(start.elapsed());
+        for salt in [&b"mnemonic"[..], &b"mnemonicTREZOR"[..], &[0x36; 300][..]] {
+            let mut batch = [[0u8; 32]; 4];
+            pbkdf2_hmac_sha512_batch_4(passwords, salt, 2048, &mut batch);
+            
+            for (password, lane) in passwords.iter().zip(batch.iter()) {
+                let mut scalar = [0u8; 32];
+                pbkdf2_hmac_sha512(password, salt, 2048, &mut scalar);
+                assert_eq!(*lane, scalar, "AVX2 lane differs from scalar PBKDF2");
+            }
         }
-        
-        let our_avg = our_times.iter().sum::<std::time::Duration>() / our_times.len() as u32;
-        let our_keys_per_sec = 1.0 / our_avg.as_secs_f64();
-        
-        println!("PBKDF2 performance test:");
-        println!("  Average time: {:?}", our_avg);
-        println!("  Keys per second: {:.1}", our_keys_per_sec);
-        
-        // Basic performance validation (execution completes in reasonable time)
-        assert!(our_avg.as_millis() < 1000, "PBKDF2 execution took too long: {:?}", our_avg);
-        assert!(our_keys_per_sec > 1.0, "Performance too low: {:.1} keys/s", our_keys_per_sec);
     }
     
     #[test]
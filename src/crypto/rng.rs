@@ -20,6 +20,16 @@
 
 #[cfg(unix)]
 use std::io::Read;
+use zeroize::Zeroize;
+
+/// Nonce for the generators that produce wallet secrets
+pub const WALLET_NONCE: [u8; 8] = *b"autoseed";
+
+/// Nonce for generators exercised by the entropy tests
+pub const ENTROPY_TEST_NONCE: [u8; 8] = *b"entropy0";
+
+/// Nonce for the random polynomial coefficients of Shamir secret sharing
+pub const SHAMIR_NONCE: [u8; 8] = *b"shamir00";
 
 /// Error types for RNG operations
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -63,8 +73,16 @@ impl ChaCha20Rng {
     /// ChaCha20 constants: "expand 32-byte k" in little-endian
     const CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
     
-    /// Create a new ChaCha20Rng from a 256-bit seed
+    /// Create a new ChaCha20Rng from a 256-bit seed with an all-zero nonce
     pub fn from_seed(seed: [u8; 32]) -> Self {
+        Self::from_seed_and_nonce(seed, [0; 8])
+    }
+    
+    /// Create a new ChaCha20Rng from a 256-bit seed and a 64-bit nonce
+    ///
+    /// The nonce separates domains: the same seed under a different nonce gives an
+    /// unrelated stream.
+    pub fn from_seed_and_nonce(seed: [u8; 32], nonce: [u8; 8]) -> Self {
         let mut state = [0u32; 16];
         
         // Set ChaCha20 constants
@@ -75,11 +93,11 @@ impl ChaCha20Rng {
             state[4 + i] = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
         }
         
-        // Initialize counter and nonce to zero
+        // Initialize counter to zero and set the nonce
         state[12] = 0; // counter (low 32 bits)
         state[13] = 0; // counter (high 32 bits) 
-        state[14] = 0; // nonce (low 32 bits)
-        state[15] = 0; // nonce (high 32 bits)
+        state[14] = u32::from_le_bytes([nonce[0], nonce[1], nonce[2], nonce[3]]); // nonce (low 32 bits)
+        state[15] = u32::from_le_bytes([nonce[4], nonce[5], nonce[6], nonce[7]]); // nonce (high 32 bits)
         
         Self {
             state,
@@ -89,10 +107,36 @@ impl ChaCha20Rng {
         }
     }
     
-    /// Create a new ChaCha20Rng using system entropy
+    /// Create a new ChaCha20Rng using system entropy mixed with process-local sources
     pub fn from_system_entropy() -> Result<Self, RngError> {
-        let seed = collect_system_entropy()?;
-        Ok(Self::from_seed(seed))
+        Self::from_system_entropy_with_nonce([0; 8])
+    }
+    
+    /// [`from_system_entropy`](Self::from_system_entropy) with a domain-separation nonce
+    pub fn from_system_entropy_with_nonce(nonce: [u8; 8]) -> Result<Self, RngError> {
+        let seed = collect_mixed_entropy()?;
+        Ok(Self::from_seed_and_nonce(seed, nonce))
+    }
+    
+    /// This generator's 64-bit nonce
+    fn nonce(&self) -> [u8; 8] {
+        let mut nonce = [0u8; 8];
+        nonce[..4].copy_from_slice(&self.state[14].to_le_bytes());
+        nonce[4..].copy_from_slice(&self.state[15].to_le_bytes());
+        nonce
+    }
+    
+    /// Derive an independent child generator, keyed from this generator's next 32 bytes
+    /// and sharing its nonce
+    ///
+    /// Splitting a seeded master gives each worker its own stream while the whole set
+    /// stays reproducible from the master seed.
+    pub fn split(&mut self) -> Self {
+        let mut seed = [0u8; 32];
+        self.fill_bytes(&mut seed);
+        let child = Self::from_seed_and_nonce(seed, self.nonce());
+        seed.zeroize();
+        child
     }
     
     
@@ -117,12 +161,12 @@ impl ChaCha20Rng {
         state[bi] = state[bi].rotate_left(7);
     }
     
-    /// Generate a 64-byte block of random data using ChaCha20
-    fn generate_block(&mut self) {
+    /// Generate a 64-byte block of random data using ChaCha with `DOUBLE_ROUNDS` × 2 rounds
+    fn generate_block<const DOUBLE_ROUNDS: usize>(&mut self) {
         let mut working_state = self.state;
         
-        // Perform 20 rounds (10 double rounds) of ChaCha20
-        for _ in 0..10 {
+        // ChaCha20 performs 20 rounds (10 double rounds)
+        for _ in 0..DOUBLE_ROUNDS {
             // Column rounds
             Self::quarter_round_indexed(&mut working_state, 0, 4, 8, 12);
             Self::quarter_round_indexed(&mut working_state, 1, 5, 9, 13);
@@ -159,11 +203,15 @@ impl ChaCha20Rng {
     
     /// Fill a buffer with random bytes
     pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.fill_bytes_with_rounds::<10>(dest);
+    }
+
+    fn fill_bytes_with_rounds<const DOUBLE_ROUNDS: usize>(&mut self, dest: &mut [u8]) {
         let mut offset = 0;
         
         while offset < dest.len() {
             if self.buffer_pos >= 64 {
-                self.generate_block();
+                self.generate_block::<DOUBLE_ROUNDS>();
             }
             
             let available = 64 - self.buffer_pos;
@@ -178,7 +226,72 @@ impl ChaCha20Rng {
             offset += to_copy;
         }
     }
-    
+
+    /// Next 8 bytes of the stream as a little-endian `u64`
+    pub fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Uniform value in `[0, upper)`, or 0 when `upper` is 0
+    ///
+    /// Uses rejection sampling: draws below `2^64 mod upper` are discarded, so every
+    /// value is equally likely instead of the low ones being favoured by a plain modulo.
+    pub fn gen_range(&mut self, upper: usize) -> usize {
+        if upper <= 1 {
+            return 0;
+        }
+        let range = upper as u64;
+        let threshold = range.wrapping_neg() % range;
+        loop {
+            let value = self.next_u64();
+            if value >= threshold {
+                return (value % range) as usize;
+            }
+        }
+    }
+
+    /// `N` random bytes
+    pub fn gen_array<const N: usize>(&mut self) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        self.fill_bytes(&mut bytes);
+        bytes
+    }
+
+    /// `true` with the given probability; 0 or less is never, 1 or more always
+    #[allow(clippy::cast_precision_loss)]
+    pub fn gen_bool(&mut self, probability: f64) -> bool {
+        if probability >= 1.0 {
+            return true;
+        }
+        (self.next_u64() as f64) < probability * u64::MAX as f64
+    }
+}
+
+/// ChaCha with 12 rounds: faster, for sampling where only statistical quality matters
+///
+/// **Not for key generation.** Twelve rounds keep a comfortable margin against known
+/// attacks, but every secret this crate produces comes from [`ChaCha20Rng`]. Use this
+/// for test harnesses and Monte Carlo sampling only.
+#[derive(Clone)]
+pub struct ChaCha12Rng(ChaCha20Rng);
+
+impl ChaCha12Rng {
+    /// Create a new ChaCha12Rng from a 256-bit seed
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        Self(ChaCha20Rng::from_seed(seed))
+    }
+
+    /// Create a new ChaCha12Rng seeded like [`ChaCha20Rng::from_system_entropy`]
+    pub fn from_system_entropy() -> Result<Self, RngError> {
+        Ok(Self::from_seed(collect_mixed_entropy()?))
+    }
+
+    /// Fill a buffer with random bytes
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes_with_rounds::<6>(dest);
+    }
 }
 
 /// Cross-platform system entropy collection
@@ -198,7 +311,12 @@ fn collect_system_entropy() -> Result<[u8; 32], RngError> {
         collect_windows_entropy(&mut seed)?;
     }
     
-    #[cfg(not(any(unix, windows)))]
+    #[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+    {
+        collect_wasm_entropy(&mut seed)?;
+    }
+    
+    #[cfg(not(any(unix, windows, all(target_arch = "wasm32", feature = "wasm"))))]
     {
         return Err(RngError::EntropyCollection);
     }
@@ -206,6 +324,45 @@ fn collect_system_entropy() -> Result<[u8; 32], RngError> {
     Ok(seed)
 }
 
+/// OS entropy hashed together with process-local sources
+///
+/// The 32 OS bytes go through BLAKE2b-256 along with the timestamp in nanoseconds,
+/// the process ID, a stack address (ASLR) and the thread ID. The extra sources are
+/// weak on their own, but a seed now stays unpredictable unless every one of them
+/// is known. Failing to read OS entropy is still an error; the extras never stand
+/// in for it. wasm32 has no clock, PID or thread ID to add.
+fn collect_mixed_entropy() -> Result<[u8; 32], RngError> {
+    use crate::crypto::blake2b::Blake2b512;
+
+    let mut os_entropy = collect_system_entropy()?;
+    let mut hasher = Blake2b512::with_digest_length(32);
+    hasher.update(&os_entropy);
+    os_entropy.fill(0);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use std::hash::{Hash, Hasher};
+
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos());
+        hasher.update(&nanos.to_le_bytes());
+        hasher.update(&std::process::id().to_le_bytes());
+        let stack_marker = 0u8;
+        hasher.update(&(std::ptr::addr_of!(stack_marker) as usize).to_le_bytes());
+        // ThreadId has no stable integer form; its hash is unique per thread
+        let mut thread_id = std::collections::hash_map::DefaultHasher::new();
+        std::thread::current().id().hash(&mut thread_id);
+        hasher.update(&thread_id.finish().to_le_bytes());
+    }
+
+    let mut digest = hasher.finalize();
+    let mut seed = [0u8; 32];
+    seed.copy_from_slice(&digest[..32]);
+    digest.fill(0);
+    Ok(seed)
+}
+
 /// Collect entropy on Unix-like systems (Linux, macOS, BSD)
 #[cfg(unix)]
 fn collect_unix_entropy(seed: &mut [u8; 32]) -> Result<(), RngError> {
@@ -250,10 +407,28 @@ fn collect_windows_entropy(seed: &mut [u8; 32]) -> Result<(), RngError> {
     Ok(())
 }
 
+/// Collect entropy in the browser through the Web Crypto API
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+fn collect_wasm_entropy(seed: &mut [u8; 32]) -> Result<(), RngError> {
+    let crypto = web_sys::window()
+        .ok_or(RngError::EntropyCollection)?
+        .crypto()
+        .map_err(|_| RngError::EntropyCollection)?;
+    crypto
+        .get_random_values_with_u8_array(seed)
+        .map_err(|_| RngError::EntropyCollection)?;
+    Ok(())
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    /// A fresh system-seeded generator as a `u64` source
+    fn thread_rng() -> impl FnMut() -> u64 {
+        let mut rng = ChaCha20Rng::from_system_entropy_with_nonce(ENTROPY_TEST_NONCE).unwrap();
+        move || rng.next_u64()
+    }
     
     #[test]
     fn test_chacha20_deterministic() {
@@ -308,11 +483,109 @@ mod tests {
         assert_eq!(rng.gen_range(0), 0);
         assert_eq!(rng.gen_range(1), 0);
     }
+
+    #[test]
+    fn test_gen_range_is_uniform() {
+        const BUCKETS: usize = 10;
+        const SAMPLES: usize = 100_000;
+        // Chi-squared critical value for 9 degrees of freedom at p = 0.001
+        const CRITICAL: f64 = 27.877;
+
+        let mut rng = ChaCha20Rng::from_seed([0x42u8; 32]);
+        let mut counts = [0usize; BUCKETS];
+        for _ in 0..SAMPLES {
+            counts[rng.gen_range(BUCKETS)] += 1;
+        }
+
+        let expected = SAMPLES as f64 / BUCKETS as f64;
+        let chi_squared: f64 = counts.iter().map(|&count| (count as f64 - expected).powi(2) / expected).sum();
+        assert!(chi_squared < CRITICAL, "chi-squared {chi_squared:.2} for counts {counts:?}");
+
+        // The full width works too
+        assert!(rng.gen_range(usize::MAX) < usize::MAX);
+    }
     
+    #[test]
+    fn test_chacha12_deterministic() {
+        let seed = [7u8; 32];
+        let (mut a, mut b) = (ChaCha12Rng::from_seed(seed), ChaCha12Rng::from_seed(seed));
+        let (mut out_a, mut out_b) = ([0u8; 100], [0u8; 100]);
+        a.fill_bytes(&mut out_a);
+        b.fill_bytes(&mut out_b);
+        assert_eq!(out_a, out_b);
+
+        // Same key and counter, different round count: a different stream
+        let mut out_20 = [0u8; 100];
+        ChaCha20Rng::from_seed(seed).fill_bytes(&mut out_20);
+        assert_ne!(out_a, out_20);
+    }
+
+    #[test]
+    fn test_chacha12_known_answer() {
+        // ChaCha12 keystream for an all-zero key and nonce, blocks 0 and 1
+        let mut out = [0u8; 128];
+        ChaCha12Rng::from_seed([0u8; 32]).fill_bytes(&mut out);
+        assert_eq!(
+            hex::encode(&out[..64]),
+            "9bf49a6a0755f953811fce125f2683d50429c3bb49e074147e0089a52eae155f\
+             0564f879d27ae3c02ce82834acfa8c793a629f2ca0de6919610be82f411326be"
+        );
+        assert_eq!(
+            hex::encode(&out[64..]),
+            "0bd58841203e74fe86fc71338ce0173dc628ebb719bdcbcc151585214cc089b4\
+             42258dcda14cf111c602b8971b8cc843e91e46ca905151c02744a6b017e69316"
+        );
+    }
+
+    #[test]
+    fn test_nonce_separates_streams() {
+        let seed = [5u8; 32];
+        let mut zero_nonce = ChaCha20Rng::from_seed(seed);
+        let mut wallet = ChaCha20Rng::from_seed_and_nonce(seed, WALLET_NONCE);
+        let mut shamir = ChaCha20Rng::from_seed_and_nonce(seed, SHAMIR_NONCE);
+
+        let (mut out_zero, mut out_wallet, mut out_shamir) = ([0u8; 64], [0u8; 64], [0u8; 64]);
+        zero_nonce.fill_bytes(&mut out_zero);
+        wallet.fill_bytes(&mut out_wallet);
+        shamir.fill_bytes(&mut out_shamir);
+        assert_ne!(out_zero, out_wallet);
+        assert_ne!(out_wallet, out_shamir);
+
+        // An all-zero nonce is the plain seeded stream
+        let mut explicit_zero = [0u8; 64];
+        ChaCha20Rng::from_seed_and_nonce(seed, [0; 8]).fill_bytes(&mut explicit_zero);
+        assert_eq!(out_zero, explicit_zero);
+
+        // Children keep their parent's domain
+        assert_eq!(wallet.split().nonce(), WALLET_NONCE);
+    }
+
+    #[test]
+    fn test_split_is_reproducible() {
+        let (mut master_a, mut master_b) = (ChaCha20Rng::from_seed([9u8; 32]), ChaCha20Rng::from_seed([9u8; 32]));
+        let (mut first, mut second) = (master_a.split(), master_a.split());
+        let mut first_again = master_b.split();
+
+        let (mut out_first, mut out_second, mut out_again) = ([0u8; 64], [0u8; 64], [0u8; 64]);
+        first.fill_bytes(&mut out_first);
+        second.fill_bytes(&mut out_second);
+        first_again.fill_bytes(&mut out_again);
+        assert_eq!(out_first, out_again);
+        assert_ne!(out_first, out_second);
+    }
+
+    #[test]
+    fn test_mixed_entropy_differs_between_calls() {
+        let first = collect_mixed_entropy().unwrap();
+        let second = collect_mixed_entropy().unwrap();
+        assert_ne!(first, second);
+        assert_ne!(first, [0u8; 32]);
+    }
+
     #[test]
     fn test_system_entropy() {
         // Test that system entropy collection works
-        match ChaCha20Rng::from_system_entropy() {
+        match ChaCha20Rng::from_system_entropy_with_nonce(ENTROPY_TEST_NONCE) {
             Ok(mut rng) => {
                 let val1 = rng.next_u64();
                 let val2 = rng.next_u64();
@@ -349,6 +622,18 @@ mod tests {
         assert_ne!(arr1, [0u8; 16]);
     }
     
+    #[test]
+    fn test_gen_bool() {
+        let mut rng = ChaCha20Rng::from_seed([0x42u8; 32]);
+        assert!((0..1000).all(|_| !rng.gen_bool(0.0)));
+        assert!((0..1000).all(|_| rng.gen_bool(1.0)));
+        assert!(!rng.gen_bool(-1.0));
+        assert!(rng.gen_bool(2.0));
+
+        let hits = (0..100_000).filter(|_| rng.gen_bool(0.25)).count();
+        assert!((24_000..26_000).contains(&hits), "{hits} hits for p = 0.25");
+    }
+
     #[test]
     fn test_large_buffer() {
         let mut rng = ChaCha20Rng::from_seed([0x42u8; 32]);
@@ -0,0 +1,212 @@
+//! Shamir's Secret Sharing over GF(2^8)
+//!
+//! This module splits a 32-byte secret into N shares such that any K of them
+//! reconstruct the secret while fewer than K reveal nothing about it. Each byte of
+//! the secret is the constant term of an independent random polynomial of degree
+//! K-1 over GF(2^8), and share i holds the evaluation of every polynomial at x = i.
+//! Recovery uses Lagrange interpolation at x = 0.
+//!
+//! Field arithmetic uses the AES reduction polynomial x^8 + x^4 + x^3 + x + 1 (0x11b)
+//! and is implemented without lookup tables so multiplication runs in constant time.
+//!
+//! A share is 33 bytes: the x coordinate followed by the 32 y values. Shares encode
+//! to exactly 24 words of the BIP39 English wordlist (264 bits, no checksum), so a
+//! share phrase looks like a 24-word mnemonic but is not a valid wallet mnemonic.
+//!
+//! ## References
+//! - Adi Shamir, "How to Share a Secret", Communications of the ACM, 1979
+
+use crate::crypto::bip39::ENGLISH_WORDS;
+use crate::crypto::rng::{ChaCha20Rng, SHAMIR_NONCE};
+use zeroize::Zeroize;
+
+/// Share length: one x coordinate byte plus 32 bytes of y values
+pub const SHARE_LEN: usize = 33;
+
+/// Number of wordlist words needed to encode one share (33 bytes * 8 / 11 bits)
+pub const SHARE_WORDS: usize = 24;
+
+/// Multiply two field elements in GF(2^8)
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    for _ in 0..8 {
+        // Branch-free conditional add and reduction
+        product ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = 0u8.wrapping_sub(a >> 7);
+        a = (a << 1) ^ (0x1b & carry);
+        b >>= 1;
+    }
+    product
+}
+
+/// Multiplicative inverse in GF(2^8) via a^254 (zero maps to zero)
+fn gf_inv(a: u8) -> u8 {
+    let mut result = 1u8;
+    let mut base = a;
+    let mut exponent = 254u8;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = gf_mul(result, base);
+        }
+        base = gf_mul(base, base);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Split a 32-byte secret into `n` shares, any `k` of which recover it
+///
+/// Panics if `k` is zero or greater than `n`.
+pub fn split_secret(secret: &[u8; 32], k: u8, n: u8) -> Vec<[u8; SHARE_LEN]> {
+    assert!(k >= 1 && k <= n, "threshold must satisfy 1 <= k <= n");
+
+    let mut rng = ChaCha20Rng::from_system_entropy_with_nonce(SHAMIR_NONCE).expect("Failed to initialize RNG from system entropy");
+
+    // coefficients[byte][degree], degree 0 is the secret byte
+    let mut coefficients = vec![[0u8; 256]; 32];
+    for (byte, poly) in coefficients.iter_mut().enumerate() {
+        poly[0] = secret[byte];
+        rng.fill_bytes(&mut poly[1..k as usize]);
+    }
+
+    let shares = (1..=n)
+        .map(|x| {
+            let mut share = [0u8; SHARE_LEN];
+            share[0] = x;
+            for (byte, poly) in coefficients.iter().enumerate() {
+                // Horner's method from the highest degree down
+                let mut y = 0u8;
+                for &coefficient in poly[..k as usize].iter().rev() {
+                    y = gf_mul(y, x) ^ coefficient;
+                }
+                share[byte + 1] = y;
+            }
+            share
+        })
+        .collect();
+
+    for poly in &mut coefficients {
+        poly.zeroize();
+    }
+
+    shares
+}
+
+/// Recover the secret from at least `k` distinct shares
+///
+/// Returns `None` for an empty share set, a zero x coordinate or duplicate shares.
+/// Supplying fewer than `k` shares yields an unrelated value rather than an error,
+/// since the threshold is not recorded in the shares.
+pub fn combine_shares(shares: &[[u8; SHARE_LEN]]) -> Option<[u8; 32]> {
+    if shares.is_empty() {
+        return None;
+    }
+
+    for (i, share) in shares.iter().enumerate() {
+        if share[0] == 0 || shares[..i].iter().any(|other| other[0] == share[0]) {
+            return None;
+        }
+    }
+
+    let mut secret = [0u8; 32];
+    for (i, share) in shares.iter().enumerate() {
+        // Lagrange basis polynomial for share i evaluated at x = 0
+        let mut numerator = 1u8;
+        let mut denominator = 1u8;
+        for (j, other) in shares.iter().enumerate() {
+            if i != j {
+                numerator = gf_mul(numerator, other[0]);
+                denominator = gf_mul(denominator, share[0] ^ other[0]);
+            }
+        }
+        let basis = gf_mul(numerator, gf_inv(denominator));
+
+        for (byte, value) in secret.iter_mut().enumerate() {
+            *value ^= gf_mul(share[byte + 1], basis);
+        }
+    }
+
+    Some(secret)
+}
+
+/// Encode a share as 24 BIP39 English words (11 bits per word, no checksum)
+pub fn share_to_phrase(share: &[u8; SHARE_LEN]) -> String {
+    let mut words = Vec::with_capacity(SHARE_WORDS);
+    for i in 0..SHARE_WORDS {
+        let mut index = 0usize;
+        for bit in i * 11..i * 11 + 11 {
+            index = (index << 1) | usize::from((share[bit / 8] >> (7 - bit % 8)) & 1);
+        }
+        words.push(ENGLISH_WORDS[index]);
+    }
+    words.join(" ")
+}
+
+/// Decode a share phrase produced by `share_to_phrase`
+pub fn phrase_to_share(phrase: &str) -> Option<[u8; SHARE_LEN]> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    if words.len() != SHARE_WORDS {
+        return None;
+    }
+
+    let mut share = [0u8; SHARE_LEN];
+    for (i, word) in words.iter().enumerate() {
+        let index = ENGLISH_WORDS.iter().position(|w| w == word)?;
+        for b in 0..11 {
+            if (index >> (10 - b)) & 1 == 1 {
+                let bit = i * 11 + b;
+                share[bit / 8] |= 1 << (7 - bit % 8);
+            }
+        }
+    }
+    Some(share)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gf_inverse() {
+        for a in 1..=255u8 {
+            assert_eq!(gf_mul(a, gf_inv(a)), 1, "inverse failed for {a}");
+        }
+    }
+
+    #[test]
+    fn test_split_combine_round_trip() {
+        let secret: [u8; 32] = core::array::from_fn(|i| i as u8 * 7 + 3);
+        let shares = split_secret(&secret, 3, 5);
+        assert_eq!(shares.len(), 5);
+
+        // Any 3 of the 5 shares recover the secret
+        for a in 0..5 {
+            for b in a + 1..5 {
+                for c in b + 1..5 {
+                    let subset = [shares[a], shares[b], shares[c]];
+                    assert_eq!(combine_shares(&subset), Some(secret));
+                }
+            }
+        }
+
+        // Two shares are below the threshold
+        assert_ne!(combine_shares(&shares[..2]), Some(secret));
+    }
+
+    #[test]
+    fn test_combine_rejects_invalid_sets() {
+        let shares = split_secret(&[0x42; 32], 2, 3);
+        assert_eq!(combine_shares(&[]), None);
+        assert_eq!(combine_shares(&[shares[0], shares[0]]), None);
+    }
+
+    #[test]
+    fn test_share_phrase_round_trip() {
+        let shares = split_secret(&[0xa5; 32], 2, 3);
+        for share in &shares {
+            let phrase = share_to_phrase(share);
+            assert_eq!(phrase.split_whitespace().count(), SHARE_WORDS);
+            assert_eq!(phrase_to_share(&phrase), Some(*share));
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! C bindings for embedding autoseed in non-Rust toolchains (`ffi` feature)
+//!
+//! The build script generates `autoseed.h` for these functions with cbindgen.
+//!
+//! ## Ownership
+//! - Buffers passed in (`seed_out`, `address_out`) are owned by the caller; the
+//!   library only writes into them.
+//! - Strings passed in (`pattern`, `address`) are borrowed for the duration of the
+//!   call and must be NUL-terminated UTF-8.
+//! - Strings returned by the library are owned by the caller and must be released
+//!   with [`autoseed_free_string`], never with `free()`.
+
+use std::ffi::{c_char, c_int, CStr, CString};
+
+/// Length of a raw seed in bytes
+pub const AUTOSEED_SEED_LEN: usize = 32;
+/// Minimum size of `address_out`, including the NUL terminator
+pub const AUTOSEED_ADDRESS_BUF_LEN: usize = 64;
+
+/// Success
+pub const AUTOSEED_OK: c_int = 0;
+/// A required pointer argument was null
+pub const AUTOSEED_ERR_NULL: c_int = -1;
+/// A string argument was not valid UTF-8
+pub const AUTOSEED_ERR_UTF8: c_int = -2;
+
+/// Generate a random seed and its SS58 address
+///
+/// Writes 32 seed bytes to `seed_out` and the NUL-terminated address to
+/// `address_out`. Returns `AUTOSEED_OK` or `AUTOSEED_ERR_NULL`.
+///
+/// # Safety
+/// `seed_out` must point to `AUTOSEED_SEED_LEN` writable bytes and `address_out`
+/// to `AUTOSEED_ADDRESS_BUF_LEN` writable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn autoseed_generate_hex(seed_out: *mut u8, address_out: *mut c_char, ss58_prefix: u16) -> c_int {
+    if seed_out.is_null() || address_out.is_null() {
+        return AUTOSEED_ERR_NULL;
+    }
+
+    let seed = crate::wallet::generate_hex_seed();
+    let address = crate::wallet::hex_to_address_with_prefix(&seed, ss58_prefix);
+    let address = address.as_bytes();
+
+    // SAFETY: the caller guarantees both buffers are large enough; SS58 addresses
+    // are far shorter than AUTOSEED_ADDRESS_BUF_LEN
+    unsafe {
+        std::ptr::copy_nonoverlapping(seed.as_ptr(), seed_out, AUTOSEED_SEED_LEN);
+        std::ptr::copy_nonoverlapping(address.as_ptr(), address_out.cast::<u8>(), address.len());
+        *address_out.add(address.len()) = 0;
+    }
+
+    AUTOSEED_OK
+}
+
+/// Derive the SS58 address for a 32-byte seed
+///
+/// Returns a newly allocated string the caller must release with
+/// [`autoseed_free_string`], or null if `seed` is null.
+///
+/// # Safety
+/// `seed` must point to `AUTOSEED_SEED_LEN` readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn autoseed_address_from_seed(seed: *const u8, ss58_prefix: u16) -> *mut c_char {
+    if seed.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    // SAFETY: the caller guarantees `seed` points to AUTOSEED_SEED_LEN bytes
+    let seed = unsafe { &*seed.cast::<[u8; AUTOSEED_SEED_LEN]>() };
+    let address = crate::wallet::hex_to_address_with_prefix(seed, ss58_prefix);
+
+    // Base58 never contains a NUL byte
+    CString::new(address).map_or(std::ptr::null_mut(), CString::into_raw)
+}
+
+/// Check whether `pattern` appears anywhere in `address` (`?` matches any character)
+///
+/// Returns 1 on a match, 0 otherwise, or `AUTOSEED_ERR_NULL` / `AUTOSEED_ERR_UTF8`
+/// for bad arguments. `case_sensitive` is treated as a C boolean.
+///
+/// # Safety
+/// `pattern` and `address` must be valid NUL-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn autoseed_search(pattern: *const c_char, address: *const c_char, case_sensitive: c_int) -> c_int {
+    if pattern.is_null() || address.is_null() {
+        return AUTOSEED_ERR_NULL;
+    }
+
+    // SAFETY: the caller guarantees both strings are NUL-terminated
+    let (pattern, address) = unsafe { (CStr::from_ptr(pattern), CStr::from_ptr(address)) };
+    let (Ok(pattern), Ok(address)) = (pattern.to_str(), address.to_str()) else {
+        return AUTOSEED_ERR_UTF8;
+    };
+
+    c_int::from(crate::search::contains_pattern(address, pattern, case_sensitive != 0))
+}
+
+/// Release a string returned by this library; null is ignored
+///
+/// # Safety
+/// `ptr` must be null or a pointer returned by this library that has not been
+/// freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn autoseed_free_string(ptr: *mut c_char) {
+    if ptr.is_null() {
+        return;
+    }
+
+    // SAFETY: the pointer came from CString::into_raw in this library
+    drop(unsafe { CString::from_raw(ptr) });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_hex_writes_matching_address() {
+        let mut seed = [0u8; AUTOSEED_SEED_LEN];
+        let mut address = [0 as c_char; AUTOSEED_ADDRESS_BUF_LEN];
+
+        let rc = unsafe { autoseed_generate_hex(seed.as_mut_ptr(), address.as_mut_ptr(), 6094) };
+        assert_eq!(rc, AUTOSEED_OK);
+
+        let address = unsafe { CStr::from_ptr(address.as_ptr()) }.to_str().unwrap();
+        assert_eq!(address, crate::wallet::hex_to_address_with_prefix(&seed, 6094));
+
+        let rc = unsafe { autoseed_generate_hex(std::ptr::null_mut(), std::ptr::null_mut(), 6094) };
+        assert_eq!(rc, AUTOSEED_ERR_NULL);
+    }
+
+    #[test]
+    fn test_address_from_seed_round_trip() {
+        let seed = [42u8; AUTOSEED_SEED_LEN];
+        let ptr = unsafe { autoseed_address_from_seed(seed.as_ptr(), 6094) };
+        assert!(!ptr.is_null());
+
+        let address = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+        unsafe { autoseed_free_string(ptr) };
+        assert_eq!(address, crate::wallet::hex_to_address_with_prefix(&seed, 6094));
+
+        assert!(unsafe { autoseed_address_from_seed(std::ptr::null(), 6094) }.is_null());
+        unsafe { autoseed_free_string(std::ptr::null_mut()) };
+    }
+
+    #[test]
+    fn test_search() {
+        let address = CString::new(crate::wallet::hex_to_address_with_prefix(&[42u8; 32], 6094)).unwrap();
+        let middle = CString::new(&address.as_bytes()[10..14]).unwrap();
+        let upper = CString::new(address.as_bytes()[10..14].to_ascii_uppercase()).unwrap();
+        let absent = CString::new("0000").unwrap();
+
+        unsafe {
+            assert_eq!(autoseed_search(middle.as_ptr(), address.as_ptr(), 1), 1);
+            assert_eq!(autoseed_search(upper.as_ptr(), address.as_ptr(), 0), 1);
+            assert_eq!(autoseed_search(absent.as_ptr(), address.as_ptr(), 0), 0);
+            assert_eq!(autoseed_search(std::ptr::null(), address.as_ptr(), 0), AUTOSEED_ERR_NULL);
+        }
+
+        let invalid = [0xffu8 as c_char, 0];
+        assert_eq!(unsafe { autoseed_search(invalid.as_ptr(), address.as_ptr(), 0) }, AUTOSEED_ERR_UTF8);
+    }
+}
@@ -1,7 +1,37 @@
+//! Vanity address generation for Substrate-based chains
+//!
+//! The `autoseed` binary is a thin CLI over this crate. The items re-exported at
+//! the root are enough to run a search and derive addresses without the CLI:
+//!
+//! ```no_run
+//! use autoseed::{generate_vanity_addresses, SearchParams, VanityResult};
+//!
+//! // One hex-seed wallet with "ab" anywhere in its Substrate address
+//! let params = SearchParams { hex_mode: true, within: 49, suffix: false, anywhere: true, ..SearchParams::new("ab", 42) };
+//! let (results, _stats) = generate_vanity_addresses(
+//!     &params,
+//!     |_progress| {},
+//!     |_result: &VanityResult| {},
+//! );
+//! assert_eq!(results.len(), 1);
+//! ```
+
 pub mod cli;
 pub mod crypto;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod networks;
 pub mod runner;
 pub mod search;
 pub mod validation;
-pub mod wallet;
\ No newline at end of file
+pub mod wallet;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use crypto::{mnemonic_batch_to_addresses, mnemonic_to_address_with_prefix};
+pub use networks::{find_network_by_address_prefix, find_network_by_prefix, find_networks_by_address_prefix, NetworkConfig};
+pub use runner::{generate_vanity_addresses, SearchParams};
+pub use runner::VanityResult;
+pub use runner::verify_vanity_result;
+pub use search::OptimizedSearchConfig;
+pub use wallet::hex_to_address_with_prefix;
@@ -1,20 +1,20 @@
-mod cli;
-mod crypto;
-mod networks;
-mod runner;
-mod search;
-mod validation;
-mod wallet;
+// The CLI needs a terminal, threads and a filesystem; wasm32 builds only ship the library
+#![cfg_attr(target_arch = "wasm32", no_main)]
+#![cfg(not(target_arch = "wasm32"))]
 
+use autoseed::{cli, networks, runner, validation, wallet};
 use cli::{
     args::{parse_and_validate_args, validate_output_directory},
-    display::{display_save_location, display_statistics, process_individual_wallet},
-    password::get_password_interactive,
-    probability::{calculate_expected_attempts, print_probability_breakdown},
-    terminal::{clear_screen_completely, hide_cursor, print_header, print_progress, reset_terminal, colors, enable_ansi_support, terminal_codes},
+    display::{display_histogram, display_statistics, HISTOGRAM_MIN_WALLETS, process_individual_wallet, write_run_statistics},
+    password::get_password_with_confirmation,
+    probability::{calculate_expected_attempts, print_monte_carlo_comparison, print_probability_breakdown},
+    terminal::{clear_screen_completely, hide_cursor, print_header, print_progress, reset_terminal, colors, enable_ansi_support, terminal_codes, watch_terminal_width},
 };
+use cli::metrics::{spawn_metrics_server, Metrics};
 use runner::parallel::{generate_vanity_addresses, ProgressInfo};
 use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use zeroize::Zeroize;
 use num_format::{SystemLocale, ToFormattedString};
 
@@ -28,8 +28,18 @@ const APP_HEADER: &str = r"
 ";
 
 
-fn setup_signal_handler() {
+/// Ctrl+C during the search sets `stop`, so the run ends normally and still writes
+/// its manifest and statistics; before the search starts, or on a second Ctrl+C, it
+/// exits at once
+fn setup_signal_handler(stop: Arc<AtomicBool>, searching: Arc<AtomicBool>) {
     ctrlc::set_handler(move || {
+        if searching.load(Ordering::Relaxed) && !stop.swap(true, Ordering::Relaxed) {
+            return;
+        }
+
+        // Exiting skips Drop, so free the output directories here
+        wallet::lock::release_all();
+
         // Restore terminal echo (platform-specific)
         #[cfg(unix)]
         {
@@ -43,29 +53,8 @@ fn setup_signal_handler() {
     .expect("Error setting Ctrl+C handler");
 }
 
-fn main() {
-    // Enable ANSI color support on Windows
-    enable_ansi_support();
-    
-    setup_signal_handler();
-    
-    let config = parse_and_validate_args();
-    
-    // Clear screen and show header first
-    clear_screen_completely(); // Clear scrollback + screen like Linux `clear` command
-    println!("{}", colors::white(APP_HEADER));
-
-    // Get password once if in hex mode
-    let password = if config.hex_mode {
-        if let Some(pwd) = &config.password {
-            Some(pwd.clone())
-        } else {
-            Some(get_password_interactive())
-        }
-    } else {
-        None
-    };
-
+/// Print the search parameters, odds and output location above the progress line
+fn print_search_summary(config: &cli::args::Config, expected_attempts: u64) {
     let header_length = print_header(
         &config.term,
         config.suffix,
@@ -74,6 +63,8 @@ fn main() {
         config.case_sensitive,
         config.hex_mode,
         config.threads,
+        config.ss58_prefix,
+        config.search_config().search_range,
     );
 
     let wallet_text = if config.count == 1 { "wallet" } else { "wallets" };
@@ -85,23 +76,16 @@ fn main() {
         format!("Custom Network (SS58: {})", config.ss58_prefix)
     };
     
-    // Calculate and display odds
-    let expected_attempts = calculate_expected_attempts(
-        &config.term,
-        config.case_sensitive,
-        config.suffix,
-        config.anywhere,
-        config.within,
-        config.ss58_prefix,
-    );
+    // Display odds
     let odds_str = expected_attempts.to_formatted_string(&SystemLocale::default().unwrap());
     
+    let count_text = if config.count == 0 { "unlimited".to_string() } else { config.count.to_string() };
     let generating_line = format!("Generating {} {} for {} Expected: ~{} searches per wallet", 
-                                  config.count, wallet_text, network_info, odds_str);
+                                  count_text, wallet_text, network_info, odds_str);
     println!("{}", generating_line);
     
     // Show output directory path
-    let output_path = std::path::Path::new(&config.output_dir);
+    let output_path = std::path::Path::new(config.wallet_dir());
     let wallet_type = if config.hex_mode { "Encrypted" } else { "Mnemonic" };
     
     // Get absolute path but handle Windows UNC gracefully
@@ -127,30 +111,130 @@ fn main() {
         format!("{}{}", path_str, std::path::MAIN_SEPARATOR)
     };
     
-    let output_line = format!("{} wallets will be saved to: {}", wallet_type, display_path);
+    let output_line = match &config.append_to {
+        _ if config.no_save => "Wallets will only be displayed".to_string(),
+        Some(path) => format!("Wallets will be appended to: {}", path),
+        None => format!("{} wallets will be saved to: {}", wallet_type, display_path),
+    };
     println!("{}", output_line);
+
+    if config.no_save {
+        println!("{}", colors::yellow("WARNING: wallet not saved to disk"));
+    }
+
+    if config.password.as_deref() == Some("") && !config.hex_mode && !config.no_save {
+        println!("{}", colors::yellow("WARNING: wallets saved without password encryption"));
+    }
+    if config.export_raw {
+        println!("{}", colors::red("WARNING: --export-raw saves UNENCRYPTED private keys (*_raw.txt)"));
+    }
+    if config.show_derivation && !config.quiet {
+        println!("{}", colors::red("WARNING: --show-derivation prints secret key material; don't log or share this output"));
+    }
+    if config.append_to.is_some() {
+        println!("{}", colors::red("WARNING: --append-to saves UNENCRYPTED secrets"));
+    }
     
     // Use the longest of the three lines for the separator
     let separator_length = header_length.max(generating_line.len()).max(output_line.len());
     println!("{}", "─".repeat(separator_length));
     println!(); // Extra line before progress
 
-    // Validate output directory after showing search parameters
-    if let Err(e) = validate_output_directory(&config.output_dir) {
-        eprintln!("{}: {e}", colors::red("ERROR"));
+    if let wallet::Kdf::Scrypt(params) = config.kdf
+        && config.hex_mode
+        && params.n > wallet::SCRYPT_N_WEB_WALLET_MAX
+    {
+        println!("{}: scrypt N={} is above {}, wallets may not import into some web wallets",
+                 colors::yellow("WARNING"), params.n, wallet::SCRYPT_N_WEB_WALLET_MAX);
+        println!();
+    }
+
+    if config.pbkdf2_rounds != wallet::DEFAULT_PBKDF2_ROUNDS && !config.hex_mode {
+        println!("{}: Using {} PBKDF2 rounds, standard wallets derive mnemonics with {} and will not recover these addresses",
+                 colors::yellow("WARNING"), config.pbkdf2_rounds, wallet::DEFAULT_PBKDF2_ROUNDS);
+        if config.pbkdf2_rounds > 100_000 {
+            println!("{}: More than 100,000 PBKDF2 rounds will make the search very slow",
+                     colors::yellow("WARNING"));
+        }
+        println!();
+    }
+}
+
+fn main() {
+    // Enable ANSI color support on Windows
+    enable_ansi_support();
+    
+    let stop = Arc::new(AtomicBool::new(false));
+    let searching = Arc::new(AtomicBool::new(false));
+    setup_signal_handler(stop.clone(), searching.clone());
+    watch_terminal_width();
+    
+    let config = parse_and_validate_args();
+    
+    // Clear screen and show header first; quiet runs print nothing but found wallets
+    if !config.quiet {
+        clear_screen_completely(); // Clear scrollback + screen like Linux `clear` command
+        println!("{}", colors::white(APP_HEADER));
+    }
+
+    // Get password once if in hex mode; --append-to stores secrets without one and
+    // --no-save stores nothing
+    let password = if config.hex_mode && config.append_to.is_none() && !config.no_save {
+        if let Some(pwd) = &config.password {
+            Some(pwd.clone())
+        } else {
+            Some(get_password_with_confirmation())
+        }
+    } else {
+        None
+    };
+
+    let expected_attempts = calculate_expected_attempts(
+        &config.term,
+        config.case_sensitive,
+        config.search_config().search_range,
+        config.ss58_prefix,
+    );
+    if !config.quiet {
+        print_search_summary(&config, expected_attempts);
+    }
+
+    // Validate output directories after showing search parameters; --no-save never creates them
+    let output_dirs = if config.no_save { [None; 3] } else { [config.output_dir.as_ref(), config.mnemonic_output_dir.as_ref(), config.json_output_dir.as_ref()] };
+    if let Err(e) = output_dirs.into_iter().flatten().try_for_each(|dir| validate_output_directory(dir)) {
+        tracing::error!("{e}");
         reset_terminal();
         std::process::exit(1);
     }
 
     // Validate the search term based on case sensitivity mode and network
-    if let Err(e) = validation::validate_search_term_with_prefix(
+    match validation::validate_search_term_with_prefix(
         &config.term,
         config.case_sensitive,
         config.suffix,
         config.within,
         config.ss58_prefix,
     ) {
-        eprintln!("{}: {e}", colors::red("ERROR"));
+        Ok(warnings) => {
+            for warning in warnings.into_iter().filter(|_| !config.quiet) {
+                println!("{}: {}\n", colors::yellow("WARNING"), warning);
+            }
+        }
+        Err(e) => {
+            tracing::error!("{e}");
+            reset_terminal();
+            std::process::exit(1);
+        }
+    }
+
+    // Catch characters the network's address encoding never produces where the search looks
+    if let Err(e) = validation::theoretical_impossibility_check(
+        &config.term,
+        config.case_sensitive,
+        config.search_config().search_range,
+        config.ss58_prefix,
+    ) {
+        tracing::error!("{e}");
         reset_terminal();
         std::process::exit(1);
     }
@@ -163,46 +247,122 @@ fn main() {
             config.suffix,
             config.anywhere,
             config.within,
+            config.search_config().search_range,
             config.ss58_prefix,
         );
+
+        if let Some(samples) = config.monte_carlo_samples {
+            print_monte_carlo_comparison(
+                &config.term,
+                config.case_sensitive,
+                config.suffix,
+                config.anywhere,
+                config.within,
+                config.ss58_prefix,
+                samples,
+                expected_attempts,
+            );
+        }
+
+        if expected_attempts > 1_000_000_000 {
+            let suggestion = validation::suggest_easier_pattern(
+                &config.term,
+                config.case_sensitive,
+                config.ss58_prefix,
+                |term| config.search_config_for(term).search_range,
+            );
+            if let Some((shorter, attempts)) = suggestion {
+                println!("{}", colors::cyan(&format!(
+                    "Suggestion: try '--term {}' for ~{} attempts instead.",
+                    shorter,
+                    attempts.to_formatted_string(&SystemLocale::default().unwrap())
+                )));
+                println!();
+            }
+        }
     }
 
+    // Serve live metrics for the duration of the search when requested
+    let metrics = Arc::new(Metrics::new(config.threads));
+    let metrics_stop = Arc::new(AtomicBool::new(false));
+    let metrics_server = config.metrics_port.map(|port| {
+        spawn_metrics_server(config.metrics_bind, port, metrics.clone(), metrics_stop.clone()).unwrap_or_else(|e| {
+            tracing::error!("{e}");
+            reset_terminal();
+            std::process::exit(1);
+        })
+    });
+
+    // Hold every output directory for the rest of the run so a second instance can't
+    // write into it; canonical paths so one directory is never locked twice
+    let mut locked_dirs: Vec<std::path::PathBuf> = Vec::new();
+    for dir in output_dirs.into_iter().flatten() {
+        let canonical = std::fs::canonicalize(dir).unwrap_or_else(|_| dir.into());
+        if !locked_dirs.contains(&canonical) {
+            locked_dirs.push(canonical);
+        }
+    }
+    let _directory_locks: Vec<wallet::lock::DirectoryLock> = locked_dirs
+        .iter()
+        .map(|dir| wallet::lock::DirectoryLock::acquire(&dir.to_string_lossy()))
+        .collect::<Result<_, _>>()
+        .unwrap_or_else(|e| {
+            tracing::error!("{e}");
+            reset_terminal();
+            std::process::exit(1);
+        });
+
+    // Open the results log up front so a bad path fails before the search
+    let mut results_file = config.append_to.as_deref().map(|path| {
+        wallet::results_file::ResultsFile::open(path).unwrap_or_else(|e| {
+            tracing::error!("{e}");
+            reset_terminal();
+            std::process::exit(1);
+        })
+    });
+
     // Hide cursor during search
-    hide_cursor();
+    if !config.quiet {
+        hide_cursor();
+    }
 
     // Track wallet number for streaming display
     let wallet_counter = std::sync::atomic::AtomicUsize::new(0);
     let start_time = std::time::Instant::now();
     // Use Mutex to store Instant for precise timing
     let last_wallet_instant = std::sync::Arc::new(std::sync::Mutex::new(start_time));
+    // Per-wallet stats collected for the manifest
+    let wallet_stats = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
 
     // Use multi-threaded generator with ETA and luck
+    searching.store(true, Ordering::Relaxed);
     let (results, total_stats) = generate_vanity_addresses(
-        config.count,
-        config.case_sensitive,
-        config.hex_mode,
-        config.ss58_prefix,
-        config.within,
-        config.suffix,
-        config.anywhere,
-        &config.term,
-        config.threads,
+        &runner::SearchParams { stop: Some(stop), ..config.search_params() },
         |info: ProgressInfo| {
+            metrics.record_progress(&info);
+            if config.quiet {
+                return;
+            }
             print_progress(
                 info.keys_per_second,
                 info.total_attempts,
                 info.elapsed_secs,
-                info.eta.as_deref(),
+                info.eta_next_wallet.as_deref(),
+                info.eta_all_remaining.as_deref(),
                 info.luck,
                 info.found_count,
                 info.count,
+                config.per_thread_stats.then_some(info.thread_keys_per_second.as_slice()),
             );
         },
         {
             let last_wallet_instant = last_wallet_instant.clone();
+            let wallet_stats = wallet_stats.clone();
             let config_clone = config.clone();
             let password_clone = password.clone();
+            let metrics = metrics.clone();
             move |result: &runner::VanityResult| {
+                metrics.record_wallet();
                 let wallet_num = wallet_counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
                 let current_instant = std::time::Instant::now();
                 
@@ -219,26 +379,55 @@ fn main() {
                 
                 // Clear the progress line completely and ensure clean display
                 // For all wallets, we need to clear the current progress line and move cursor to start
-                print!("{}", terminal_codes::CR_CLEAR_LINE); // Clear the progress line
-                std::io::stdout().flush().unwrap();
+                if !config_clone.quiet {
+                    print!("{}", terminal_codes::CR_CLEAR_LINE); // Clear the progress line
+                    std::io::stdout().flush().unwrap();
+                }
                 
-                process_individual_wallet(result, &config_clone, &password_clone, wallet_num, wallet_elapsed_secs, wallet_elapsed_nanos);
+                match process_individual_wallet(result, &config_clone, &password_clone, wallet_num, wallet_elapsed_secs, wallet_elapsed_nanos, results_file.as_mut()) {
+                    Ok(stat) => wallet_stats.lock().unwrap().push(stat),
+                    Err(e) => tracing::error!("Failed to save wallet: {e}"),
+                }
             }
         },
     );
+    searching.store(false, Ordering::Relaxed);
 
-    // Clear the final progress line since it's redundant after all wallets are found
-    print!("{}", terminal_codes::CR_CLEAR_LINE); // Clear the final progress line
-    std::io::stdout().flush().unwrap();
+    if let Some(handle) = metrics_server {
+        metrics_stop.store(true, Ordering::Relaxed);
+        let _ = handle.join();
+    }
 
-    // Display statistics
-    display_statistics(&results, &total_stats, &config);
+    if !config.quiet {
+        // Clear the final progress line since it's redundant after all wallets are found
+        print!("{}", terminal_codes::CR_CLEAR_LINE); // Clear the final progress line
+        std::io::stdout().flush().unwrap();
 
-    // Show save location
-    display_save_location(&config);
+        // Display statistics
+        display_statistics(&results, &total_stats);
+        if config.histogram && config.count > HISTOGRAM_MIN_WALLETS {
+            display_histogram(&results, expected_attempts);
+        }
+    }
 
-    // Restore terminal state
-    reset_terminal();
+    // Write manifest of all saved wallets (public metadata only)
+    if !config.no_save
+        && let Err(e) = wallet::manifest::write_manifest(&results, &wallet_stats.lock().unwrap(), config.report_dir())
+    {
+        tracing::error!("{e}");
+    }
+
+    // Write the per-wallet statistics report when requested
+    if config.statistics {
+        if let Err(e) = write_run_statistics(&results, &wallet_stats.lock().unwrap(), &config) {
+            tracing::error!("{e}");
+        }
+    }
+
+    if !config.quiet {
+        // Restore terminal state
+        reset_terminal();
+    }
 
     // Clear password from memory
     if let Some(mut pwd) = password {
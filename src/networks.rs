@@ -1,38 +1,318 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock, RwLock};
+
 /// Network configuration for different Substrate-based chains
 #[derive(Debug, Clone)]
 pub struct NetworkConfig {
     pub name: &'static str,
     pub ss58_prefix: u16,
     pub address_prefixes: &'static [&'static str],
+    pub genesis_hash: Option<&'static str>, // Written to JSON wallet metadata as `genesisHash`
+    pub token_symbol: &'static str,
+    pub decimals: u8, // Token amounts are integers of 10^-decimals tokens
+    pub coingecko_id: Option<&'static str>, // CoinGecko API coin id, for price lookups
+    pub address_example: Option<&'static str>, // Address of the all-zeros hex seed, for docs and tests
 }
 
+/// Token of a chain that doesn't set one (Substrate's development default)
+pub const DEFAULT_TOKEN_SYMBOL: &str = "UNIT";
+/// Decimals of a chain that doesn't set them (Substrate's development default)
+pub const DEFAULT_DECIMALS: u8 = 12;
+
 impl NetworkConfig {
     pub const fn new(name: &'static str, ss58_prefix: u16, address_prefixes: &'static [&'static str]) -> Self {
         Self {
             name,
             ss58_prefix,
             address_prefixes,
+            genesis_hash: None,
+            token_symbol: DEFAULT_TOKEN_SYMBOL,
+            decimals: DEFAULT_DECIMALS,
+            coingecko_id: None,
+            address_example: None,
         }
     }
+
+    /// Attach the chain's genesis block hash (0x-prefixed hex)
+    pub const fn with_genesis_hash(mut self, genesis_hash: &'static str) -> Self {
+        self.genesis_hash = Some(genesis_hash);
+        self
+    }
+
+    /// Set the native token's symbol and decimals
+    pub const fn with_token(mut self, token_symbol: &'static str, decimals: u8) -> Self {
+        self.token_symbol = token_symbol;
+        self.decimals = decimals;
+        self
+    }
+
+    /// Attach the token's CoinGecko coin id
+    pub const fn with_coingecko_id(mut self, coingecko_id: &'static str) -> Self {
+        self.coingecko_id = Some(coingecko_id);
+        self
+    }
+
+    /// Attach the address the all-zeros hex seed (`--hex`, 32 zero bytes) has on this network
+    pub const fn with_address_example(mut self, address_example: &'static str) -> Self {
+        self.address_example = Some(address_example);
+        self
+    }
 }
 
+/// Autonomys mainnet genesis block hash
+pub const AUTONOMYS_GENESIS_HASH: &str = "0x66455a580aabff303720aa83adbe6c44502922251c03ba73686d5245da9e21bd";
+/// Polkadot relay chain genesis block hash
+pub const POLKADOT_GENESIS_HASH: &str = "0x91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3";
+
 /// Network reference table
 pub const NETWORKS: &[NetworkConfig] = &[
-    NetworkConfig::new("Autonomys", 6094, &["su"]),  // Single prefix
-    NetworkConfig::new("Polkadot", 0, &["1"]),
-    NetworkConfig::new("Substrate", 42, &["5"]),
+    NetworkConfig::new("Autonomys", 6094, &["su"])  // Single prefix
+        .with_genesis_hash(AUTONOMYS_GENESIS_HASH)
+        .with_token("AI3", 18)
+        .with_coingecko_id("autonomys")
+        .with_address_example("sug7Q64BCR6Z2HwXnPR31Y3GUrnXLMM5JwzF5MAvhbb69DHoZ"),
+    NetworkConfig::new("Polkadot", 0, &["1"])
+        .with_genesis_hash(POLKADOT_GENESIS_HASH)
+        .with_token("DOT", 10)
+        .with_coingecko_id("polkadot")
+        .with_address_example("163KH1bkorFNdyVktuFAoVdCHWz7HChPB7BrzfJFQvsFLgnL"),
+    // Generic prefix, no single chain or token
+    NetworkConfig::new("Substrate", 42, &["5"])
+        .with_address_example("5H728gLgx4yuCSVEwGCAfLo3RtzTau9F6cTNqNJtrqqjACWq"),
     // Example with multiple prefixes (commented out):
     // NetworkConfig::new("Autonomys", 6094, &["su", "sub", "suc", "sue"]),
 ];
 
+/// Networks every lookup searches: `NETWORKS` until `--network-config` loads a file
+///
+/// A reload swaps in a new list; lookups that already hold the old one keep it until
+/// they drop it.
+static ACTIVE_NETWORKS: LazyLock<RwLock<Arc<[NetworkConfig]>>> = LazyLock::new(|| RwLock::new(NETWORKS.into()));
+
+/// The networks lookups currently search
+pub fn networks() -> Arc<[NetworkConfig]> {
+    ACTIVE_NETWORKS.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Names of the current networks, for error messages
+pub fn network_names() -> String {
+    networks().iter().map(|network| network.name).collect::<Vec<_>>().join(", ")
+}
+
 /// Find network configuration by name
-pub fn find_network(name: &str) -> Option<&'static NetworkConfig> {
-    NETWORKS.iter().find(|network| network.name.eq_ignore_ascii_case(name))
+pub fn find_network(name: &str) -> Option<NetworkConfig> {
+    networks().iter().find(|network| network.name.eq_ignore_ascii_case(name)).cloned()
 }
 
 /// Find network configuration by SS58 prefix
-pub fn find_network_by_prefix(ss58_prefix: u16) -> Option<&'static NetworkConfig> {
-    NETWORKS.iter().find(|network| network.ss58_prefix == ss58_prefix)
+pub fn find_network_by_prefix(ss58_prefix: u16) -> Option<NetworkConfig> {
+    networks().iter().find(|network| network.ss58_prefix == ss58_prefix).cloned()
+}
+
+/// Find every network whose addresses can start like `address`
+///
+/// Only the leading characters are compared with each network's `address_prefixes`;
+/// the address isn't decoded, so a match doesn't prove the address is valid.
+pub fn find_networks_by_address_prefix(address: &str) -> Vec<NetworkConfig> {
+    networks()
+        .iter()
+        .filter(|network| network.address_prefixes.iter().any(|prefix| address.starts_with(prefix)))
+        .cloned()
+        .collect()
+}
+
+/// Find the network an address belongs to by its first characters
+///
+/// Returns `None` when no network or more than one matches; use
+/// [`find_networks_by_address_prefix`] to see every candidate.
+pub fn find_network_by_address_prefix(address: &str) -> Option<NetworkConfig> {
+    let mut networks = find_networks_by_address_prefix(address);
+    match networks.len() {
+        1 => networks.pop(),
+        _ => None,
+    }
+}
+
+/// Validate a genesis hash given as 64 hex digits (0x optional) and return it as
+/// lowercase 0x-prefixed hex, the form polkadot.js stores in wallet metadata
+pub fn parse_genesis_hash(hash: &str) -> Result<String, String> {
+    let digits = hash.strip_prefix("0x").or_else(|| hash.strip_prefix("0X")).unwrap_or(hash);
+    let bytes = hex::decode(digits).map_err(|e| format!("Invalid genesis hash '{hash}': {e}"))?;
+    if bytes.len() != 32 {
+        return Err(format!(
+            "Invalid genesis hash '{hash}': expected 32 bytes, got {}\nSUGGESTION: A genesis hash is 64 hex digits, e.g. {AUTONOMYS_GENESIS_HASH}",
+            bytes.len()
+        ));
+    }
+    Ok(format!("0x{}", hex::encode(bytes)))
+}
+
+/// A `&'static` copy of `s`, leaked only the first time that text is seen
+///
+/// `NetworkConfig` holds `&'static str`s, so strings from a network config file are
+/// leaked; interning them means reloading a file leaks only text it didn't have before.
+fn leak_str(s: &str) -> &'static str {
+    static STRINGS: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    let mut strings = STRINGS.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(&interned) = strings.get(s) {
+        return interned;
+    }
+    let leaked: &'static str = Box::leak(s.to_string().into_boxed_str());
+    strings.insert(leaked);
+    leaked
+}
+
+/// A `&'static` copy of `prefixes`, interned like [`leak_str`]
+fn leak_prefixes(prefixes: Vec<&'static str>) -> &'static [&'static str] {
+    static PREFIXES: OnceLock<Mutex<HashMap<Vec<&'static str>, &'static [&'static str]>>> = OnceLock::new();
+    let mut interned = PREFIXES.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+    interned.entry(prefixes).or_insert_with_key(|prefixes| Box::leak(prefixes.clone().into_boxed_slice()))
+}
+
+/// Parse a `--network-config` file
+///
+/// The file is a JSON array of objects with `name`, `ss58_prefix`, `address_prefixes`
+/// and optional `genesis_hash`, `token_symbol`, `decimals`, `coingecko_id` and
+/// `address_example`. Its entries come first; built-in networks it doesn't name
+/// (case-insensitively) follow.
+pub fn parse_network_config(contents: &str) -> Result<Vec<NetworkConfig>, String> {
+    let value: serde_json::Value = serde_json::from_str(contents).map_err(|e| format!("Invalid JSON: {e}"))?;
+    let entries = value.as_array().ok_or("Network config must be a JSON array of networks")?;
+
+    let mut networks = Vec::with_capacity(entries.len() + NETWORKS.len());
+    for (i, entry) in entries.iter().enumerate() {
+        let name = entry["name"]
+            .as_str()
+            .filter(|name| !name.trim().is_empty())
+            .ok_or_else(|| format!("Network {}: 'name' must be a non-empty string", i + 1))?;
+        if networks.iter().any(|network: &NetworkConfig| network.name.eq_ignore_ascii_case(name)) {
+            return Err(format!("Network '{name}' is listed twice"));
+        }
+        // SS58 prefixes above 16383 have no encoding
+        let ss58_prefix = entry["ss58_prefix"]
+            .as_u64()
+            .filter(|&prefix| prefix <= 16383)
+            .ok_or_else(|| format!("Network '{name}': 'ss58_prefix' must be an integer from 0 to 16383"))?
+            as u16;
+        let address_prefixes: Vec<&'static str> = entry["address_prefixes"]
+            .as_array()
+            .and_then(|prefixes| prefixes.iter().map(|prefix| prefix.as_str().map(leak_str)).collect())
+            .ok_or_else(|| format!("Network '{name}': 'address_prefixes' must be an array of strings"))?;
+
+        let mut network = NetworkConfig::new(leak_str(name), ss58_prefix, leak_prefixes(address_prefixes));
+        if let Some(hash) = entry.get("genesis_hash").filter(|hash| !hash.is_null()) {
+            let hash = hash.as_str().ok_or_else(|| format!("Network '{name}': 'genesis_hash' must be a string"))?;
+            network = network.with_genesis_hash(leak_str(&parse_genesis_hash(hash).map_err(|e| format!("Network '{name}': {e}"))?));
+        }
+        if let Some(symbol) = entry.get("token_symbol").filter(|symbol| !symbol.is_null()) {
+            let symbol = symbol.as_str().ok_or_else(|| format!("Network '{name}': 'token_symbol' must be a string"))?;
+            network.token_symbol = leak_str(symbol);
+        }
+        if let Some(decimals) = entry.get("decimals").filter(|decimals| !decimals.is_null()) {
+            network.decimals = decimals
+                .as_u64()
+                .and_then(|decimals| u8::try_from(decimals).ok())
+                .ok_or_else(|| format!("Network '{name}': 'decimals' must be an integer from 0 to 255"))?;
+        }
+        if let Some(id) = entry.get("coingecko_id").filter(|id| !id.is_null()) {
+            let id = id.as_str().ok_or_else(|| format!("Network '{name}': 'coingecko_id' must be a string"))?;
+            network = network.with_coingecko_id(leak_str(id));
+        }
+        if let Some(example) = entry.get("address_example").filter(|example| !example.is_null()) {
+            let example = example.as_str().ok_or_else(|| format!("Network '{name}': 'address_example' must be a string"))?;
+            match crate::validation::decode_ss58_address(example) {
+                Ok(decoded) if decoded.ss58_prefix == ss58_prefix => {}
+                Ok(decoded) => {
+                    return Err(format!("Network '{name}': 'address_example' has SS58 prefix {}, not {ss58_prefix}", decoded.ss58_prefix));
+                }
+                Err(e) => return Err(format!("Network '{name}': invalid 'address_example': {e}")),
+            }
+            network = network.with_address_example(leak_str(example));
+        }
+        networks.push(network);
+    }
+
+    for builtin in NETWORKS {
+        if !networks.iter().any(|network| network.name.eq_ignore_ascii_case(builtin.name)) {
+            networks.push(builtin.clone());
+        }
+    }
+    Ok(networks)
+}
+
+/// Read and parse a `--network-config` file
+pub fn load_network_config(path: &std::path::Path) -> Result<Vec<NetworkConfig>, String> {
+    parse_network_config(&read_network_config(path)?).map_err(|e| format!("Invalid network config {}: {}", path.display(), e))
+}
+
+fn read_network_config(path: &std::path::Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("Failed to read network config {}: {}", path.display(), e))
+}
+
+/// Replace the networks every lookup searches
+pub fn set_networks(networks: Vec<NetworkConfig>) {
+    *ACTIVE_NETWORKS.write().unwrap_or_else(|e| e.into_inner()) = networks.into();
+}
+
+/// Reload `path` on a background thread whenever it changes
+///
+/// The parent directory is watched, so editors that save by replacing the file are
+/// seen too. Events that leave the contents as they were are ignored. A file that fails
+/// to parse is logged and the current networks are kept.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn watch_network_config(path: &std::path::Path) -> Result<(), String> {
+    use notify::{EventKind, RecursiveMode, Watcher};
+
+    let path = path
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve network config {}: {}", path.display(), e))?;
+    let dir = path.parent().ok_or_else(|| format!("Network config {} has no parent directory", path.display()))?;
+
+    let mut last_contents = read_network_config(&path)?;
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx).map_err(|e| format!("Failed to watch network config: {e}"))?;
+    watcher
+        .watch(dir, RecursiveMode::NonRecursive)
+        .map_err(|e| format!("Failed to watch {}: {}", dir.display(), e))?;
+
+    std::thread::Builder::new()
+        .name("network-config".to_string())
+        .spawn(move || {
+            // Events stop once the watcher is dropped, so the thread owns it
+            let _watcher = watcher;
+            for event in rx {
+                match event {
+                    Ok(event)
+                        if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_))
+                            && event.paths.contains(&path) =>
+                    {
+                        let contents = match read_network_config(&path) {
+                            Ok(contents) => contents,
+                            Err(e) => {
+                                tracing::error!("{e}; keeping the current networks");
+                                continue;
+                            }
+                        };
+                        // Saving truncates before writing; wait for the write's own event
+                        if contents.is_empty() || contents == last_contents {
+                            continue;
+                        }
+                        match parse_network_config(&contents) {
+                            Ok(networks) => {
+                                tracing::info!("Reloaded {} networks from {}", networks.len(), path.display());
+                                set_networks(networks);
+                            }
+                            Err(e) => tracing::error!("Invalid network config {}: {}; keeping the current networks", path.display(), e),
+                        }
+                        last_contents = contents;
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::error!("Watching network config {} failed: {}", path.display(), e),
+                }
+            }
+        })
+        .map_err(|e| format!("Failed to start network config watcher: {e}"))?;
+    Ok(())
 }
 
 
@@ -56,6 +336,69 @@ mod tests {
         assert_eq!(autonomys.name, "Autonomys");
         assert_eq!(autonomys.ss58_prefix, 6094);
         assert_eq!(autonomys.address_prefixes, &["su"]);
+        assert_eq!(autonomys.genesis_hash, Some(AUTONOMYS_GENESIS_HASH));
+        assert_eq!((autonomys.token_symbol, autonomys.decimals), ("AI3", 18));
+        assert_eq!(autonomys.coingecko_id, Some("autonomys"));
+    }
+
+    #[test]
+    fn test_parse_genesis_hash() {
+        assert_eq!(parse_genesis_hash(POLKADOT_GENESIS_HASH).unwrap(), POLKADOT_GENESIS_HASH);
+        assert_eq!(parse_genesis_hash(&POLKADOT_GENESIS_HASH[2..].to_uppercase()).unwrap(), POLKADOT_GENESIS_HASH);
+        assert!(parse_genesis_hash("0x1234").unwrap_err().contains("expected 32 bytes, got 2"));
+        assert!(parse_genesis_hash("0xzz").is_err());
+        assert!(find_network("substrate").unwrap().genesis_hash.is_none());
+    }
+
+    #[test]
+    fn test_find_network_by_address_prefix() {
+        let polkadot = find_network_by_address_prefix("14E5nqKAp3oAJcmzgZhUD2RcptBeUBScxKHgJKU4HPNcKVf3").unwrap();
+        assert_eq!(polkadot.name, "Polkadot");
+        assert_eq!(find_network_by_address_prefix("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap().name, "Substrate");
+        assert_eq!(find_network_by_address_prefix("sucPSnqV7cZXnm5Csifi5GPuTEQP6Go7or4yUVo4W9ybitN8s").unwrap().name, "Autonomys");
+        assert!(find_network_by_address_prefix("Fxyz").is_none());
+        assert!(find_networks_by_address_prefix("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_network_config() {
+        let networks = parse_network_config(
+            r#"[
+                {"name": "Kusama", "ss58_prefix": 2, "address_prefixes": ["C", "D"], "token_symbol": "KSM", "decimals": 12, "coingecko_id": "kusama"},
+                {"name": "substrate", "ss58_prefix": 42, "address_prefixes": ["5"], "genesis_hash": null},
+                {"name": "Westend", "ss58_prefix": 42, "address_prefixes": [], "genesis_hash": "e143f23803ac50e8f6f8e62695d1ce9e4e1d68aa36c1cd2cfd15340213f3423e"}
+            ]"#,
+        )
+        .unwrap();
+        let names: Vec<&str> = networks.iter().map(|network| network.name).collect();
+        // File entries first; the file's "substrate" replaces the built-in one
+        assert_eq!(names, ["Kusama", "substrate", "Westend", "Autonomys", "Polkadot"]);
+        assert_eq!(networks[0].address_prefixes, &["C", "D"]);
+        assert_eq!((networks[0].token_symbol, networks[0].decimals, networks[0].coingecko_id), ("KSM", 12, Some("kusama")));
+        assert_eq!((networks[2].token_symbol, networks[2].coingecko_id), (DEFAULT_TOKEN_SYMBOL, None));
+        assert_eq!(networks[2].genesis_hash, Some("0xe143f23803ac50e8f6f8e62695d1ce9e4e1d68aa36c1cd2cfd15340213f3423e"));
+
+        assert!(parse_network_config("{}").is_err());
+        assert!(parse_network_config(r#"[{"name": "X", "ss58_prefix": 16384, "address_prefixes": []}]"#).is_err());
+        assert!(parse_network_config(r#"[{"name": "X", "ss58_prefix": 1, "address_prefixes": [1]}]"#).is_err());
+        assert!(parse_network_config(r#"[{"name": "X", "ss58_prefix": 1, "address_prefixes": [], "decimals": 256}]"#).is_err());
+        assert!(parse_network_config(r#"[{"name": "X", "ss58_prefix": 1, "address_prefixes": [], "genesis_hash": "0x12"}]"#).is_err());
+        let example = |prefix: u16, address: &str| format!(r#"[{{"name": "X", "ss58_prefix": {prefix}, "address_prefixes": [], "address_example": "{address}"}}]"#);
+        let substrate = "5H728gLgx4yuCSVEwGCAfLo3RtzTau9F6cTNqNJtrqqjACWq";
+        assert_eq!(parse_network_config(&example(42, substrate)).unwrap()[0].address_example, Some(substrate));
+        assert!(parse_network_config(&example(0, substrate)).unwrap_err().contains("SS58 prefix 42, not 0"));
+        assert!(parse_network_config(&example(42, "5H728")).is_err());
+        let duplicate = r#"[{"name": "X", "ss58_prefix": 1, "address_prefixes": []}, {"name": "x", "ss58_prefix": 2, "address_prefixes": []}]"#;
+        assert!(parse_network_config(duplicate).unwrap_err().contains("listed twice"));
+    }
+
+    #[test]
+    fn test_reparse_reuses_strings() {
+        let config = r#"[{"name": "Kusama", "ss58_prefix": 2, "address_prefixes": ["C", "D"], "token_symbol": "KSM"}]"#;
+        let (first, second) = (parse_network_config(config).unwrap(), parse_network_config(config).unwrap());
+        assert!(std::ptr::eq(first[0].name, second[0].name));
+        assert!(std::ptr::eq(first[0].token_symbol, second[0].token_symbol));
+        assert!(std::ptr::eq(first[0].address_prefixes, second[0].address_prefixes));
     }
 
     #[test]
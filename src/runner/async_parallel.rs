@@ -0,0 +1,39 @@
+//! Async front end to the parallel search (`async-runner` feature)
+//!
+//! The search itself is CPU bound, so it runs on Tokio's blocking pool through
+//! `spawn_blocking` and never occupies an executor thread. Found wallets stream back
+//! over a `tokio::sync::mpsc` channel as they are found.
+
+use super::parallel::generate_vanity_addresses;
+use super::{SearchParams, VanityResult};
+use tokio_stream::Stream;
+use tokio_stream::wrappers::ReceiverStream;
+
+/// Async counterpart of [`generate_vanity_addresses`](super::parallel::generate_vanity_addresses)
+///
+/// Takes the same search parameters and yields each wallet as it is found; the stream ends
+/// once `count` wallets have been found. Progress reporting is not streamed. Unlike the
+/// sync version, an impossible pattern doesn't exit the process: the stream just ends
+/// without yielding anything.
+///
+/// Must be called from within a Tokio runtime. Dropping the stream does not stop the
+/// search early; it keeps running on the blocking pool until `count` wallets are found.
+pub async fn generate_vanity_addresses_async(params: &SearchParams) -> impl Stream<Item = VanityResult> {
+    let (tx, rx) = tokio::sync::mpsc::channel(params.count.clamp(1, 64));
+
+    if params.search_config().is_valid() && params.count > 0 {
+        let params = params.clone();
+        tokio::task::spawn_blocking(move || {
+            generate_vanity_addresses(
+                &params,
+                |_progress| {},
+                // A closed receiver only means the caller stopped listening
+                |result: &VanityResult| {
+                    let _ = tx.blocking_send(result.clone());
+                },
+            )
+        });
+    }
+
+    ReceiverStream::new(rx)
+}
@@ -1,7 +1,32 @@
+#[cfg(all(feature = "async-runner", not(target_arch = "wasm32")))]
+pub mod async_parallel;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod parallel;
+#[cfg(target_arch = "wasm32")]
+pub mod single;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use parallel::generate_vanity_addresses;
+#[cfg(target_arch = "wasm32")]
+pub use single::generate_vanity_addresses;
+
+use crate::crypto::rng::{ChaCha20Rng, WALLET_NONCE};
+use crate::search::{MatchType, OptimizedSearchConfig};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+/// `count` that turns `generate_vanity_addresses` into watch mode (`--watch`): every
+/// address is passed to the wallet callback with `matches == 0`, nothing is kept, and
+/// the run only ends with the process
+pub const WATCH_COUNT: usize = usize::MAX;
+
+/// Seconds without new attempts before the parallel runner reports a stall, unless overridden
+pub const DEFAULT_WATCHDOG_TIMEOUT_SECS: u64 = 10;
+
+/// Milliseconds between the parallel runner's progress reports, unless overridden
+pub const DEFAULT_PROGRESS_INTERVAL_MS: u64 = 1000;
+
 #[derive(Debug, Clone, Zeroize, ZeroizeOnDrop)]
 pub struct VanityResult {
     #[zeroize(skip)]
@@ -14,13 +39,213 @@ pub struct VanityResult {
     #[zeroize(skip)]
     pub attempts: u64, // Number of attempts to find this result
     #[zeroize(skip)]
+    pub expected_attempts: u64, // Expected attempts for the pattern, for this wallet's luck
+    #[zeroize(skip)]
     pub ss58_prefix: u16, // Network prefix used to generate this address
+    #[zeroize(skip)]
+    pub network_name: String, // Registry name for ss58_prefix, or "Custom"
+    #[zeroize(skip)]
+    pub match_detail: Vec<MatchType>, // How each matched character matched, for highlighting
+}
+
+impl VanityResult {
+    /// Luck in percent: expected attempts / actual attempts × 100 (0 when either is unknown)
+    pub fn luck_pct(&self) -> f64 {
+        crate::cli::probability::calculate_luck_factor(self.attempts, self.expected_attempts)
+    }
+
+    /// Fill `template` with this result's fields
+    ///
+    /// Supported tokens: `{address}`, `{secret}`, `{attempts}`, `{ss58_prefix}`,
+    /// `{network}` and `{luck_pct}` (whole percent). Other text, including unknown
+    /// tokens, is kept as is.
+    pub fn format_with(&self, template: &str) -> String {
+        template
+            .replace("{address}", &self.address)
+            .replace("{attempts}", &self.attempts.to_string())
+            .replace("{ss58_prefix}", &self.ss58_prefix.to_string())
+            .replace("{network}", &self.network_name)
+            .replace("{luck_pct}", &format!("{:.0}", self.luck_pct()))
+            // Last, so a template can't pull tokens out of the other fields' values
+            .replace("{secret}", &self.secret)
+    }
+}
+
+/// The address only, so logging or printing a result never shows its secret; use
+/// [`VanityResult::format_with`] with `{secret}` to include it
+impl std::fmt::Display for VanityResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.address)
+    }
+}
+
+#[cfg(test)]
+impl VanityResult {
+    /// An Autonomys result for `address` and `secret` with placeholder search
+    /// details, for tests; set any other field on the returned value
+    pub(crate) fn for_test(address: &str, secret: &str) -> Self {
+        Self {
+            address: address.to_string(),
+            secret: secret.to_string(),
+            matches: 0,
+            offset: 0,
+            attempts: 1,
+            expected_attempts: 0,
+            ss58_prefix: 6094,
+            network_name: "Autonomys".to_string(),
+            match_detail: Vec::new(),
+        }
+    }
+}
+
+/// What `generate_vanity_addresses` searches for and how
+///
+/// [`SearchParams::new`] gives the CLI defaults; override fields with struct update
+/// syntax, e.g. `SearchParams { count: 5, hex_mode: true, ..SearchParams::new("ai3", 6094) }`.
+#[derive(Debug, Clone)]
+pub struct SearchParams {
+    pub term: String,
+    pub count: usize, // Wallets to find; 0 searches until stopped, WATCH_COUNT is watch mode
+    pub case_sensitive: bool,
+    pub hex_mode: bool, // Raw hex seeds instead of mnemonics
+    pub pbkdf2_rounds: u32, // Mnemonic mode only
+    pub ss58_prefix: u16,
+    pub within: usize, // Positions searched from the start (or end with `suffix`)
+    pub within_window: Option<(usize, usize)>, // Explicit position window; overrides the mode and `within`
+    pub suffix: bool,
+    pub anywhere: bool,
+    pub threads: usize, // Worker threads; ignored by the single-threaded runner
+    pub progress_interval_ms: u64, // Between progress reports and watchdog ticks. Parallel runner only
+    pub watchdog_timeout_secs: u64, // Seconds without new attempts before a stall is reported; 0 = off. Parallel runner only
+    pub stop: Option<Arc<AtomicBool>>, // Set to end the search early; the wallets found so far are returned
+    /// Seed for the run's master RNG instead of system entropy
+    ///
+    /// **For tests only.** Every secret the run produces follows from the seed. A
+    /// single-threaded run is then fully reproducible; with several threads each worker's
+    /// stream is fixed, but which worker finds a match first still depends on timing.
+    pub rng_seed: Option<[u8; 32]>,
+}
+
+impl SearchParams {
+    /// One mnemonic wallet ending in `term`, on every available core
+    pub fn new(term: &str, ss58_prefix: u16) -> Self {
+        Self {
+            term: term.to_string(),
+            count: 1,
+            case_sensitive: false,
+            hex_mode: false,
+            pbkdf2_rounds: crate::wallet::DEFAULT_PBKDF2_ROUNDS,
+            ss58_prefix,
+            within: term.chars().count(),
+            within_window: None,
+            suffix: true,
+            anywhere: false,
+            threads: std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get),
+            progress_interval_ms: DEFAULT_PROGRESS_INTERVAL_MS,
+            watchdog_timeout_secs: DEFAULT_WATCHDOG_TIMEOUT_SECS,
+            stop: None,
+            rng_seed: None,
+        }
+    }
+
+    /// Whether `stop` has been set
+    pub(crate) fn stop_requested(&self) -> bool {
+        self.stop.as_ref().is_some_and(|stop| stop.load(Ordering::Relaxed))
+    }
+
+    /// Search configuration for these parameters
+    pub(crate) fn search_config(&self) -> OptimizedSearchConfig {
+        build_search_config(&self.term, self.case_sensitive, self.ss58_prefix, self.within, self.within_window, self.suffix, self.anywhere)
+    }
 }
 
 pub struct GenerationStats {
     pub total_attempts: u64,
     pub elapsed_secs: u64,
     pub elapsed_nanos: u128, // Store nanoseconds for maximum precision
+    pub wallets_per_second: f64,
+    pub average_attempts_per_wallet: u64,
+    pub min_attempts: u64, // Luckiest wallet's attempts; 0 if nothing was found
+    pub max_attempts: u64, // Unluckiest wallet's attempts; 0 if nothing was found
+}
+
+impl GenerationStats {
+    /// Totals for a finished run, with the per-wallet figures taken from `results`
+    pub fn new(results: &[VanityResult], total_attempts: u64, elapsed_secs: u64, elapsed_nanos: u128) -> Self {
+        let found_count = results.len();
+        #[allow(clippy::cast_precision_loss)]
+        let wallets_per_second = if elapsed_nanos > 0 {
+            found_count as f64 / (elapsed_nanos as f64 / 1_000_000_000.0)
+        } else {
+            0.0
+        };
+        let attempts = || results.iter().map(|result| result.attempts);
+
+        Self {
+            total_attempts,
+            elapsed_secs,
+            elapsed_nanos,
+            wallets_per_second,
+            average_attempts_per_wallet: total_attempts.checked_div(found_count as u64).unwrap_or(0),
+            min_attempts: attempts().min().unwrap_or(0),
+            max_attempts: attempts().max().unwrap_or(0),
+        }
+    }
+}
+
+pub struct ProgressInfo {
+    pub count: usize,
+    pub elapsed_secs: u64,
+    pub eta_next_wallet: Option<String>, // Time until the current wallet's expected attempts
+    pub eta_all_remaining: Option<String>, // Time until every remaining wallet is found (multi-wallet runs)
+    pub found_count: usize,
+    pub keys_per_second: u64,
+    pub luck: Option<f64>,
+    pub thread_keys_per_second: Vec<u64>, // Per worker thread since the last report; empty if untracked
+    pub total_attempts: u64,
+}
+
+/// Build the search configuration for a run; an explicit `--within-start`/`--within-end`
+/// window takes precedence over mode + within
+pub(crate) fn build_search_config(
+    target: &str,
+    case_sensitive: bool,
+    ss58_prefix: u16,
+    within: usize,
+    within_window: Option<(usize, usize)>,
+    suffix: bool,
+    anywhere: bool,
+) -> OptimizedSearchConfig {
+    match within_window {
+        Some((start, end)) => OptimizedSearchConfig::new_with_window(target, case_sensitive, start, end, ss58_prefix),
+        None => OptimizedSearchConfig::new_with_prefix(target, case_sensitive, suffix, anywhere, within, ss58_prefix),
+    }
+}
+
+/// Create the master RNG for a run; workers split their own generators from it
+///
+/// `seed` is [`SearchParams::rng_seed`]: `None` draws from system entropy.
+pub(crate) fn master_rng(seed: Option<[u8; 32]>) -> ChaCha20Rng {
+    match seed {
+        Some(seed) => ChaCha20Rng::from_seed_and_nonce(seed, WALLET_NONCE),
+        None => ChaCha20Rng::from_system_entropy_with_nonce(WALLET_NONCE).expect("Failed to initialize cryptographically secure RNG"),
+    }
+}
+
+/// Re-derive the address from `result.secret` and check it matches `result.address`
+///
+/// Mnemonics are derived with the standard 2048 PBKDF2 rounds; use
+/// [`verify_vanity_result_with_rounds`] for wallets found with `--pbkdf2-rounds`.
+pub fn verify_vanity_result(result: &VanityResult, hex_mode: bool) -> bool {
+    verify_vanity_result_with_rounds(result, hex_mode, crate::wallet::DEFAULT_PBKDF2_ROUNDS)
+}
+
+/// [`verify_vanity_result`] with a custom PBKDF2 round count for mnemonics
+///
+/// A secret that doesn't decode (bad hex, wrong length, invalid mnemonic) fails.
+pub fn verify_vanity_result_with_rounds(result: &VanityResult, hex_mode: bool, pbkdf2_rounds: u32) -> bool {
+    crate::validation::validate_wallet(&result.secret, &result.address, result.ss58_prefix, hex_mode, pbkdf2_rounds)
+        == crate::validation::ValidationResult::Valid
 }
 
 #[cfg(test)]
@@ -34,14 +259,7 @@ mod tests {
         let hex_secret = hex::encode(seed);
         let address = crate::wallet::hex_to_address_with_prefix(&seed, 6094);
 
-        let result = VanityResult {
-            address: address.clone(),
-            secret: hex_secret,
-            matches: 3,
-            offset: 2,
-            attempts: 1000,
-            ss58_prefix: 6094, // Autonomys Network
-        };
+        let result = VanityResult::for_test(&address, &hex_secret);
 
         // Should verify successfully
         assert!(verify_vanity_result(&result, true));
@@ -65,14 +283,7 @@ mod tests {
             .expect("Known valid mnemonic");
         let address = crate::crypto::mnemonic_to_address_with_prefix(&mnemonic, 6094);
 
-        let result = VanityResult {
-            address: address.clone(),
-            secret: mnemonic.to_string(),
-            matches: 3,
-            offset: 2,
-            attempts: 1000,
-            ss58_prefix: 6094, // Autonomys Network
-        };
+        let result = VanityResult::for_test(&address, &mnemonic.to_string());
 
         // Should verify successfully
         assert!(verify_vanity_result(&result, false));
@@ -90,14 +301,7 @@ mod tests {
 
     #[test]
     fn test_vanity_result_zeroize() {
-        let mut result = VanityResult {
-            address: "suTestAddress123".to_string(),
-            secret: "test secret key".to_string(),
-            matches: 3,
-            offset: 2,
-            attempts: 1000,
-            ss58_prefix: 6094, // Autonomys Network
-        };
+        let mut result = VanityResult::for_test("suTestAddress123", "test secret key");
 
         // Clone the secret to verify it gets zeroized
         let secret_copy = result.secret.clone();
@@ -111,18 +315,57 @@ mod tests {
 
         // Address should not be zeroized (has skip attribute)
         assert_eq!(result.address, "suTestAddress123");
+
+        // Full zeroize must also skip the network name
+        result.zeroize();
+        assert_eq!(result.network_name, "Autonomys");
+    }
+
+    #[test]
+    fn test_vanity_result_display_and_format_with() {
+        let mut result = VanityResult::for_test("suTestAddress123", "abandon ability able");
+        (result.attempts, result.expected_attempts) = (500, 1000);
+
+        assert_eq!(result.to_string(), "suTestAddress123");
+        assert_eq!(result.format_with("{address}\t{secret}"), "suTestAddress123\tabandon ability able");
+        assert_eq!(
+            result.format_with("{network} ({ss58_prefix}): {address} {attempts} {luck_pct}% {secret}"),
+            "Autonomys (6094): suTestAddress123 500 200% abandon ability able"
+        );
+        // Unknown tokens and plain text pass through; tokens may repeat
+        assert_eq!(result.format_with("{address}/{address} {adress} {}"), "suTestAddress123/suTestAddress123 {adress} {}");
+        assert_eq!(result.format_with(""), "");
+
+        // Zeroizing clears the secret but leaves the address
+        result.zeroize();
+        assert_eq!(result.to_string(), "suTestAddress123");
+        assert_eq!(result.format_with("{address}\t{secret}"), "suTestAddress123\t");
+        assert_eq!(result.format_with("[{secret}]"), "[]");
+
+        // No expected attempts recorded means no luck figure
+        result.expected_attempts = 0;
+        assert_eq!(result.format_with("{luck_pct}"), "0");
     }
 
     #[test]
     fn test_generation_stats() {
-        let stats = GenerationStats {
-            total_attempts: 1_000_000,
-            elapsed_secs: 60,
-            elapsed_nanos: 60_000_000_000,
-        };
+        let stats = GenerationStats::new(&[], 1_000_000, 60, 60_000_000_000);
 
         assert_eq!(stats.total_attempts, 1_000_000);
         assert_eq!(stats.elapsed_secs, 60);
         assert_eq!(stats.elapsed_nanos, 60_000_000_000);
+        assert_eq!(stats.wallets_per_second, 0.0);
+        assert_eq!((stats.average_attempts_per_wallet, stats.min_attempts, stats.max_attempts), (0, 0, 0));
+
+        let result = |attempts| {
+            let mut result = VanityResult::for_test("suTestAddress123", "secret");
+            result.attempts = attempts;
+            result
+        };
+        let results = [result(400_000), result(100_000), result(500_000)];
+        let stats = GenerationStats::new(&results, 1_000_000, 60, 60_000_000_000);
+        assert_eq!(stats.wallets_per_second, 0.05);
+        assert_eq!(stats.average_attempts_per_wallet, 333_333);
+        assert_eq!((stats.min_attempts, stats.max_attempts), (100_000, 500_000));
     }
 }
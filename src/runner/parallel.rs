@@ -1,55 +1,246 @@
+use crate::crypto::rng::ChaCha20Rng;
 use crate::wallet::{
-    generate_hex_seed, generate_mnemonic, seed_to_hex_string,
+    generate_hex_seed_with_rng, generate_mnemonic_with_rng, seed_to_hex_string,
 };
 use crate::cli::{
-    format_eta, format_eta_negative, calculate_luck_factor, calculate_expected_attempts,
+    format_eta, format_eta_negative, calculate_luck_factor, calculate_expected_attempts, expected_time_kth_wallet,
 };
-use crate::runner::{GenerationStats, VanityResult};
+use crate::runner::{master_rng, SearchParams, GenerationStats, VanityResult, WATCH_COUNT};
+pub use crate::runner::ProgressInfo;
 use crate::search::OptimizedSearchConfig;
 use crate::search::search_with_config;
-use crossbeam_channel::{bounded, Receiver, Sender};
+use crossbeam_channel::{bounded, unbounded, Receiver, RecvTimeoutError, Sender};
 use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::thread;
 use std::time::{Duration, Instant};
+use zeroize::Zeroize;
+
+/// Weight of a one-second throughput sample in the ETA moving average; shorter or
+/// longer samples are weighted to match
+const EMA_ALPHA: f64 = 0.1;
+
+/// Each worker traces one key-generation attempt in this many
+const TRACE_SAMPLE_INTERVAL: u64 = 1000;
+
+/// Debug builds: each worker logs a diagnostic line for one key in this many
+#[cfg(debug_assertions)]
+const VERBOSE_SAMPLE_INTERVAL: u64 = 100_000;
+
+/// Debug builds: how often the shared search state is logged
+#[cfg(debug_assertions)]
+const VERBOSE_STATE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Counts progress ticks on which no worker reported new attempts
+struct Watchdog {
+    threshold_ticks: u64,
+    stalled_ticks: u64,
+}
+
+impl Watchdog {
+    fn new(threshold_ticks: u64) -> Self {
+        Self { threshold_ticks, stalled_ticks: 0 }
+    }
+
+    /// Watchdog for a timeout in seconds, ticked every `interval`; a timeout that
+    /// isn't a whole number of ticks rounds up
+    fn with_timeout(timeout_secs: u64, interval: Duration) -> Self {
+        let interval_ms = (interval.as_millis() as u64).max(1);
+        Self::new((timeout_secs * 1000).div_ceil(interval_ms))
+    }
+
+    /// Compare `total_attempts` with the last tick's value; true on the tick a stall
+    /// crosses the threshold, so each stall is reported once
+    fn tick(&mut self, state: &SharedState, total_attempts: u64) -> bool {
+        if state.last_progress_attempts.swap(total_attempts, Ordering::Relaxed) != total_attempts {
+            self.stalled_ticks = 0;
+            return false;
+        }
+        self.stalled_ticks += 1;
+        self.threshold_ticks > 0 && self.stalled_ticks == self.threshold_ticks
+    }
+}
+
+/// Smoothed throughput for the ETA
+///
+/// The rate between two progress ticks swings with thermal throttling and background
+/// load, so the ETA divides by an exponential moving average of it rather than the
+/// raw rate. The first sample seeds the average so early ETAs aren't inflated.
+struct ProgressTracker {
+    ema_keys_per_second: f64,
+    samples: u64,
+    last_total: u64,
+    last_tick: Instant,
+}
+
+impl ProgressTracker {
+    fn new(start: Instant) -> Self {
+        Self {
+            ema_keys_per_second: 0.0,
+            samples: 0,
+            last_total: 0,
+            last_tick: start,
+        }
+    }
+
+    /// Record the attempts made since the last tick and return the updated average
+    fn update(&mut self, total_attempts: u64, now: Instant) -> f64 {
+        let elapsed = now.duration_since(self.last_tick).as_secs_f64();
+        if elapsed > 0.0 {
+            #[allow(clippy::cast_precision_loss)]
+            let current_kps = total_attempts.saturating_sub(self.last_total) as f64 / elapsed;
+            self.record_over(current_kps, elapsed);
+            self.last_total = total_attempts;
+            self.last_tick = now;
+        }
+        self.ema_keys_per_second
+    }
+
+    /// Record a one-second sample
+    #[cfg(test)]
+    fn record(&mut self, current_kps: f64) {
+        self.record_over(current_kps, 1.0);
+    }
+
+    /// Record a sample covering `secs` seconds; ten 100 ms samples move the average
+    /// as far as one 1 s sample, so smoothing doesn't depend on `--progress-interval`
+    fn record_over(&mut self, current_kps: f64, secs: f64) {
+        let alpha = 1.0 - (1.0 - EMA_ALPHA).powf(secs);
+        self.ema_keys_per_second = if self.samples == 0 {
+            current_kps
+        } else {
+            alpha * current_kps + (1.0 - alpha) * self.ema_keys_per_second
+        };
+        self.samples += 1;
+    }
+}
+
+/// Buckets in a [`RollingHistogram`]
+pub const HISTOGRAM_BUCKETS: usize = 10;
+
+/// Characters in the tallest [`RollingHistogram`] bar
+pub const HISTOGRAM_BAR_WIDTH: usize = 40;
+
+/// Attempts-per-wallet distribution of a run (`--histogram`)
+///
+/// [`HISTOGRAM_BUCKETS`] equal-width buckets span 0 to twice the expected attempts;
+/// wallets that took longer than that are counted in the last bucket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RollingHistogram {
+    bucket_width: u64,
+    buckets: [u64; HISTOGRAM_BUCKETS],
+}
+
+impl RollingHistogram {
+    pub fn new(expected_attempts: u64) -> Self {
+        let span = expected_attempts.saturating_mul(2).max(HISTOGRAM_BUCKETS as u64);
+        Self {
+            bucket_width: span.div_ceil(HISTOGRAM_BUCKETS as u64),
+            buckets: [0; HISTOGRAM_BUCKETS],
+        }
+    }
+
+    /// Count a wallet found after `attempts` attempts
+    pub fn record(&mut self, attempts: u64) {
+        let index = usize::try_from(attempts / self.bucket_width).map_or(HISTOGRAM_BUCKETS - 1, |i| i.min(HISTOGRAM_BUCKETS - 1));
+        self.buckets[index] += 1;
+    }
+
+    /// Wallets counted in each bucket
+    pub const fn buckets(&self) -> &[u64; HISTOGRAM_BUCKETS] {
+        &self.buckets
+    }
+
+    /// Attempts covered by bucket `index`, as a half-open range
+    pub const fn bucket_range(&self, index: usize) -> (u64, u64) {
+        (index as u64 * self.bucket_width, (index as u64 + 1) * self.bucket_width)
+    }
+
+    /// One bar of '█' per bucket, the fullest [`HISTOGRAM_BAR_WIDTH`] long; any
+    /// non-empty bucket gets at least one character
+    pub fn as_ascii_bars(&self) -> Vec<String> {
+        let tallest = self.buckets.iter().copied().max().unwrap_or(0).max(1);
+        self.buckets
+            .iter()
+            .map(|&count| {
+                let width = (count * HISTOGRAM_BAR_WIDTH as u64).div_ceil(tallest);
+                "█".repeat(usize::try_from(width).unwrap_or(HISTOGRAM_BAR_WIDTH))
+            })
+            .collect()
+    }
+}
 
 #[derive(Clone)]
 struct WorkerConfig {
+    expected_attempts: u64,
     hex_mode: bool,
+    pbkdf2_rounds: u32,
     search_config: OptimizedSearchConfig,
     ss58_prefix: u16,
     target: String,
 }
 
+impl WorkerConfig {
+    fn new(params: &SearchParams) -> Self {
+        let search_config = params.search_config();
+        Self {
+            // Mean-based expectation, for the ETA and each wallet's luck
+            expected_attempts: calculate_expected_attempts(&params.term, params.case_sensitive, search_config.search_range, params.ss58_prefix),
+            hex_mode: params.hex_mode,
+            pbkdf2_rounds: params.pbkdf2_rounds,
+            search_config,
+            ss58_prefix: params.ss58_prefix,
+            target: params.term.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
 struct SharedState {
     count: usize,
     found_count: AtomicUsize,
+    last_progress_attempts: AtomicU64, // total_attempts at the last progress tick, for the watchdog
     last_wallet_attempts: AtomicU64, // Track attempts since last wallet found
     should_stop: AtomicBool,
+    thread_attempts: Vec<AtomicU64>, // Per worker slot; a replacement worker takes slot id % thread count
+    thread_panics: AtomicUsize, // Worker threads that panicked and were replaced
     total_attempts: AtomicU64,
 }
 
-pub struct ProgressInfo {
-    pub count: usize,
-    pub elapsed_secs: u64,
-    pub eta: Option<String>,
-    pub found_count: usize,
-    pub keys_per_second: u64,
-    pub luck: Option<f64>,
-    pub total_attempts: u64,
+impl SharedState {
+    fn new(count: usize, thread_count: usize) -> Self {
+        Self {
+            count,
+            found_count: AtomicUsize::new(0),
+            last_progress_attempts: AtomicU64::new(0),
+            last_wallet_attempts: AtomicU64::new(0),
+            should_stop: AtomicBool::new(false),
+            thread_attempts: (0..thread_count).map(|_| AtomicU64::new(0)).collect(),
+            thread_panics: AtomicUsize::new(0),
+            total_attempts: AtomicU64::new(0),
+        }
+    }
+}
+
+/// Keys per second of each worker slot since the last call, updating `last_attempts`
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn thread_keys_per_second(state: &SharedState, last_attempts: &mut [u64], elapsed: Duration) -> Vec<u64> {
+    let secs = elapsed.as_secs_f64();
+    state
+        .thread_attempts
+        .iter()
+        .zip(last_attempts.iter_mut())
+        .map(|(attempts, last)| {
+            let attempts = attempts.load(Ordering::Relaxed);
+            let delta = attempts.saturating_sub(std::mem::replace(last, attempts));
+            if secs > 0.0 { (delta as f64 / secs) as u64 } else { 0 }
+        })
+        .collect()
 }
 
-#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+#[allow(clippy::too_many_lines)]
 pub fn generate_vanity_addresses<F, W>(
-    count: usize,
-    case_sensitive: bool,
-    hex_mode: bool,
-    ss58_prefix: u16,
-    within: usize,
-    suffix: bool,
-    anywhere: bool,
-    target: &str,
-    thread_count: usize,
+    params: &SearchParams,
     mut progress_callback: F,
     mut wallet_callback: W,
 ) -> (Vec<VanityResult>, GenerationStats)
@@ -59,75 +250,83 @@ where
 {
     let start_time = Instant::now();
 
-    // Calculate expected attempts using mean-based calculation
-    let expected_attempts = calculate_expected_attempts(target, case_sensitive, suffix, anywhere, within, ss58_prefix);
+    let SearchParams { count, threads: thread_count, .. } = *params;
+    let target = params.term.as_str();
 
     // Create shared configuration
-    let worker_config = WorkerConfig {
-        hex_mode,
-        search_config: OptimizedSearchConfig::new(target, case_sensitive, suffix, anywhere, within),
-        ss58_prefix,
-        target: target.to_string(),
-    };
+    let worker_config = WorkerConfig::new(params);
+    let expected_attempts = worker_config.expected_attempts;
+    let mut rng = master_rng(params.rng_seed);
+
+    // Watch mode emits every address, so any pattern (or none) is fine
+    let watch = count == WATCH_COUNT;
 
     // Validate search config
-    if !worker_config.search_config.is_valid() {
-        eprintln!("{}: Invalid search configuration: pattern '{}' cannot be found with current settings", 
-                 crate::cli::terminal::colors::red("ERROR"), target);
+    if !watch && !worker_config.search_config.is_valid() {
+        tracing::error!("Invalid search configuration: pattern '{target}' cannot be found with current settings");
         crate::cli::terminal::reset_terminal();
         std::process::exit(1);
     }
 
+    #[cfg(debug_assertions)]
+    tracing::debug!(search_config = ?worker_config.search_config, "search configuration");
+
     // Create shared state
-    let shared_state = Arc::new(SharedState {
-        count,
-        found_count: AtomicUsize::new(0),
-        last_wallet_attempts: AtomicU64::new(0),
-        should_stop: AtomicBool::new(false),
-        total_attempts: AtomicU64::new(0),
-    });
+    let shared_state = Arc::new(SharedState::new(count, thread_count));
 
     // Create channels for results
     let (tx, rx): (Sender<VanityResult>, Receiver<VanityResult>) = bounded(thread_count * 2);
 
-    // Spawn worker threads
-    let mut handles = Vec::with_capacity(thread_count);
-
-    for _ in 0..thread_count {
+    // Spawn worker threads, each with its own RNG split from the master; the same
+    // closure starts replacements for panicked workers
+    let next_worker_id = AtomicUsize::new(0);
+    let mut spawn_worker = || {
         let config = worker_config.clone();
+        let mut rng = rng.split();
         let state = shared_state.clone();
         let tx = tx.clone();
+        let worker_id = next_worker_id.fetch_add(1, Ordering::Relaxed);
 
-        let handle = thread::spawn(move || {
-            worker_thread(&config, &state, &tx);
-        });
-
-        handles.push(handle);
-    }
-
-    // Drop the original sender so the channel closes when all workers are done
-    drop(tx);
+        thread::spawn(move || {
+            worker_thread(&config, &mut rng, &state, &tx, worker_id);
+        })
+    };
+    let mut handles: Vec<_> = (0..thread_count).map(|_| spawn_worker()).collect();
 
     // Collect results and report progress
-    let mut results = Vec::with_capacity(count);
+    let mut results = Vec::with_capacity(if watch { 0 } else { count });
     let mut last_progress_report = Instant::now();
-    let report_interval = Duration::from_secs(1);
+    let mut last_thread_attempts = vec![0u64; thread_count];
+    let mut progress_tracker = ProgressTracker::new(start_time);
+    let report_interval = Duration::from_millis(params.progress_interval_ms.max(1));
+    let mut watchdog = Watchdog::with_timeout(params.watchdog_timeout_secs, report_interval);
+    #[cfg(debug_assertions)]
+    let mut last_state_snapshot = Instant::now();
 
     // Use a timeout on receive to check progress periodically
     loop {
+        // Stopped from outside (Ctrl+C): return what has been found so far
+        if params.stop_requested() {
+            shared_state.should_stop.store(true, Ordering::Relaxed);
+            break;
+        }
+
         match rx.recv_timeout(Duration::from_millis(100)) {
             Ok(result) => {
-                // Only process if we haven't reached our count yet
-                if results.len() < count {
-                    // Call wallet callback immediately when found
+                // Watch mode only streams addresses; keeping them would grow without bound
+                if watch {
+                    wallet_callback(&result);
+                } else if count == 0 || results.len() < count {
+                    // Only process if we haven't reached our count yet (0 means unlimited);
+                    // call wallet callback immediately when found
                     wallet_callback(&result);
                     
                     results.push(result);
 
                     // Note: last_wallet_attempts is now reset atomically in worker_thread using swap(0)
 
-                    // Check if we've found enough
-                    if results.len() >= count {
+                    // Check if we've found enough; an unlimited run only stops on Ctrl+C
+                    if count != 0 && results.len() >= count {
                         // Signal all workers to stop
                         shared_state.should_stop.store(true, Ordering::Relaxed);
                         break; // Exit immediately when we have enough
@@ -136,37 +335,66 @@ where
                 // Ignore extra results if we already have enough
             }
             Err(_) => {
-                // Check if all workers have finished
-                if handles.iter().all(std::thread::JoinHandle::is_finished) {
+                // Channel is drained; check if all workers have finished
+                if handles.is_empty() {
                     break;
                 }
             }
         }
 
-        // Report progress if needed
-        if last_progress_report.elapsed() >= report_interval {
+        // Replace any worker that panicked, giving up once most of them have
+        if let Err(e) = restart_panicked_workers(&mut handles, &shared_state, thread_count, &mut spawn_worker) {
+            shared_state.should_stop.store(true, Ordering::Relaxed);
+            println!(); // Move off the progress line
+            tracing::error!("{e}");
+            crate::cli::terminal::reset_terminal();
+            std::process::exit(1);
+        }
+
+        #[cfg(debug_assertions)]
+        if last_state_snapshot.elapsed() >= VERBOSE_STATE_INTERVAL {
+            tracing::debug!(state = ?shared_state, "shared state");
+            last_state_snapshot = Instant::now();
+        }
+
+        // Report progress if needed; watch mode has no target to estimate
+        if !watch && last_progress_report.elapsed() >= report_interval {
             let total = shared_state.total_attempts.load(Ordering::Relaxed);
             let elapsed = start_time.elapsed();
-            let keys_per_sec = if elapsed.as_secs() > 0 {
-                total / elapsed.as_secs()
+            // Fractional seconds: with a sub-second --progress-interval the first ticks
+            // come before a whole second has passed
+            #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let keys_per_sec = if elapsed.as_secs_f64() > 0.0 {
+                (total as f64 / elapsed.as_secs_f64()) as u64
             } else {
                 0
             };
+            let ema_keys_per_sec = progress_tracker.update(total, Instant::now()) as u64;
+
+            if watchdog.tick(&shared_state, total) {
+                let live_workers = handles.iter().filter(|handle| !handle.is_finished()).count();
+                // stderr keeps --quiet output machine-readable
+                eprintln!("\n{} (no new attempts for {}s)",
+                          crate::cli::terminal::colors::red("WARNING: generation appears stalled"),
+                          (report_interval.as_millis() as u64 * watchdog.stalled_ticks).div_ceil(1000));
+                tracing::warn!(live_workers, state = ?shared_state, "worker threads stalled");
+            }
 
             // Calculate ETA and luck
             let current_wallet_attempts = shared_state.last_wallet_attempts.load(Ordering::Relaxed);
             let found_count_val = shared_state.found_count.load(Ordering::Relaxed);
-            let (eta, luck) = if current_wallet_attempts > 0 && keys_per_sec > 0 {
+            let (eta, luck) = if current_wallet_attempts > 0 && ema_keys_per_sec > 0 {
                 // Calculate remaining attempts based on expected mean
                 let eta_str = if current_wallet_attempts >= expected_attempts {
                     // Past expected - show negative time (how long we've been over)
                     let over_attempts = current_wallet_attempts - expected_attempts;
-                    let over_seconds = over_attempts / keys_per_sec;
+                    let over_seconds = over_attempts / ema_keys_per_sec;
                     format_eta_negative(over_seconds)
                 } else {
                     // Still within expected range - show positive ETA
                     let remaining = expected_attempts - current_wallet_attempts;
-                    let eta_seconds = remaining / keys_per_sec;
+                    #[allow(clippy::cast_precision_loss)]
+                    let eta_seconds = remaining as f64 / ema_keys_per_sec as f64;
                     format_eta(eta_seconds)
                 };
 
@@ -183,17 +411,30 @@ where
                 (Some(eta_str), luck_val)
             } else {
                 // First wallet, show initial ETA
-                let eta_seconds = expected_attempts / keys_per_sec.max(1);
+                #[allow(clippy::cast_precision_loss)]
+                let eta_seconds = expected_attempts as f64 / ema_keys_per_sec.max(1) as f64;
                 (Some(format_eta(eta_seconds)), None)
             };
 
+            // k × E with no order-statistic correction: one search stream serves every
+            // wallet (see expected_time_kth_wallet)
+            let remaining_wallets = count.saturating_sub(found_count_val);
+            let eta_all = if remaining_wallets > 1 && ema_keys_per_sec > 0 {
+                let all_time = expected_time_kth_wallet(expected_attempts, remaining_wallets, ema_keys_per_sec);
+                Some(format_eta(all_time.as_secs_f64()))
+            } else {
+                None
+            };
+
             progress_callback(ProgressInfo {
                 count,
                 elapsed_secs: elapsed.as_secs(),
-                eta,
+                eta_next_wallet: eta,
+                eta_all_remaining: eta_all,
                 found_count: found_count_val,
                 keys_per_second: keys_per_sec,
                 luck,
+                thread_keys_per_second: thread_keys_per_second(&shared_state, &mut last_thread_attempts, last_progress_report.elapsed()),
                 total_attempts: total,
             });
 
@@ -201,9 +442,15 @@ where
         }
     }
 
-    // Wait for all workers to finish
+    // Stop feeding the channel from here, then wait for the remaining workers. Results
+    // sent meanwhile are dropped so no worker stays blocked on a full channel; a late
+    // panic no longer matters once the search is over
+    drop(tx);
+    while rx.recv().is_ok() {}
     for handle in handles {
-        handle.join().expect("Worker thread panicked");
+        if handle.join().is_err() {
+            shared_state.thread_panics.fetch_add(1, Ordering::Relaxed);
+        }
     }
 
     // Get final stats
@@ -213,9 +460,10 @@ where
     let elapsed_nanos = elapsed.as_nanos();
     
     // Final progress report (only if single wallet or didn't find all requested)
-    if count == 1 || results.len() < count {
-        let final_keys_per_sec = if elapsed_secs > 0 {
-            total_attempts / elapsed_secs
+    if !watch && (count == 1 || results.len() < count) {
+        #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let final_keys_per_sec = if elapsed.as_secs_f64() > 0.0 {
+            (total_attempts as f64 / elapsed.as_secs_f64()) as u64
         } else {
             0
         };
@@ -223,26 +471,159 @@ where
         progress_callback(ProgressInfo {
             count,
             elapsed_secs,
-            eta: None,
+            eta_next_wallet: None,
+            eta_all_remaining: None,
             found_count: results.len(),
             keys_per_second: final_keys_per_sec,
             luck: None,
+            thread_keys_per_second: thread_keys_per_second(&shared_state, &mut vec![0; thread_count], elapsed),
             total_attempts,
         });
     }
 
-    (
-        results,
-        GenerationStats {
-            total_attempts,
-            elapsed_secs,
-            elapsed_nanos,
-        },
-    )
+    let stats = GenerationStats::new(&results, total_attempts, elapsed_secs, elapsed_nanos);
+    (results, stats)
+}
+
+/// One search handed to every worker of a [`SearchPool`]
+struct PoolJob {
+    config: WorkerConfig,
+    state: Arc<SharedState>,
+    results: Sender<VanityResult>,
+}
+
+/// Worker threads and their RNGs kept alive across searches (`--script`)
+///
+/// [`generate_vanity_addresses`] starts its workers and master RNG for one run.
+/// A pool starts them once. Each worker keeps its split of the master RNG for the
+/// pool's lifetime, so later searches continue the same random streams instead of
+/// reseeding.
+pub struct SearchPool {
+    jobs: Vec<Sender<PoolJob>>,
+    handles: Vec<thread::JoinHandle<()>>,
+}
+
+impl SearchPool {
+    /// Start `threads` idle workers; `rng_seed` works like [`SearchParams::rng_seed`]
+    pub fn new(threads: usize, rng_seed: Option<[u8; 32]>) -> Self {
+        let mut master = master_rng(rng_seed);
+        let (jobs, handles) = (0..threads.max(1))
+            .map(|worker_id| {
+                let (job_tx, job_rx) = unbounded::<PoolJob>();
+                let mut rng = master.split();
+                let handle = thread::spawn(move || {
+                    for job in job_rx {
+                        worker_thread(&job.config, &mut rng, &job.state, &job.results, worker_id);
+                    }
+                });
+                (job_tx, handle)
+            })
+            .unzip();
+        Self { jobs, handles }
+    }
+
+    /// Worker threads in the pool
+    pub fn threads(&self) -> usize {
+        self.jobs.len()
+    }
+
+    /// Run one search on the pool's workers
+    ///
+    /// Works like [`generate_vanity_addresses`] without progress reports;
+    /// `params.threads` and `params.rng_seed` are ignored in favour of the pool's.
+    /// Returns an error instead of exiting when the pattern can't be found or a
+    /// worker has died.
+    pub fn search<W>(&self, params: &SearchParams, mut wallet_callback: W) -> Result<(Vec<VanityResult>, GenerationStats), String>
+    where
+        W: FnMut(&VanityResult),
+    {
+        let start_time = Instant::now();
+        let count = params.count;
+        let config = WorkerConfig::new(params);
+        if count != WATCH_COUNT && !config.search_config.is_valid() {
+            return Err(format!("Pattern '{}' cannot be found with current settings", params.term));
+        }
+
+        let state = Arc::new(SharedState::new(count, self.threads()));
+        let (tx, rx) = bounded(self.threads() * 2);
+        for job in &self.jobs {
+            job.send(PoolJob { config: config.clone(), state: state.clone(), results: tx.clone() })
+                .map_err(|_| "A search worker thread has exited".to_string())?;
+        }
+        // Each worker drops its sender when it finishes the job, disconnecting the channel
+        drop(tx);
+
+        let mut results = Vec::new();
+        while !params.stop_requested() && (count == 0 || results.len() < count) {
+            match rx.recv_timeout(Duration::from_millis(100)) {
+                Ok(result) => {
+                    wallet_callback(&result);
+                    if count != WATCH_COUNT {
+                        results.push(result);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+
+        // Wait until every worker is idle again before the next search
+        state.should_stop.store(true, Ordering::Relaxed);
+        while rx.recv().is_ok() {}
+
+        let elapsed = start_time.elapsed();
+        let total_attempts = state.total_attempts.load(Ordering::Relaxed);
+        let stats = GenerationStats::new(&results, total_attempts, elapsed.as_secs(), elapsed.as_nanos());
+        Ok((results, stats))
+    }
+}
+
+impl Drop for SearchPool {
+    fn drop(&mut self) {
+        // Closing the job channels ends each worker's loop
+        self.jobs.clear();
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Join finished workers and start a replacement for each one that panicked
+///
+/// Finished handles are removed, so an empty list means every worker exited. Returns
+/// an error once more than half of `thread_count` workers have panicked, since a fault
+/// that keeps recurring is not an edge case worth retrying.
+fn restart_panicked_workers(
+    handles: &mut Vec<thread::JoinHandle<()>>,
+    state: &SharedState,
+    thread_count: usize,
+    mut spawn_worker: impl FnMut() -> thread::JoinHandle<()>,
+) -> Result<(), String> {
+    let (finished, running): (Vec<_>, Vec<_>) = std::mem::take(handles)
+        .into_iter()
+        .partition(thread::JoinHandle::is_finished);
+    *handles = running;
+
+    for handle in finished {
+        if handle.join().is_ok() {
+            continue;
+        }
+
+        let panics = state.thread_panics.fetch_add(1, Ordering::Relaxed) + 1;
+        if panics * 2 > thread_count {
+            return Err(format!("{panics} of {thread_count} worker threads panicked, aborting search"));
+        }
+        tracing::warn!("Worker thread panicked, starting a replacement ({panics} so far)");
+        handles.push(spawn_worker());
+    }
+
+    Ok(())
 }
 
-fn worker_thread(config: &WorkerConfig, state: &Arc<SharedState>, tx: &Sender<VanityResult>) {
+fn worker_thread(config: &WorkerConfig, rng: &mut ChaCha20Rng, state: &SharedState, tx: &Sender<VanityResult>, worker_id: usize) {
     let target_len = config.target.len();
+    // Watch mode emits every address
+    let watch = state.count == WATCH_COUNT;
     let mut local_attempts = 0u64;
     const HEX_MODE_REPORT_INTERVAL: u64 = 1000;
     const MNEMONIC_MODE_REPORT_INTERVAL: u64 = 100;
@@ -251,6 +632,10 @@ fn worker_thread(config: &WorkerConfig, state: &Arc<SharedState>, tx: &Sender<Va
     } else {
         MNEMONIC_MODE_REPORT_INTERVAL
     };
+    let thread_attempts = &state.thread_attempts[worker_id % state.thread_attempts.len()];
+    // Derived (address, mnemonic) pairs waiting to be searched
+    let mut pending: Vec<(String, String)> = Vec::with_capacity(4);
+    let mut attempt = 0u64;
 
     loop {
         // Check if we should stop
@@ -258,30 +643,48 @@ fn worker_thread(config: &WorkerConfig, state: &Arc<SharedState>, tx: &Sender<Va
             break;
         }
 
-        // Check if we've already found enough
-        if state.found_count.load(Ordering::Relaxed) >= state.count {
+        // Check if we've already found enough (a count of 0 never is)
+        let found_count = state.found_count.load(Ordering::Relaxed);
+        if !(state.count == 0 || found_count < state.count) {
             break;
         }
 
+        // Trace a sample of attempts; only the address and match count are recorded,
+        // never the secret
+        attempt += 1;
+        let span = attempt.is_multiple_of(TRACE_SAMPLE_INTERVAL)
+            .then(|| tracing::debug_span!("key_attempt", worker = worker_id, attempt).entered());
+
         // Generate address
         let (address, secret) = if config.hex_mode {
-            let seed = generate_hex_seed();
+            let seed = generate_hex_seed_with_rng(rng);
             let address = crate::wallet::hex_to_address_with_prefix(&seed, config.ss58_prefix);
             let hex_string = seed_to_hex_string(&seed);
             (address, hex_string)
         } else {
-            let mnemonic = generate_mnemonic();
-            let address = crate::crypto::mnemonic_to_address_with_prefix(&mnemonic, config.ss58_prefix);
-            (address, mnemonic.to_string())
+            if pending.is_empty() {
+                refill_pending_mnemonics(config, rng, &mut pending);
+            }
+            pending.pop().expect("pending mnemonics were just refilled")
         };
 
         // Perform optimized search
         let result = search_with_config(&config.search_config, &address);
+        if span.is_some() {
+            tracing::debug!(%address, matches = result.count, "sampled attempt");
+        }
+        #[cfg(debug_assertions)]
+        if attempt.is_multiple_of(VERBOSE_SAMPLE_INTERVAL) {
+            tracing::debug!(
+                "Thread {worker_id} | Attempt {attempt} | Address {address} | Pattern match: {}/{target_len}",
+                result.count
+            );
+        }
 
         local_attempts += 1;
 
         // Check for match
-        if result.count == target_len {
+        if watch || result.count == target_len {
             // Get the attempts since the last wallet was found
             let attempts_since_last = state
                 .last_wallet_attempts
@@ -292,16 +695,23 @@ fn worker_thread(config: &WorkerConfig, state: &Arc<SharedState>, tx: &Sender<Va
             let vanity_result = VanityResult {
                 address,
                 secret,
-                matches: result.count,
+                matches: if watch { 0 } else { result.count },
                 offset: result.offset,
                 attempts: attempts_since_last, // Individual wallet attempts
+                expected_attempts: config.expected_attempts,
                 ss58_prefix: config.ss58_prefix,
+                network_name: crate::networks::find_network_by_prefix(config.ss58_prefix)
+                    .map(|n| n.name)
+                    .unwrap_or("Custom")
+                    .to_string(),
+                match_detail: result.match_detail,
             };
 
             // Update total attempts before resetting local counter
             state
                 .total_attempts
                 .fetch_add(local_attempts, Ordering::Relaxed);
+            thread_attempts.fetch_add(local_attempts, Ordering::Relaxed);
             
             // Update found count
             state.found_count.fetch_add(1, Ordering::Relaxed);
@@ -318,6 +728,7 @@ fn worker_thread(config: &WorkerConfig, state: &Arc<SharedState>, tx: &Sender<Va
             state
                 .total_attempts
                 .fetch_add(local_attempts, Ordering::Relaxed);
+            thread_attempts.fetch_add(local_attempts, Ordering::Relaxed);
             state
                 .last_wallet_attempts
                 .fetch_add(local_attempts, Ordering::Relaxed);
@@ -325,13 +736,182 @@ fn worker_thread(config: &WorkerConfig, state: &Arc<SharedState>, tx: &Sender<Va
         }
     }
 
+    // Mnemonics derived but never searched
+    for (_, mut secret) in pending {
+        secret.zeroize();
+    }
+
     // Add any remaining attempts
     if local_attempts > 0 {
         state
             .total_attempts
             .fetch_add(local_attempts, Ordering::Relaxed);
+        thread_attempts.fetch_add(local_attempts, Ordering::Relaxed);
         state
             .last_wallet_attempts
             .fetch_add(local_attempts, Ordering::Relaxed);
     }
 }
+
+/// Derive the next mnemonic addresses, four at a time through the AVX2 batched
+/// PBKDF2 when available and one at a time through the scalar path otherwise
+fn refill_pending_mnemonics(config: &WorkerConfig, rng: &mut ChaCha20Rng, pending: &mut Vec<(String, String)>) {
+    #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+    {
+        let mnemonics: [_; 4] = std::array::from_fn(|_| generate_mnemonic_with_rng(rng));
+        let addresses = crate::crypto::mnemonics_to_addresses_batch_4(&mnemonics, config.ss58_prefix, config.pbkdf2_rounds);
+        pending.extend(addresses.into_iter().zip(mnemonics.iter().map(|m| m.to_string())));
+    }
+
+    #[cfg(not(all(target_arch = "x86_64", target_feature = "avx2")))]
+    {
+        let mnemonic = generate_mnemonic_with_rng(rng);
+        let address = crate::crypto::mnemonic_to_address_with_rounds(&mnemonic, config.ss58_prefix, config.pbkdf2_rounds);
+        pending.push((address, mnemonic.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_state() -> SharedState {
+        SharedState::new(1, 1)
+    }
+
+    /// Spawn a worker stand-in that panics inside the thread, like a key generation bug
+    fn panicking_worker() -> thread::JoinHandle<()> {
+        thread::spawn(|| {
+            let outcome = std::panic::catch_unwind(|| panic!("simulated worker panic"));
+            assert!(outcome.is_err());
+            std::panic::resume_unwind(outcome.unwrap_err());
+        })
+    }
+
+    fn wait_until_finished(handles: &[thread::JoinHandle<()>]) {
+        while !handles.iter().all(thread::JoinHandle::is_finished) {
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn test_panicked_worker_is_replaced() {
+        let state = test_state();
+        let mut handles = vec![panicking_worker(), thread::spawn(|| {}), thread::spawn(|| {}), thread::spawn(|| {})];
+        wait_until_finished(&handles);
+
+        let mut replacements = 0;
+        let outcome = restart_panicked_workers(&mut handles, &state, 4, || {
+            replacements += 1;
+            thread::spawn(|| {})
+        });
+
+        assert!(outcome.is_ok());
+        assert_eq!(replacements, 1);
+        assert_eq!(handles.len(), 1); // Cleanly finished workers are dropped
+        assert_eq!(state.thread_panics.load(Ordering::Relaxed), 1);
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_too_many_worker_panics_abort() {
+        let state = test_state();
+        let mut handles = vec![panicking_worker(), panicking_worker(), panicking_worker(), thread::spawn(|| {})];
+        wait_until_finished(&handles);
+
+        let outcome = restart_panicked_workers(&mut handles, &state, 4, || thread::spawn(|| {}));
+        assert!(outcome.is_err());
+        assert_eq!(state.thread_panics.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn test_watchdog_reports_stall_once() {
+        let state = test_state();
+        let mut watchdog = Watchdog::new(3);
+
+        assert!(!watchdog.tick(&state, 100));
+        assert!(!watchdog.tick(&state, 100));
+        assert!(!watchdog.tick(&state, 100));
+        assert!(watchdog.tick(&state, 100)); // Third tick without progress
+        assert!(!watchdog.tick(&state, 100));
+
+        // Progress resets the count, so a later stall is reported again
+        assert!(!watchdog.tick(&state, 200));
+        assert!((0..2).all(|_| !watchdog.tick(&state, 200)));
+        assert!(watchdog.tick(&state, 200));
+
+        let mut disabled = Watchdog::new(0);
+        assert!((0..20).all(|_| !disabled.tick(&state, 0)));
+
+        // The timeout is in seconds whatever the tick interval
+        assert_eq!(Watchdog::with_timeout(10, Duration::from_secs(1)).threshold_ticks, 10);
+        assert_eq!(Watchdog::with_timeout(10, Duration::from_millis(250)).threshold_ticks, 40);
+        assert_eq!(Watchdog::with_timeout(10, Duration::from_secs(60)).threshold_ticks, 1);
+        assert_eq!(Watchdog::with_timeout(0, Duration::from_millis(100)).threshold_ticks, 0);
+    }
+
+    #[test]
+    fn test_rolling_histogram() {
+        let mut histogram = RollingHistogram::new(1000);
+        assert_eq!(histogram.bucket_range(0), (0, 200));
+        assert_eq!(histogram.bucket_range(9), (1800, 2000));
+        assert!(histogram.as_ascii_bars().iter().all(String::is_empty));
+
+        for attempts in [0, 199, 200, 250, 1999, 2000, 50_000, 390] {
+            histogram.record(attempts);
+        }
+        assert_eq!(histogram.buckets(), &[2, 3, 0, 0, 0, 0, 0, 0, 0, 3]);
+
+        let bars = histogram.as_ascii_bars();
+        assert_eq!(bars.len(), HISTOGRAM_BUCKETS);
+        assert_eq!(bars[1].chars().count(), HISTOGRAM_BAR_WIDTH);
+        assert_eq!(bars[0].chars().count(), (2 * HISTOGRAM_BAR_WIDTH).div_ceil(3));
+        assert!(bars[2].is_empty());
+
+        // Tiny expectations still get non-empty buckets
+        let mut easy = RollingHistogram::new(1);
+        easy.record(0);
+        easy.record(5);
+        assert_eq!(easy.buckets()[0], 1);
+        assert_eq!(easy.buckets()[5], 1);
+    }
+
+    #[test]
+    fn test_progress_tracker_ema() {
+        let start = Instant::now();
+        let mut tracker = ProgressTracker::new(start);
+
+        // The first tick seeds the average
+        assert_eq!(tracker.update(1000, start + Duration::from_secs(1)), 1000.0);
+
+        // A throttled second halves the raw rate but only nudges the average
+        let throttled = tracker.update(1500, start + Duration::from_secs(2));
+        assert!((throttled - 950.0).abs() < 1e-9);
+
+        // Alternating fast and slow ticks stay within the bounds without jumping to them
+        let mut previous = throttled;
+        for (i, kps) in [2000.0, 500.0, 2000.0, 500.0].into_iter().enumerate() {
+            tracker.record(kps);
+            assert!(tracker.ema_keys_per_second > 500.0 && tracker.ema_keys_per_second < 2000.0, "tick {i}");
+            assert!((tracker.ema_keys_per_second - previous).abs() <= EMA_ALPHA * 1500.0 + 1e-9);
+            previous = tracker.ema_keys_per_second;
+        }
+
+        // A steady rate converges monotonically
+        for _ in 0..100 {
+            tracker.record(3000.0);
+            assert!(tracker.ema_keys_per_second >= previous);
+            previous = tracker.ema_keys_per_second;
+        }
+        assert!((tracker.ema_keys_per_second - 3000.0).abs() < 1.0);
+
+        // Two half-second samples move the average as far as one one-second sample
+        let mut fast = ProgressTracker::new(start);
+        fast.record(1000.0);
+        fast.record_over(500.0, 0.5);
+        fast.record_over(500.0, 0.5);
+        assert!((fast.ema_keys_per_second - 950.0).abs() < 1e-9);
+    }
+}
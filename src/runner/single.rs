@@ -0,0 +1,105 @@
+//! Single-threaded search for targets without `std::thread` (wasm32)
+//!
+//! A browser gives a wasm module one thread and no shared atomics, so this runs the
+//! same generate-and-match loop as the parallel runner inline on the caller's thread.
+//! `std::time::Instant` isn't available there either, so progress reports carry
+//! attempt counts only: no elapsed time, throughput or ETA.
+
+use crate::runner::{master_rng, GenerationStats, ProgressInfo, SearchParams, VanityResult, WATCH_COUNT};
+use crate::search::search_with_config;
+use crate::wallet::{generate_hex_seed_with_rng, generate_mnemonic_with_rng, seed_to_hex_string};
+
+/// Attempts between progress reports
+const PROGRESS_INTERVAL: u64 = 1000;
+
+/// Single-threaded counterpart of the parallel `generate_vanity_addresses`
+///
+/// Takes the same arguments so callers build unchanged; `params.threads` is ignored.
+/// An impossible pattern returns no results instead of exiting the process.
+pub fn generate_vanity_addresses<F, W>(
+    params: &SearchParams,
+    mut progress_callback: F,
+    mut wallet_callback: W,
+) -> (Vec<VanityResult>, GenerationStats)
+where
+    F: FnMut(ProgressInfo),
+    W: FnMut(&VanityResult),
+{
+    let SearchParams { count, case_sensitive, hex_mode, pbkdf2_rounds, ss58_prefix, .. } = *params;
+    let target = params.term.as_str();
+    let search_config = params.search_config();
+    let mut results = Vec::new();
+    let mut total_attempts = 0u64;
+
+    // Watch mode emits every address, so any pattern (or none) is fine. There is no
+    // sleep in a browser, so unlike the parallel runner it isn't rate-limited.
+    let watch = count == WATCH_COUNT;
+
+    if watch || search_config.is_valid() {
+        let expected_attempts = crate::cli::probability::calculate_expected_attempts(
+            target,
+            case_sensitive,
+            search_config.search_range,
+            ss58_prefix,
+        );
+        let network_name = crate::networks::find_network_by_prefix(ss58_prefix)
+            .map(|n| n.name)
+            .unwrap_or("Custom");
+        let mut wallet_attempts = 0u64;
+        let mut rng = master_rng(params.rng_seed);
+
+        // A count of 0 searches until `params.stop` is set
+        while (count == 0 || results.len() < count) && !params.stop_requested() {
+            let (address, secret) = if hex_mode {
+                let seed = generate_hex_seed_with_rng(&mut rng);
+                (crate::wallet::hex_to_address_with_prefix(&seed, ss58_prefix), seed_to_hex_string(&seed))
+            } else {
+                let mnemonic = generate_mnemonic_with_rng(&mut rng);
+                let address = crate::crypto::mnemonic_to_address_with_rounds(&mnemonic, ss58_prefix, pbkdf2_rounds);
+                (address, mnemonic.to_string())
+            };
+
+            let result = search_with_config(&search_config, &address);
+            total_attempts += 1;
+            wallet_attempts += 1;
+
+            if watch || result.count == target.len() {
+                let vanity_result = VanityResult {
+                    address,
+                    secret,
+                    matches: if watch { 0 } else { result.count },
+                    offset: result.offset,
+                    attempts: wallet_attempts,
+                    expected_attempts,
+                    ss58_prefix,
+                    network_name: network_name.to_string(),
+                    match_detail: result.match_detail,
+                };
+                wallet_callback(&vanity_result);
+                // Watch mode only streams addresses; keeping them would grow without bound
+                if !watch {
+                    results.push(vanity_result);
+                }
+                wallet_attempts = 0;
+            }
+
+            // Watch mode has no target to report progress towards
+            if !watch && total_attempts.is_multiple_of(PROGRESS_INTERVAL) {
+                progress_callback(ProgressInfo {
+                    count,
+                    elapsed_secs: 0,
+                    eta_next_wallet: None,
+                    eta_all_remaining: None,
+                    found_count: results.len(),
+                    keys_per_second: 0,
+                    luck: None,
+                    thread_keys_per_second: Vec::new(),
+                    total_attempts,
+                });
+            }
+        }
+    }
+
+    let stats = GenerationStats::new(&results, total_attempts, 0, 0);
+    (results, stats)
+}
@@ -1,50 +1,86 @@
-/// Standard Substrate address length in characters
-const SS58_ADDRESS_LENGTH: usize = 49;
+use crate::validation::ss58_address_length_for_prefix;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Network assumed by `OptimizedSearchConfig::new` (Autonomys)
+const DEFAULT_SS58_PREFIX: u16 = 6094;
+
+/// Shortest pattern [`OptimizedSearchConfig::with_dfa`] compiles into a [`CompiledDfa`];
+/// `new_with_prefix` uses it for anywhere searches of at least this length
+pub const DFA_MIN_PATTERN_LEN: usize = 4;
 
 /// Pre-computed search configuration for optimal performance
 /// This struct is immutable and can be safely shared across threads
-#[derive(Debug, Clone)]
+///
+/// The pattern and its first character are wiped when the config is dropped.
+#[derive(Debug, Clone, Zeroize, ZeroizeOnDrop)]
 pub struct OptimizedSearchConfig {
     /// Original search pattern
     pub pattern: String,
     /// Pattern length in characters (pre-computed)
+    #[zeroize(skip)]
     pub pattern_len: usize,
     /// Whether search is case-sensitive
+    #[zeroize(skip)]
     pub case_sensitive: bool,
     /// Pre-computed valid search range (start, end inclusive) for both modes
+    #[zeroize(skip)]
     pub search_range: Option<(usize, usize)>,
+    /// Lowercase byte of the pattern's first character; `?` when it is a wildcard
+    pub first_char_lower: u8,
+    /// Uppercase byte of the pattern's first character (the same byte for digits or
+    /// case-sensitive searches)
+    pub first_char_upper: u8,
+    /// Bit-parallel matcher; `None` (the sliding window) unless [`with_dfa`](Self::with_dfa) is used,
+    /// as `new_with_prefix` does for anywhere searches
+    #[zeroize(skip)]
+    pub dfa: Option<Box<CompiledDfa>>,
 }
 
 impl OptimizedSearchConfig {
     /// Create a new optimized search configuration  
     /// Pre-computes all values that would otherwise be calculated per-address
+    ///
+    /// Assumes Autonomys-length addresses; use `new_with_prefix` for other networks.
     pub fn new(pattern: &str, case_sensitive: bool, suffix: bool, anywhere: bool, within: usize) -> Self {
+        let address_len = ss58_address_length_for_prefix(DEFAULT_SS58_PREFIX);
+        Self::for_address_length(pattern, case_sensitive, suffix, anywhere, within, address_len)
+    }
+
+    /// Compute the search range for addresses of `address_len` characters
+    fn for_address_length(
+        pattern: &str,
+        case_sensitive: bool,
+        suffix: bool,
+        anywhere: bool,
+        within: usize,
+        address_len: usize,
+    ) -> Self {
         let pattern_len = pattern.chars().count();
 
         // Pre-compute search positions based on mode
-        let search_range = if pattern_len > SS58_ADDRESS_LENGTH {
+        let search_range = if pattern_len > address_len {
             None // Pattern longer than address
         } else if anywhere {
             // For anywhere mode: pattern can appear anywhere in the address
-            if within >= SS58_ADDRESS_LENGTH || within >= pattern_len {
+            if within >= address_len || within >= pattern_len {
                 // Search entire valid range
                 // Note: We don't filter by network prefix here because we don't know
                 // the ss58_prefix at this point. The search will still work but may
                 // check some impossible positions. This is OK for correctness.
-                Some((0, SS58_ADDRESS_LENGTH - pattern_len))
+                Some((0, address_len - pattern_len))
             } else {
                 None // Within constraint too restrictive for pattern length
             }
         } else if suffix {
             // For suffix mode: pattern must appear within the last `within` characters
-            if within >= SS58_ADDRESS_LENGTH {
+            if within >= address_len {
                 // Search window covers entire address, so pattern can be anywhere
-                Some((0, SS58_ADDRESS_LENGTH - pattern_len))
+                Some((0, address_len - pattern_len))
             } else {
                 // Pattern must end within the last `within` characters
                 // So it can start from (length - within) to (length - pattern_len)
-                let earliest_start = SS58_ADDRESS_LENGTH.saturating_sub(within);
-                let latest_start = SS58_ADDRESS_LENGTH - pattern_len;
+                let earliest_start = address_len.saturating_sub(within);
+                let latest_start = address_len - pattern_len;
                 if earliest_start <= latest_start {
                     Some((earliest_start, latest_start))
                 } else {
@@ -61,14 +97,97 @@ impl OptimizedSearchConfig {
             }
         };
 
+        let (first_char_lower, first_char_upper) = first_char_bytes(pattern, case_sensitive);
         Self {
             pattern: pattern.to_string(),
             pattern_len,
             case_sensitive,
             search_range,
+            first_char_lower,
+            first_char_upper,
+            dfa: None,
         }
     }
 
+    /// Search with a [`CompiledDfa`] instead of the sliding window
+    ///
+    /// Patterns shorter than [`DFA_MIN_PATTERN_LEN`] or longer than 64 characters keep
+    /// the sliding window. `cargo bench -- anywhere_6_chars` compares the two.
+    pub fn with_dfa(mut self) -> Self {
+        self.dfa = (self.pattern_len >= DFA_MIN_PATTERN_LEN)
+            .then(|| CompiledDfa::new(&self.pattern, self.case_sensitive))
+            .flatten()
+            .map(Box::new);
+        self
+    }
+
+    /// Create a search configuration that skips positions locked by the network prefix
+    ///
+    /// Leading positions where the pattern conflicts with the fixed SS58 prefix
+    /// characters (e.g. "su" for Autonomys) can never match, so the range starts
+    /// at the first position compatible with the prefix. Anywhere searches of
+    /// [`DFA_MIN_PATTERN_LEN`] or more characters use the [`CompiledDfa`].
+    pub fn new_with_prefix(
+        pattern: &str,
+        case_sensitive: bool,
+        suffix: bool,
+        anywhere: bool,
+        within: usize,
+        ss58_prefix: u16,
+    ) -> Self {
+        let address_len = ss58_address_length_for_prefix(ss58_prefix);
+        let config = Self::for_address_length(pattern, case_sensitive, suffix, anywhere, within, address_len)
+            .skip_prefix_locked(ss58_prefix);
+        if anywhere { config.with_dfa() } else { config }
+    }
+
+    /// Create a search configuration for an explicit character window
+    /// (`--within-start`/`--within-end`)
+    ///
+    /// The pattern must lie entirely within characters `within_start..within_end`
+    /// (end exclusive), so it can start from `within_start` to `within_end - pattern_len`.
+    pub fn new_with_window(
+        pattern: &str,
+        case_sensitive: bool,
+        within_start: usize,
+        within_end: usize,
+        ss58_prefix: u16,
+    ) -> Self {
+        let pattern_len = pattern.chars().count();
+
+        let address_len = ss58_address_length_for_prefix(ss58_prefix);
+
+        let search_range = if within_end <= address_len && within_start + pattern_len <= within_end {
+            Some((within_start, within_end - pattern_len))
+        } else {
+            None // Window out of bounds or too narrow for the pattern
+        };
+
+        let (first_char_lower, first_char_upper) = first_char_bytes(pattern, case_sensitive);
+        Self {
+            pattern: pattern.to_string(),
+            pattern_len,
+            case_sensitive,
+            search_range,
+            first_char_lower,
+            first_char_upper,
+            dfa: None,
+        }
+        .skip_prefix_locked(ss58_prefix)
+    }
+
+    /// Advance the range start past positions that conflict with the network prefix
+    fn skip_prefix_locked(mut self, ss58_prefix: u16) -> Self {
+        if let Some((start, end)) = self.search_range {
+            let first_fit = (start..=end).find(|&pos| {
+                crate::cli::probability::pattern_fits_network_prefix(&self.pattern, pos, self.case_sensitive, ss58_prefix)
+            });
+            self.search_range = first_fit.map(|first| (first, end));
+        }
+
+        self
+    }
+
     /// Check if this configuration can possibly find matches
     #[inline]
     pub const fn is_valid(&self) -> bool {
@@ -82,10 +201,105 @@ impl OptimizedSearchConfig {
     }
 }
 
+/// Bytes the first address character at a candidate position may take, as
+/// `(lower, upper)`; a leading `?` (or empty pattern) gives `(b'?', b'?')`
+fn first_char_bytes(pattern: &str, case_sensitive: bool) -> (u8, u8) {
+    match pattern.as_bytes().first() {
+        None => (b'?', b'?'),
+        Some(&first) if case_sensitive => (first, first),
+        Some(&first) => (first.to_ascii_lowercase(), first.to_ascii_uppercase()),
+    }
+}
+
+/// Shift-and (bitap) matcher: one pass over the address, whatever the pattern length
+///
+/// Bit `i` of the state is set while the last `i + 1` address bytes match the first
+/// `i + 1` pattern bytes, so a pattern of up to 64 bytes fits in a `u64`.
+#[derive(Clone)]
+pub struct CompiledDfa {
+    /// For each address byte, the pattern positions it can stand at
+    masks: [u64; 256],
+    /// Bit that marks a full match
+    accept: u64,
+    /// Pattern length in bytes
+    len: usize,
+    /// Both cases of the first pattern byte, or `None` for a leading `?`
+    first_bytes: Option<(u8, u8)>,
+}
+
+impl CompiledDfa {
+    /// Compile `pattern`; `None` when it is empty or longer than 64 bytes
+    pub fn new(pattern: &str, case_sensitive: bool) -> Option<Self> {
+        let bytes = pattern.as_bytes();
+        if bytes.is_empty() || bytes.len() > 64 {
+            return None;
+        }
+
+        let mut masks = [0u64; 256];
+        for (i, &p_byte) in bytes.iter().enumerate() {
+            let bit = 1u64 << i;
+            if p_byte == b'?' {
+                masks.iter_mut().for_each(|mask| *mask |= bit);
+            } else if case_sensitive {
+                masks[p_byte as usize] |= bit;
+            } else {
+                masks[p_byte.to_ascii_lowercase() as usize] |= bit;
+                masks[p_byte.to_ascii_uppercase() as usize] |= bit;
+            }
+        }
+
+        let first_bytes = match first_char_bytes(pattern, case_sensitive) {
+            (b'?', _) => None,
+            first => Some(first),
+        };
+        Some(Self { masks, accept: 1 << (bytes.len() - 1), len: bytes.len(), first_bytes })
+    }
+
+    /// Offset of the first match starting within `start..=end`
+    pub fn find(&self, address: &[u8], start: usize, end: usize) -> Option<usize> {
+        // Nothing can match before the first byte that can start the pattern
+        let start = match self.first_bytes {
+            Some((lower, upper)) => {
+                let window = address.get(start..=end.min(address.len().checked_sub(1)?))?;
+                start + memchr::memchr2(lower, upper, window)?
+            }
+            None => start,
+        };
+        let scan_end = address.len().min(end.saturating_add(self.len));
+        let mut state = 0u64;
+        for (i, &byte) in address.get(start..scan_end)?.iter().enumerate() {
+            state = ((state << 1) | 1) & self.masks[byte as usize];
+            if state & self.accept != 0 {
+                return Some(start + i + 1 - self.len);
+            }
+        }
+        None
+    }
+}
+
+impl std::fmt::Debug for CompiledDfa {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The 256 masks are noise in a debug dump
+        f.debug_struct("CompiledDfa").field("len", &self.len).finish_non_exhaustive()
+    }
+}
+
+/// How one pattern character matched the address
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchType {
+    /// Same character, same case
+    Literal,
+    /// A `?` in the pattern, which matches any character
+    Wildcard,
+    /// Same letter in the other case (case-insensitive searches only)
+    CaseInsensitive,
+}
+
 #[derive(Debug, Clone)]
 pub struct SearchResult {
     pub count: usize,
     pub offset: usize,
+    pub match_detail: Vec<MatchType>, // One entry per pattern byte; empty unless fully matched
 }
 
 /// Optimized byte-based comparison for ASCII-only Base58 addresses
@@ -130,6 +344,24 @@ fn compare_bytes_at_offset(
     count
 }
 
+/// Classify each character of a full match; only called once a match is found, so
+/// misses never allocate
+fn match_detail(pattern: &[u8], address: &[u8], offset: usize) -> Vec<MatchType> {
+    pattern
+        .iter()
+        .zip(&address[offset..])
+        .map(|(&p_byte, &a_byte)| {
+            if p_byte == b'?' {
+                MatchType::Wildcard
+            } else if p_byte == a_byte {
+                MatchType::Literal
+            } else {
+                MatchType::CaseInsensitive
+            }
+        })
+        .collect()
+}
+
 /// Optimized search using pre-computed configuration
 #[inline]
 pub fn search_with_config(config: &OptimizedSearchConfig, address: &str) -> SearchResult {
@@ -138,20 +370,54 @@ pub fn search_with_config(config: &OptimizedSearchConfig, address: &str) -> Sear
         return SearchResult {
             count: 0,
             offset: 0,
+            match_detail: Vec::new(),
         };
     }
 
     let pattern_bytes = config.get_pattern().as_bytes();
     let address_bytes = address.as_bytes();
 
-    if let Some((start, end)) = config.search_range {
-        // Try each position in the range
-        for pos in start..=end {
-            let count =
-                compare_bytes_at_offset(pattern_bytes, address_bytes, pos, config.case_sensitive);
+    let match_at = |pos: usize| {
+        let count = compare_bytes_at_offset(pattern_bytes, address_bytes, pos, config.case_sensitive);
+
+        // Compare in bytes: `pattern_len` counts chars, so a non-ASCII pattern
+        // would otherwise "match" after only part of its bytes
+        (count == pattern_bytes.len()).then(|| SearchResult {
+            count,
+            offset: pos,
+            match_detail: match_detail(pattern_bytes, address_bytes, pos),
+        })
+    };
+
+    if let (Some(dfa), Some((start, end))) = (&config.dfa, config.search_range) {
+        return dfa
+            .find(address_bytes, start, end)
+            .and_then(match_at)
+            .unwrap_or(SearchResult { count: 0, offset: 0, match_detail: Vec::new() });
+    }
 
-            if count == config.pattern_len {
-                return SearchResult { count, offset: pos };
+    // Positions past the end of a short address can never match
+    if let Some((start, end)) = config.search_range
+        && let Some(last_byte) = address_bytes.len().checked_sub(1)
+        && start <= end.min(last_byte)
+    {
+        let end = end.min(last_byte);
+
+        if config.first_char_lower == b'?' {
+            // A leading wildcard matches anywhere, so try every position
+            for pos in start..=end {
+                if let Some(result) = match_at(pos) {
+                    return result;
+                }
+            }
+        } else {
+            // Only visit positions holding the first character; memchr2 picks an
+            // SSE2/AVX2 or NEON scan at runtime and falls back to portable code
+            let window = &address_bytes[start..=end];
+            for pos in memchr::memchr2_iter(config.first_char_lower, config.first_char_upper, window) {
+                if let Some(result) = match_at(start + pos) {
+                    return result;
+                }
             }
         }
     }
@@ -159,13 +425,29 @@ pub fn search_with_config(config: &OptimizedSearchConfig, address: &str) -> Sear
     SearchResult {
         count: 0,
         offset: 0,
+        match_detail: Vec::new(),
     }
 }
 
+/// Check whether `pattern` appears anywhere in `address` (`?` matches any character)
+pub fn contains_pattern(address: &str, pattern: &str, case_sensitive: bool) -> bool {
+    let config = OptimizedSearchConfig::new(pattern, case_sensitive, false, true, usize::MAX);
+    search_with_config(&config, address).count == pattern.len()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_suffix_range_uses_network_address_length() {
+        // Substrate addresses are 48 characters, one shorter than Autonomys
+        let config = OptimizedSearchConfig::new_with_prefix("xyz", false, true, false, 3, 42);
+        assert_eq!(config.search_range, Some((45, 45)));
+        let config = OptimizedSearchConfig::new_with_prefix("xyz", false, true, false, 3, 6094);
+        assert_eq!(config.search_range, Some((46, 46)));
+    }
+
     // Config tests
     #[test]
     fn test_prefix_config() {
@@ -200,6 +482,44 @@ mod tests {
         assert!(!config.is_valid());
     }
 
+    #[test]
+    fn test_new_with_prefix_skips_locked_positions() {
+        // "abc" conflicts with "su" at positions 0 and 1
+        let config = OptimizedSearchConfig::new_with_prefix("abc", false, false, true, 49, 6094);
+        assert_eq!(config.search_range, Some((2, 46)));
+
+        // "ubc" lines up with the "u" of "su" at position 1
+        let config = OptimizedSearchConfig::new_with_prefix("ubc", false, false, true, 49, 6094);
+        assert_eq!(config.search_range, Some((1, 46)));
+
+        // Suffix ranges never touch the prefix
+        let config = OptimizedSearchConfig::new_with_prefix("ai", false, true, false, 5, 6094);
+        assert_eq!(config.search_range, Some((42, 47)));
+
+        // Prefix mode with no room past the network prefix
+        let config = OptimizedSearchConfig::new_with_prefix("abc", false, false, false, 3, 6094);
+        assert!(!config.is_valid());
+    }
+
+    #[test]
+    fn test_new_with_window() {
+        // Characters 5 through 15 (end exclusive)
+        let config = OptimizedSearchConfig::new_with_window("abc", false, 5, 15, 6094);
+        assert_eq!(config.search_range, Some((5, 12)));
+
+        // Window exactly the pattern length
+        let config = OptimizedSearchConfig::new_with_window("abc", false, 10, 13, 6094);
+        assert_eq!(config.search_range, Some((10, 10)));
+
+        // Window too narrow or past the end of the address
+        assert!(!OptimizedSearchConfig::new_with_window("abc", false, 10, 12, 6094).is_valid());
+        assert!(!OptimizedSearchConfig::new_with_window("abc", false, 40, 50, 6094).is_valid());
+
+        // Window starting inside the network prefix skips locked positions
+        let config = OptimizedSearchConfig::new_with_window("abc", false, 0, 10, 6094);
+        assert_eq!(config.search_range, Some((2, 7)));
+    }
+
     // Search tests
     #[test]
     fn test_compare_bytes_at_offset_exact_match() {
@@ -259,7 +579,7 @@ mod tests {
 
     #[test]
     fn test_search_with_config_suffix() {
-        // Real addresses are 49 chars according to SS58_ADDRESS_LENGTH
+        // Autonomys addresses are 49 chars (ss58_address_length_for_prefix(6094))
         let config = OptimizedSearchConfig::new("xyz", true, true, false, 3);
         // Create a 49-character address ending with "xyz"
         let address = "su12345678901234567890123456789012345678901234xyz";
@@ -278,6 +598,68 @@ mod tests {
         let result = search_with_config(&config, address);
         assert_eq!(result.count, 0);
         assert_eq!(result.offset, 0);
+        assert!(result.match_detail.is_empty());
+    }
+
+    #[test]
+    fn test_search_with_config_match_detail() {
+        let config = OptimizedSearchConfig::new("a?3b", false, false, false, 6);
+        let result = search_with_config(&config, "sua93Btestaddress");
+        assert_eq!(result.count, 4);
+        assert_eq!(
+            result.match_detail,
+            vec![MatchType::Literal, MatchType::Wildcard, MatchType::Literal, MatchType::CaseInsensitive]
+        );
+    }
+
+    #[test]
+    fn test_first_char_skip() {
+        let config = OptimizedSearchConfig::new("ab", false, false, true, 49);
+        assert_eq!((config.first_char_lower, config.first_char_upper), (b'a', b'A'));
+        assert_eq!(search_with_config(&config, "suxxAbxx").offset, 4);
+
+        let config = OptimizedSearchConfig::new("ab", true, false, true, 49);
+        assert_eq!((config.first_char_lower, config.first_char_upper), (b'a', b'a'));
+        assert_eq!(search_with_config(&config, "suxxAbxx").count, 0);
+
+        let config = OptimizedSearchConfig::new("7x", false, false, true, 49);
+        assert_eq!((config.first_char_lower, config.first_char_upper), (b'7', b'7'));
+
+        // A leading wildcard can't be skipped on
+        let config = OptimizedSearchConfig::new("?b", false, false, true, 49);
+        assert_eq!(config.first_char_lower, b'?');
+        assert_eq!(search_with_config(&config, "suxxAbxx").offset, 4);
+    }
+
+    #[test]
+    fn test_dfa_agrees_with_sliding_window() {
+        for (pattern, case_sensitive) in [("abcd", false), ("AbCd", true), ("a?c?e", false), ("zzzzzz", false)] {
+            let sliding = OptimizedSearchConfig::new(pattern, case_sensitive, false, true, 49);
+            assert!(sliding.dfa.is_none());
+            let with_dfa = sliding.clone().with_dfa();
+            assert!(with_dfa.dfa.is_some());
+
+            for address in ["suxxabcdxx", "suxxABCDxx", "suxAbCdxAbCd", "suaXcYexx", "abcd", "suzzzzzzzzz", "su"] {
+                let (dfa, window) = (search_with_config(&with_dfa, address), search_with_config(&sliding, address));
+                assert_eq!((dfa.count, dfa.offset), (window.count, window.offset), "{pattern} in {address}");
+            }
+        }
+
+        // Short patterns keep the sliding window
+        assert!(OptimizedSearchConfig::new("abc", false, false, true, 49).with_dfa().dfa.is_none());
+        assert!(CompiledDfa::new(&"a".repeat(65), false).is_none());
+    }
+
+    #[test]
+    fn test_new_with_prefix_uses_dfa_for_anywhere() {
+        assert!(OptimizedSearchConfig::new_with_prefix("abcd", false, false, true, 49, 6094).dfa.is_some());
+        assert!(OptimizedSearchConfig::new_with_prefix("abc", false, false, true, 49, 6094).dfa.is_none());
+        // Prefix and suffix searches keep the sliding window
+        assert!(OptimizedSearchConfig::new_with_prefix("abcd", false, false, false, 10, 6094).dfa.is_none());
+        assert!(OptimizedSearchConfig::new_with_prefix("abcd", false, true, false, 10, 6094).dfa.is_none());
+
+        let config = OptimizedSearchConfig::new_with_prefix("abcd", false, false, true, 49, 6094);
+        assert_eq!(search_with_config(&config, "suxxABCDxx").offset, 4);
     }
 
     #[test]
@@ -291,4 +673,14 @@ mod tests {
         assert_eq!(result.count, 0);
         assert_eq!(result.offset, 0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_search_with_config_partial_multibyte_match() {
+        // "Ȃ" and "ȇ" share their first UTF-8 byte; found by fuzz/fuzz_targets/fuzz_search.rs
+        let config = OptimizedSearchConfig::new("Ȃ", true, false, true, 49);
+
+        assert_eq!(search_with_config(&config, "ȇ").count, 0);
+        assert_eq!(search_with_config(&config, "xȂ").count, "Ȃ".len());
+        assert!(!contains_pattern("ȇ", "Ȃ", true));
+    }
+}
@@ -1,12 +1,16 @@
 use crate::crypto::bip39::Mnemonic;
-use crate::crypto::mnemonic_to_address_with_prefix;
+use crate::crypto::mnemonic_to_address_with_rounds;
 use crate::wallet::hex_to_address_with_prefix;
-use std::str::FromStr;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use zeroize::Zeroize;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ValidationResult {
     Valid,
     Mismatch,
+    /// The secret's key is right but the address was encoded for another network
+    WrongNetwork { expected_prefix: u16, actual_address: String },
     Error,
 }
 
@@ -14,51 +18,432 @@ impl ValidationResult {
     pub fn status_symbol(&self) -> &'static str {
         match self {
             ValidationResult::Valid => "OK",
-            ValidationResult::Mismatch => "X", 
+            ValidationResult::Mismatch => "X",
+            ValidationResult::WrongNetwork { .. } => "X",
             ValidationResult::Error => "!",
         }
     }
 }
 
-fn validate_mnemonic_address(mnemonic: &str, expected_address: &str, ss58_prefix: u16) -> Result<bool, String> {
-    let mnemonic_parsed = Mnemonic::from_str(mnemonic)
-        .map_err(|e| format!("Failed to parse mnemonic: {:?}", e))?;
-    
-    let generated_address = mnemonic_to_address_with_prefix(&mnemonic_parsed, ss58_prefix);
-    Ok(generated_address == expected_address)
+/// Length in characters of an SS58 address for `ss58_prefix`
+///
+/// Measured by encoding an all-`0xff` account ID, so this is the longest address the
+/// network produces: prefixes 0-10 occasionally yield one character fewer, every
+/// other prefix always gives this length. Computed once per prefix and cached.
+pub fn ss58_address_length_for_prefix(ss58_prefix: u16) -> usize {
+    use crate::crypto::substrate::crypto::{AccountId32, Ss58AddressFormat, Ss58Codec};
+
+    static LENGTHS: OnceLock<Mutex<HashMap<u16, usize>>> = OnceLock::new();
+
+    let mut lengths = LENGTHS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    *lengths.entry(ss58_prefix).or_insert_with(|| {
+        AccountId32::from([0xff; 32]).to_ss58check_with_version(Ss58AddressFormat::custom(ss58_prefix))
+            .len()
+    })
 }
 
-fn validate_hex_address(hex_seed: &str, expected_address: &str, ss58_prefix: u16) -> Result<bool, String> {
-    let hex_clean = if hex_seed.starts_with("0x") {
-        &hex_seed[2..]
-    } else {
-        hex_seed
+/// Reasons an SS58 address can't be decoded for `--anatomy`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ss58Error {
+    /// The address isn't valid Base58
+    InvalidBase58(crate::crypto::bs58::Error),
+    /// The decoded payload isn't prefix + 32-byte key + 2-byte checksum
+    InvalidLength(usize),
+    /// The first byte isn't a valid SS58 prefix encoding
+    InvalidPrefix,
+    /// The trailing checksum doesn't match the payload
+    BadChecksum,
+    /// The address belongs to a different network than the one selected
+    PrefixMismatch { expected: u16, found: u16 },
+}
+
+impl std::fmt::Display for Ss58Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ss58Error::InvalidBase58(e) => write!(f, "{e}"),
+            Ss58Error::InvalidLength(len) => write!(f, "decoded address is {len} bytes, expected 35 or 36"),
+            Ss58Error::InvalidPrefix => write!(f, "invalid SS58 prefix encoding"),
+            Ss58Error::BadChecksum => write!(f, "SS58 checksum mismatch"),
+            Ss58Error::PrefixMismatch { expected, found } => {
+                write!(f, "address has SS58 prefix {found}, expected {expected} (select the network with --network or --ss58-prefix)")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Ss58Error {}
+
+impl Ss58Error {
+    /// The check that failed, for `--check-ss58`
+    pub fn step(&self) -> &'static str {
+        match self {
+            Ss58Error::InvalidBase58(_) => "Base58 decoding",
+            Ss58Error::InvalidLength(_) => "Length check",
+            Ss58Error::InvalidPrefix => "Prefix decoding",
+            Ss58Error::BadChecksum => "Checksum",
+            Ss58Error::PrefixMismatch { .. } => "Network check",
+        }
+    }
+}
+
+/// The parts of a valid SS58 address (`--check-ss58`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ss58Address {
+    pub ss58_prefix: u16,
+    pub public_key: [u8; 32],
+    pub checksum: [u8; 2],
+}
+
+/// Decode an SS58 address, checking its Base58, length, prefix encoding and checksum
+pub fn decode_ss58_address(address: &str) -> Result<Ss58Address, Ss58Error> {
+    let decoded = decode_ss58(address)?;
+    Ok(Ss58Address {
+        ss58_prefix: decoded.ss58_prefix,
+        public_key: decoded.public_key().try_into().expect("decode_ss58 checks the payload length"),
+        checksum: decoded.checksum().try_into().expect("decode_ss58 checks the payload length"),
+    })
+}
+
+/// An SS58 address split into its parts
+struct DecodedAddress {
+    payload: Vec<u8>,
+    prefix_len: usize,
+    ss58_prefix: u16,
+}
+
+impl DecodedAddress {
+    fn public_key(&self) -> &[u8] {
+        &self.payload[self.prefix_len..self.prefix_len + 32]
+    }
+
+    fn checksum(&self) -> &[u8] {
+        &self.payload[self.prefix_len + 32..]
+    }
+
+    /// Name of payload byte `index`, e.g. `prefix[0]` or `key[31]`
+    fn byte_label(&self, index: usize) -> String {
+        match index {
+            i if i < self.prefix_len => format!("prefix[{i}]"),
+            i if i < self.prefix_len + 32 => format!("key[{}]", i - self.prefix_len),
+            i => format!("checksum[{}]", i - self.prefix_len - 32),
+        }
+    }
+
+    /// Diagram letter for payload byte `index`
+    fn byte_region(&self, index: usize) -> char {
+        match index {
+            i if i < self.prefix_len => 'P',
+            i if i < self.prefix_len + 32 => 'K',
+            _ => 'C',
+        }
+    }
+}
+
+/// Decode an SS58 address, checking its length and checksum
+fn decode_ss58(address: &str) -> Result<DecodedAddress, Ss58Error> {
+    let payload = crate::crypto::bs58::decode(address).into_vec().map_err(Ss58Error::InvalidBase58)?;
+
+    let (prefix_len, ss58_prefix) = match payload.first() {
+        Some(&first @ 0..=63) => (1, u16::from(first)),
+        Some(&first @ 64..=127) if payload.len() > 1 => {
+            // Inverse of the two-byte encoding in Ss58Codec::to_ss58check_with_version
+            let second = payload[1];
+            let lower = ((first & 0b0011_1111) << 2) | (second >> 6);
+            let upper = second & 0b0011_1111;
+            (2, u16::from(lower) | (u16::from(upper) << 8))
+        }
+        Some(_) => return Err(Ss58Error::InvalidPrefix),
+        None => return Err(Ss58Error::InvalidLength(0)),
     };
-    
-    let seed_bytes = hex::decode(hex_clean)
-        .map_err(|e| format!("Failed to decode hex seed: {}", e))?;
-    
-    if seed_bytes.len() != 32 {
-        return Err(format!("Hex seed must be exactly 32 bytes, got {}", seed_bytes.len()));
-    }
-    
-    let mut seed_array = [0u8; 32];
-    seed_array.copy_from_slice(&seed_bytes);
-    let generated_address = hex_to_address_with_prefix(&seed_array, ss58_prefix);
-    
-    Ok(generated_address == expected_address)
-}
-
-pub fn validate_wallet(secret: &str, address: &str, ss58_prefix: u16, is_hex_mode: bool) -> ValidationResult {
-    let result = if is_hex_mode {
-        validate_hex_address(secret, address, ss58_prefix)
+
+    if payload.len() != prefix_len + 32 + 2 {
+        return Err(Ss58Error::InvalidLength(payload.len()));
+    }
+
+    let body = &payload[..prefix_len + 32];
+    let hash = crate::crypto::blake2_512(&[b"SS58PRE".as_slice(), body].concat());
+    if hash[..2] != payload[prefix_len + 32..] {
+        return Err(Ss58Error::BadChecksum);
+    }
+
+    Ok(DecodedAddress { payload, prefix_len, ss58_prefix })
+}
+
+/// Payload bytes each address character is drawn from, as inclusive index ranges
+///
+/// Base58 treats the payload as one big-endian number, so character `i` is the
+/// base-58 digit of weight 58^r (r counted from the right) and covers bits
+/// `r·log2(58)` up to `(r+1)·log2(58)` of that number. Each digit therefore spans at
+/// most two bytes. Leading zero bytes are encoded as one `1` character each.
+fn character_sources(payload: &[u8], address_len: usize) -> Vec<(usize, usize)> {
+    let leading_zeros = payload.iter().take_while(|&&b| b == 0).count();
+    let number_bytes = payload.len() - leading_zeros;
+    let bits_per_digit = 58f64.log2();
+
+    (0..address_len)
+        .map(|i| {
+            if i < leading_zeros {
+                return (i, i);
+            }
+            let r = address_len - 1 - i;
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let bit = |x: f64| (x / 8.0).floor() as usize;
+            let low_weight = bit(r as f64 * bits_per_digit).min(number_bytes - 1);
+            let high_weight = bit((r + 1) as f64 * bits_per_digit - 1e-9).min(number_bytes - 1);
+            (payload.len() - 1 - high_weight, payload.len() - 1 - low_weight)
+        })
+        .collect()
+}
+
+/// Print how an SS58 address breaks down into prefix, public key and checksum (`--anatomy`)
+///
+/// Shows the decoded bytes, which payload bytes each address character comes from,
+/// and which positions the network prefix fixes or constrains.
+pub fn explain_address(address: &str, ss58_prefix: u16) -> Result<(), Ss58Error> {
+    use crate::cli::terminal::colors;
+
+    let decoded = decode_ss58(address)?;
+    if decoded.ss58_prefix != ss58_prefix {
+        return Err(Ss58Error::PrefixMismatch { expected: ss58_prefix, found: decoded.ss58_prefix });
+    }
+
+    let network = crate::networks::find_network_by_prefix(ss58_prefix);
+    let network_name = network.as_ref().map(|n| n.name).unwrap_or("Custom");
+    let to_hex = |bytes: &[u8]| bytes.iter().map(|b| format!("{b:02x}")).collect::<Vec<_>>().join(" ");
+
+    println!("{}", colors::yellow("ADDRESS ANATOMY"));
+    println!("\n{} {}", colors::yellow("→ Address:"), colors::bright_yellow(address));
+    println!("  Network:      {} (SS58: {}), {} characters", network_name, ss58_prefix, address.len());
+    println!("  Prefix bytes: {} ({} byte{})", to_hex(&decoded.payload[..decoded.prefix_len]), decoded.prefix_len,
+             if decoded.prefix_len == 1 { "" } else { "s" });
+    println!("  Public key:   {}", to_hex(&decoded.public_key()[..16]));
+    println!("                {}", to_hex(&decoded.public_key()[16..]));
+    println!("  Checksum:     {} (first 2 bytes of blake2b-512(\"SS58PRE\" + prefix + key))", to_hex(decoded.checksum()));
+
+    let sources = character_sources(&decoded.payload, address.len());
+    let regions: String = sources
+        .iter()
+        .map(|&(first, last)| {
+            let region = decoded.byte_region(first);
+            if region == decoded.byte_region(last) { region } else { '+' }
+        })
+        .collect();
+
+    println!("\n{}", colors::yellow("→ Character Map:"));
+    println!("{}", colors::gray("P = prefix bytes, K = public key, C = checksum, + = spans two regions"));
+    println!("  {address}");
+    println!("  {regions}");
+    println!();
+    for (pos, (ch, &(first, last))) in address.chars().zip(&sources).enumerate() {
+        let bytes = if first == last {
+            decoded.byte_label(first)
+        } else {
+            format!("{} + {}", decoded.byte_label(first), decoded.byte_label(last))
+        };
+        println!("  {pos:>2}  {ch}  {bytes}");
+    }
+
+    // Positions drawn from the prefix bytes; the network's known leading characters never vary
+    let fixed_len = network
+        .and_then(|n| n.address_prefixes.iter().map(|p| p.len()).min())
+        .unwrap_or(0);
+    let prefix_positions: Vec<usize> = sources
+        .iter()
+        .enumerate()
+        .filter(|&(_, &(first, _))| first < decoded.prefix_len)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    println!("\n{}", colors::yellow("→ Network Prefix:"));
+    for pos in prefix_positions {
+        let ch = address[pos..].chars().next().unwrap_or('?');
+        if pos < fixed_len {
+            println!("  {pos:>2}  {ch}  fixed: every {network_name} address has '{ch}' here");
+        } else {
+            println!("  {pos:>2}  {ch}  constrained: mixes prefix bits with public key bits");
+        }
+    }
+
+    Ok(())
+}
+
+/// SS58 prefix an address is encoded with, if it decodes
+pub(super) fn address_ss58_prefix(address: &str) -> Option<u16> {
+    decode_ss58(address).ok().map(|decoded| decoded.ss58_prefix)
+}
+
+/// Derive the address a wallet secret (hex seed or mnemonic) has on `ss58_prefix`
+pub fn derive_address(secret: &str, ss58_prefix: u16, is_hex_mode: bool, pbkdf2_rounds: u32) -> Result<String, String> {
+    if is_hex_mode {
+        let hex_clean = secret.strip_prefix("0x").unwrap_or(secret);
+        let mut seed_bytes = hex::decode(hex_clean)
+            .map_err(|e| format!("Failed to decode hex seed: {}", e))?;
+        let seed = <[u8; 32]>::try_from(seed_bytes.as_slice())
+            .map_err(|_| format!("Hex seed must be exactly 32 bytes, got {}", seed_bytes.len()));
+        seed_bytes.zeroize();
+        let mut seed = seed?;
+        let address = hex_to_address_with_prefix(&seed, ss58_prefix);
+        seed.zeroize();
+        Ok(address)
     } else {
-        validate_mnemonic_address(secret, address, ss58_prefix)
+        let mnemonic = Mnemonic::parse(secret)
+            .map_err(|e| format!("Failed to parse mnemonic: {:?}", e))?;
+        Ok(mnemonic_to_address_with_rounds(&mnemonic, ss58_prefix, pbkdf2_rounds))
+    }
+}
+
+/// Check that `secret` derives `address` on network `ss58_prefix`
+///
+/// An address that holds the right key under a different SS58 prefix is reported as
+/// `WrongNetwork`, which points at a network configuration bug rather than a bad key.
+pub fn validate_wallet(secret: &str, address: &str, ss58_prefix: u16, is_hex_mode: bool, pbkdf2_rounds: u32) -> ValidationResult {
+    let derived = match derive_address(secret, ss58_prefix, is_hex_mode, pbkdf2_rounds) {
+        Ok(derived) => derived,
+        Err(_) => return ValidationResult::Error,
     };
-    
-    match result {
-        Ok(true) => ValidationResult::Valid,
-        Ok(false) => ValidationResult::Mismatch,
-        Err(_) => ValidationResult::Error,
+    if derived == address {
+        return ValidationResult::Valid;
+    }
+
+    match (decode_ss58(address), decode_ss58(&derived)) {
+        (Ok(actual), Ok(expected))
+            if actual.ss58_prefix != ss58_prefix && actual.public_key() == expected.public_key() =>
+        {
+            ValidationResult::WrongNetwork { expected_prefix: ss58_prefix, actual_address: address.to_string() }
+        }
+        _ => ValidationResult::Mismatch,
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_ss58() {
+        let seed = [42u8; 32];
+        for prefix in [0, 42, 6094, 16_383] {
+            let address = hex_to_address_with_prefix(&seed, prefix);
+            let decoded = decode_ss58(&address).unwrap();
+            assert_eq!(decoded.ss58_prefix, prefix);
+            assert_eq!(decoded.prefix_len, if prefix < 64 { 1 } else { 2 });
+            assert_eq!(decoded.public_key().len(), 32);
+            assert!(explain_address(&address, prefix).is_ok());
+        }
+
+        let address = hex_to_address_with_prefix(&seed, 6094);
+        assert_eq!(
+            explain_address(&address, 42),
+            Err(Ss58Error::PrefixMismatch { expected: 42, found: 6094 })
+        );
+
+        // Swapping two characters breaks the checksum
+        let mut swapped: Vec<char> = address.chars().collect();
+        swapped.swap(10, 11);
+        let swapped: String = swapped.into_iter().collect();
+        assert_ne!(swapped, address);
+        assert!(matches!(decode_ss58(&swapped), Err(Ss58Error::BadChecksum | Ss58Error::InvalidLength(_))));
+
+        assert!(matches!(decode_ss58("su0"), Err(Ss58Error::InvalidBase58(_))));
+        assert!(matches!(decode_ss58("sub"), Err(Ss58Error::InvalidLength(_))));
+    }
+
+    #[test]
+    fn test_decode_ss58_address() {
+        // Recorded in tests/known_vectors.rs: seed 0x00..01 on Substrate
+        let address = decode_ss58_address("5DP4qTec9XxffaALGWsEPhS1oWrDWMBjzhBmyzShREMJpymt").unwrap();
+        assert_eq!(address.ss58_prefix, 42);
+        let autonomys = decode_ss58_address("sucPSnqV7cZXnm5Csifi5GPuTEQP6Go7or4yUVo4W9ybitN8s").unwrap();
+        assert_eq!(autonomys.ss58_prefix, 6094);
+        assert_eq!(autonomys.public_key, address.public_key);
+        assert_ne!(autonomys.checksum, address.checksum);
+
+        assert_eq!(decode_ss58_address("su0").unwrap_err().step(), "Base58 decoding");
+        assert_eq!(decode_ss58_address("sub").unwrap_err().step(), "Length check");
+    }
+
+    #[test]
+    fn test_validate_wallet_wrong_network() {
+        let seed = [42u8; 32];
+        let secret = hex::encode(seed);
+        let autonomys = hex_to_address_with_prefix(&seed, 6094);
+        let substrate = hex_to_address_with_prefix(&seed, 42);
+
+        assert_eq!(validate_wallet(&secret, &autonomys, 6094, true, 2048), ValidationResult::Valid);
+        assert_eq!(
+            validate_wallet(&secret, &substrate, 6094, true, 2048),
+            ValidationResult::WrongNetwork { expected_prefix: 6094, actual_address: substrate }
+        );
+        let other_key = hex_to_address_with_prefix(&[7u8; 32], 6094);
+        assert_eq!(validate_wallet(&secret, &other_key, 6094, true, 2048), ValidationResult::Mismatch);
+        assert_eq!(validate_wallet("not hex", &autonomys, 6094, true, 2048), ValidationResult::Error);
+    }
+
+    #[test]
+    fn test_derive_address_known_vectors() {
+        // Recorded in tests/known_vectors.rs
+        let seed = "0000000000000000000000000000000000000000000000000000000000000001";
+        assert_eq!(derive_address(seed, 6094, true, 2048).unwrap(), "sucPSnqV7cZXnm5Csifi5GPuTEQP6Go7or4yUVo4W9ybitN8s");
+        assert_eq!(derive_address(&format!("0x{seed}"), 42, true, 2048).unwrap(), "5DP4qTec9XxffaALGWsEPhS1oWrDWMBjzhBmyzShREMJpymt");
+
+        let mnemonic = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        assert_eq!(derive_address(mnemonic, 6094, false, 2048).unwrap(), "sudJ4UJrg83Puu7ACTJySYTpaYPa5vzSCpDuiwUK94u3adbMp");
+
+        assert!(derive_address("00ff", 6094, true, 2048).unwrap_err().contains("exactly 32 bytes"));
+        assert!(derive_address("legal winner", 6094, false, 2048).is_err());
+    }
+
+    #[test]
+    fn test_character_sources() {
+        let address = hex_to_address_with_prefix(&[42u8; 32], 6094);
+        let decoded = decode_ss58(&address).unwrap();
+        let sources = character_sources(&decoded.payload, address.len());
+
+        assert_eq!(sources.len(), 49);
+        // The first characters come from the prefix, the last from the checksum
+        assert_eq!(sources[0].0, 0);
+        assert_eq!(sources[48], (35, 35));
+        // Ranges run left to right and never span more than two bytes
+        for window in sources.windows(2) {
+            assert!(window[0].0 <= window[1].0);
+        }
+        assert!(sources.iter().all(|&(first, last)| last - first <= 1));
+
+        // Polkadot's leading zero byte becomes a lone '1'
+        let address = hex_to_address_with_prefix(&[42u8; 32], 0);
+        let decoded = decode_ss58(&address).unwrap();
+        assert_eq!(character_sources(&decoded.payload, address.len())[0], (0, 0));
+    }
+
+    #[test]
+    fn test_ss58_address_length_for_prefix() {
+        assert_eq!(ss58_address_length_for_prefix(6094), 49); // Autonomys
+        assert_eq!(ss58_address_length_for_prefix(42), 48); // Substrate
+        assert_eq!(ss58_address_length_for_prefix(0), 48); // Polkadot
+        assert_eq!(ss58_address_length_for_prefix(16_383), 49);
+
+        // Real addresses never exceed the measured length
+        for prefix in [0, 2, 42, 6094] {
+            let address = hex_to_address_with_prefix(&[0xff; 32], prefix);
+            assert!(address.len() <= ss58_address_length_for_prefix(prefix));
+        }
+    }
+
+    #[test]
+    fn test_ss58_address_length_for_every_network() {
+        // Every registered network plus the edges of the one- and two-byte prefix encodings
+        let prefixes = crate::networks::NETWORKS.iter().map(|network| network.ss58_prefix).chain([1, 10, 11, 63, 64, 16_383]);
+        for prefix in prefixes {
+            let length = ss58_address_length_for_prefix(prefix);
+            for byte in [0u8, 1, 7, 42, 128, 255] {
+                let address = hex_to_address_with_prefix(&[byte; 32], prefix);
+                // Only prefixes 0-10 can encode one character shorter
+                let shortest = if prefix <= 10 { length - 1 } else { length };
+                assert!((shortest..=length).contains(&address.len()), "prefix {prefix}: {address}");
+            }
+        }
+    }
+}
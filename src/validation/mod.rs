@@ -1,5 +1,117 @@
 mod address;
 mod search;
 
-pub use address::{ValidationResult, validate_wallet};
-pub use search::validate_search_term_with_prefix;
\ No newline at end of file
+pub use address::{
+    Ss58Address, Ss58Error, ValidationResult, decode_ss58_address, derive_address, explain_address,
+    ss58_address_length_for_prefix, validate_wallet,
+};
+pub use search::{suggest_easier_pattern, theoretical_impossibility_check, validate_search_term_with_prefix};
+
+use std::path::Path;
+
+/// A wallet file recognised by `validate_directory`
+enum WalletFile {
+    Mnemonic { address: String, mnemonic: String, pbkdf2_rounds: u32 },
+    Json { address: String, contents: String },
+}
+
+/// Read a mnemonic `.txt` or encrypted `.json` wallet; other files give `None`
+///
+/// Share files, raw key exports and the manifest share these extensions but lack
+/// the wallet fields, so they are skipped too.
+fn read_wallet_file(path: &Path) -> Option<WalletFile> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    match path.extension()?.to_str()? {
+        "txt" => {
+            let field = |name: &str| contents.lines().find_map(|line| line.strip_prefix(name)).map(str::trim);
+            Some(WalletFile::Mnemonic {
+                address: field("Address:")?.to_string(),
+                mnemonic: field("Mnemonic:")?.to_string(),
+                // Files written before the round count was recorded used the default
+                pbkdf2_rounds: field("PBKDF2-rounds:")
+                    .and_then(|rounds| rounds.parse().ok())
+                    .unwrap_or(crate::wallet::DEFAULT_PBKDF2_ROUNDS),
+            })
+        }
+        "json" => {
+            let wallet: serde_json::Value = serde_json::from_str(&contents).ok()?;
+            wallet["encoded"].as_str()?;
+            let address = wallet["address"].as_str()?.to_string();
+            Some(WalletFile::Json { address, contents })
+        }
+        _ => None,
+    }
+}
+
+/// Address stored in a wallet file, or `None` if it isn't one
+pub fn wallet_file_address(path: &Path) -> Option<String> {
+    match read_wallet_file(path)? {
+        WalletFile::Mnemonic { address, .. } | WalletFile::Json { address, .. } => Some(address),
+    }
+}
+
+/// Validate every wallet file in `dir`, sorted by file name
+///
+/// Mnemonic wallets are checked on the network their address is encoded for. JSON
+/// wallets are decrypted with `password` and report `Error` without one.
+pub fn validate_directory(dir: &str, password: Option<&str>) -> Vec<(String, ValidationResult)> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    let mut paths: Vec<_> = entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .filter_map(|path| {
+            let result = match read_wallet_file(path)? {
+                WalletFile::Mnemonic { address, mnemonic, pbkdf2_rounds } => match address::address_ss58_prefix(&address) {
+                    Some(ss58_prefix) => validate_wallet(&mnemonic, &address, ss58_prefix, false, pbkdf2_rounds),
+                    None => ValidationResult::Error,
+                },
+                WalletFile::Json { contents, .. } => match password.map(|pwd| crate::wallet::verify_wallet_json(&contents, pwd)) {
+                    Some(Ok(true)) => ValidationResult::Valid,
+                    Some(Ok(false)) => ValidationResult::Mismatch,
+                    Some(Err(_)) | None => ValidationResult::Error,
+                },
+            };
+            Some((path.file_name()?.to_string_lossy().into_owned(), result))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_directory() {
+        let dir = ".devonly/validate_dir";
+        std::fs::create_dir_all(dir).ok();
+        let mnemonic = crate::wallet::generate_mnemonic();
+        let mut result = crate::runner::VanityResult::for_test(
+            &crate::crypto::mnemonic_to_address_with_prefix(&mnemonic, 6094),
+            &mnemonic.to_string(),
+        );
+        crate::wallet::save_wallet_mnemonic(&result, dir, "good", crate::wallet::DEFAULT_PBKDF2_ROUNDS).unwrap();
+        let good_address = result.address.clone();
+        result.address = crate::wallet::hex_to_address_with_prefix(&[7u8; 32], 6094);
+        crate::wallet::save_wallet_mnemonic(&result, dir, "tampered", crate::wallet::DEFAULT_PBKDF2_ROUNDS).unwrap();
+
+        result.secret = hex::encode([7u8; 32]);
+        crate::wallet::save_wallet_json(&result, "testpass123", "test", dir, "wallet", crate::wallet::Kdf::default(), None).unwrap();
+        std::fs::write(format!("{dir}/manifest.json"), "{\"wallets\": []}").unwrap();
+
+        let results = validate_directory(dir, Some("testpass123"));
+        assert_eq!(
+            results,
+            vec![
+                ("good.txt".to_string(), ValidationResult::Valid),
+                ("tampered.txt".to_string(), ValidationResult::Mismatch),
+                ("wallet.json".to_string(), ValidationResult::Valid),
+            ]
+        );
+        assert_eq!(validate_directory(dir, None)[2].1, ValidationResult::Error);
+        assert_eq!(wallet_file_address(Path::new(&format!("{dir}/good.txt"))), Some(good_address));
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
@@ -1,3 +1,9 @@
+use crate::cli::probability::{calculate_expected_attempts, possible_characters_by_position};
+use unicode_normalization::UnicodeNormalization;
+
+/// Expected attempts a suggested easier pattern must come in under
+const SUGGESTION_THRESHOLD: u64 = 1_000_000_000;
+
 pub const fn is_valid_ss58_character(ch: char) -> bool {
     matches!(ch, '1'..='9' | 'A'..='H' | 'J'..='N' | 'P'..='Z' | 'a'..='k' | 'm'..='z')
 }
@@ -33,13 +39,31 @@ const fn can_appear_in_address(ch: char, case_sensitive: bool) -> bool {
     }
 }
 
+/// Check that a search term can match addresses of the given network
+///
+/// Returns warnings for terms that are valid but almost certainly not what the user
+/// meant, or an error for terms that can never match.
 pub fn validate_search_term_with_prefix(
     term: &str,
     case_sensitive: bool,
     suffix: bool,
     within: usize,
     ss58_prefix: u16,
-) -> Result<(), String> {
+) -> Result<Vec<String>, String> {
+    // Lookalikes such as Cyrillic 'а' survive NFKC and can never appear in Base58
+    let mut non_ascii: Vec<char> = term.nfkc().filter(|ch| !matches!(ch, '\x20'..='\x7e')).collect();
+    if !non_ascii.is_empty() {
+        non_ascii.sort_unstable();
+        non_ascii.dedup();
+
+        let non_ascii_str = non_ascii
+            .iter()
+            .map(|c| format!("'{c}' (U+{:04X})", u32::from(*c)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(format!("Pattern contains non-ASCII characters after normalization: {non_ascii_str}"));
+    }
+
     let mut invalid_chars = Vec::new();
 
     for ch in term.chars() {
@@ -71,6 +95,14 @@ pub fn validate_search_term_with_prefix(
     }
 
     let term_len = term.len();
+    let address_len = crate::validation::ss58_address_length_for_prefix(ss58_prefix);
+    if term_len > address_len {
+        return Err(format!(
+            "Search term '{}' ({} characters) is longer than a {} character {} address",
+            term, term_len, address_len, get_network_name_for_prefix(ss58_prefix)
+        ));
+    }
+
     if term_len > within {
         return Err(format!(
             "Search term '{}' ({} characters) cannot fit within --within {} characters limit",
@@ -140,5 +172,160 @@ pub fn validate_search_term_with_prefix(
         }
     }
 
+    let mut warnings = Vec::new();
+
+    // Prefix and anywhere searches for the network's own prefix match every address
+    if !suffix
+        && let Some(expected_prefixes) = get_expected_address_prefixes(ss58_prefix)
+        && expected_prefixes.iter().any(|prefix| {
+            if case_sensitive { term == *prefix } else { term.eq_ignore_ascii_case(prefix) }
+        })
+    {
+        warnings.push(format!(
+            "Every {} address starts with '{}', so all addresses match search term '{}' by definition",
+            get_network_name_for_prefix(ss58_prefix), expected_prefixes.join("' or '"), term
+        ));
+    }
+
+    Ok(warnings)
+}
+
+/// Reject patterns with a character no address can hold where the search would put it
+///
+/// Checks each pattern character against every address position the search range
+/// could place it at, using the characters the network's prefix encoding allows there
+/// (see `possible_characters_by_position`). This catches positions the prefix bytes
+/// constrain beyond the fixed prefix characters, such as the third character of
+/// Autonomys addresses. Positions in the error are 0-based address positions.
+///
+/// The allowed characters are derived rather than sampled from generated addresses:
+/// a character missing from a sample may only be rare, so a sampled check could
+/// reject patterns that real addresses do match.
+pub fn theoretical_impossibility_check(
+    pattern: &str,
+    case_sensitive: bool,
+    search_range: Option<(usize, usize)>,
+    ss58_prefix: u16,
+) -> Result<(), String> {
+    let Some((start, end)) = search_range else {
+        return Ok(()); // Nothing to check; the search range itself is already empty
+    };
+    let possible_by_position = possible_characters_by_position(ss58_prefix);
+
+    for (i, ch) in pattern.chars().enumerate() {
+        if ch == '?' {
+            continue;
+        }
+
+        let possible = (start + i..=end + i).any(|pos| {
+            possible_by_position.get(pos).is_some_and(|possible| {
+                if case_sensitive {
+                    possible.contains(&ch)
+                } else {
+                    possible.contains(&ch.to_ascii_uppercase()) || possible.contains(&ch.to_ascii_lowercase())
+                }
+            })
+        });
+        if !possible {
+            return Err(format!(
+                "Character '{}' at position {} cannot appear in any address for this network",
+                ch, start + i
+            ));
+        }
+    }
+
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Find the longest leading part of `pattern` expected to match in under a billion attempts
+///
+/// `search_range` maps a candidate term to the start positions the search would check
+/// for it, since shorter terms can fit more positions. Candidates ending in a wildcard
+/// are skipped because the trailing `?` adds nothing.
+pub fn suggest_easier_pattern(
+    pattern: &str,
+    case_sensitive: bool,
+    ss58_prefix: u16,
+    search_range: impl Fn(&str) -> Option<(usize, usize)>,
+) -> Option<(String, u64)> {
+    let chars: Vec<char> = pattern.chars().collect();
+
+    (1..chars.len()).rev().find_map(|len| {
+        if chars[len - 1] == '?' {
+            return None;
+        }
+        let candidate: String = chars[..len].iter().collect();
+        let expected = calculate_expected_attempts(&candidate, case_sensitive, search_range(&candidate), ss58_prefix);
+        (expected < SUGGESTION_THRESHOLD).then_some((candidate, expected))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::OptimizedSearchConfig;
+
+    #[test]
+    fn test_term_longer_than_address() {
+        let term = "a".repeat(49);
+        assert!(validate_search_term_with_prefix(&term, false, true, 49, 6094).is_ok());
+        assert!(validate_search_term_with_prefix(&term, false, true, 49, 42).is_err());
+    }
+
+    #[test]
+    fn test_non_ascii_pattern_rejected() {
+        // Cyrillic 'а' looks like Latin 'a'
+        let err = validate_search_term_with_prefix("b\u{430}d", false, true, 3, 6094).unwrap_err();
+        assert!(err.contains("non-ASCII characters after normalization"));
+        assert!(err.contains("U+0430"));
+
+        // Fullwidth letters normalize to ASCII and fall through to the Base58 check
+        let err = validate_search_term_with_prefix("\u{ff42}ad", false, true, 3, 6094).unwrap_err();
+        assert!(!err.contains("non-ASCII"));
+
+        assert!(validate_search_term_with_prefix("bad", false, true, 3, 6094).is_ok());
+    }
+
+    #[test]
+    fn test_network_prefix_term_warns() {
+        let warnings = validate_search_term_with_prefix("su", true, false, 5, 6094).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("all addresses match"));
+
+        // Anywhere mode and case-insensitive matching warn too
+        assert_eq!(validate_search_term_with_prefix("SU", false, false, 49, 6094).unwrap().len(), 1);
+
+        // Suffix mode, longer terms and other networks don't
+        assert!(validate_search_term_with_prefix("su", true, true, 2, 6094).unwrap().is_empty());
+        assert!(validate_search_term_with_prefix("sux", true, false, 5, 6094).unwrap().is_empty());
+        assert!(validate_search_term_with_prefix("su", true, false, 5, 42).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_theoretical_impossibility_check() {
+        // The third Autonomys character is always one of b-g
+        let err = theoretical_impossibility_check("suZ", true, Some((0, 0)), 6094).unwrap_err();
+        assert_eq!(err, "Character 'Z' at position 2 cannot appear in any address for this network");
+        assert!(theoretical_impossibility_check("suB", false, Some((0, 0)), 6094).is_ok());
+
+        // With room to move, 'Z' can land past the constrained position
+        assert!(theoretical_impossibility_check("suZ", true, Some((0, 2)), 6094).is_ok());
+        assert!(theoretical_impossibility_check("suZ", true, None, 6094).is_ok());
+    }
+
+    #[test]
+    fn test_suggest_easier_pattern() {
+        let anywhere = |term: &str| OptimizedSearchConfig::new_with_prefix(term, false, false, true, 49, 6094).search_range;
+
+        let (shorter, attempts) = suggest_easier_pattern("abcdefgh", false, 6094, anywhere).unwrap();
+        assert_eq!(shorter, "abcdefg");
+        assert!(attempts < SUGGESTION_THRESHOLD);
+
+        // Trailing wildcards are dropped along with the hard part
+        let (shorter, _) = suggest_easier_pattern("abcdefg?h", false, 6094, anywhere).unwrap();
+        assert_eq!(shorter, "abcdefg");
+
+        // Nothing shorter than a single character
+        assert_eq!(suggest_easier_pattern("a", false, 6094, anywhere), None);
+    }
+}
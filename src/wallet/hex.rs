@@ -1,33 +1,23 @@
 // Use substrate implementations for cryptographic operations
 use crate::crypto::substrate::sr25519::{Pair, IdentifyAccount};
 use crate::crypto::substrate::crypto::{Ss58Codec, Ss58AddressFormat};
+use crate::crypto::rng::{ChaCha20Rng, WALLET_NONCE};
 
 
+/// Generate a random 32-byte seed from a fresh system-entropy RNG
+///
+/// Seeding costs an entropy read per call; hot loops should hold a [`ChaCha20Rng`]
+/// and call [`generate_hex_seed_with_rng`] instead.
 pub fn generate_hex_seed() -> [u8; 32] {
-    use crate::crypto::rng::ChaCha20Rng;
-    
-    thread_local! {
-        static RNG: std::cell::RefCell<Option<ChaCha20Rng>> = std::cell::RefCell::new(None);
-    }
-    
+    let mut rng = ChaCha20Rng::from_system_entropy_with_nonce(WALLET_NONCE)
+        .expect("Failed to initialize cryptographically secure RNG");
+    generate_hex_seed_with_rng(&mut rng)
+}
+
+/// Generate a random 32-byte seed from the caller's RNG
+pub fn generate_hex_seed_with_rng(rng: &mut ChaCha20Rng) -> [u8; 32] {
     let mut seed = [0u8; 32];
-    
-    // Use thread-local cryptographically secure RNG
-    RNG.with(|rng_cell| {
-        let mut rng_opt = rng_cell.borrow_mut();
-        
-        // Initialize RNG if not already done
-        if rng_opt.is_none() {
-            *rng_opt = Some(ChaCha20Rng::from_system_entropy()
-                .expect("Failed to initialize cryptographically secure RNG"));
-        }
-        
-        // Generate cryptographically secure entropy
-        if let Some(ref mut rng) = *rng_opt {
-            rng.fill_bytes(&mut seed);
-        }
-    });
-    
+    rng.fill_bytes(&mut seed);
     seed
 }
 
@@ -17,7 +17,6 @@ use xsalsa20poly1305::{
     XSalsa20Poly1305,
 };
 use serde_json::json;
-use std::fs;
 use crate::crypto::schnorrkel::{MiniSecretKey, ExpansionMode, Keypair};
 
 
@@ -28,6 +27,204 @@ const SCRYPT_R: u32 = 8;
 const SCRYPT_P: u32 = 1;
 const SCRYPT_DKLEN: usize = 64;
 
+/// Range accepted for a user-chosen scrypt N (`--scrypt-n`)
+pub const SCRYPT_N_MIN: u32 = 32768;
+pub const SCRYPT_N_MAX: u32 = 4_194_304;
+/// Largest N that polkadot.js based web wallets are known to import
+pub const SCRYPT_N_WEB_WALLET_MAX: u32 = 131_072;
+/// Largest user-chosen scrypt r (`--scrypt-r`) and p (`--scrypt-p`)
+pub const SCRYPT_R_MAX: u32 = 32;
+pub const SCRYPT_P_MAX: u32 = 16;
+
+/// Argon2id defaults following the second recommended option of RFC 9106
+const ARGON2_M_COST: u32 = 65536; // 64 MiB
+const ARGON2_T_COST: u32 = 3;
+const ARGON2_P_COST: u32 = 4;
+const ARGON2_KEYLEN: usize = 32;
+
+/// Key derivation function used to encrypt JSON wallets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kdf {
+    /// scrypt; the defaults (N=32768, r=8, p=1) are the format every Polkadot wallet understands
+    Scrypt(ScryptParams),
+    /// Argon2id with `Argon2Params::default()`
+    Argon2,
+}
+
+impl Default for Kdf {
+    fn default() -> Self {
+        Kdf::Scrypt(ScryptParams::default())
+    }
+}
+
+impl Kdf {
+    /// Name used in the JSON `encoding.type` array
+    pub fn encoding_name(&self) -> &'static str {
+        match self {
+            Kdf::Scrypt(_) => "scrypt",
+            Kdf::Argon2 => "argon2",
+        }
+    }
+}
+
+/// scrypt cost parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScryptParams {
+    /// CPU/memory cost, a power of two
+    pub n: u32,
+    /// Block size
+    pub r: u32,
+    /// Parallelization
+    pub p: u32,
+}
+
+impl Default for ScryptParams {
+    fn default() -> Self {
+        Self {
+            n: SCRYPT_N,
+            r: SCRYPT_R,
+            p: SCRYPT_P,
+        }
+    }
+}
+
+impl ScryptParams {
+    /// Check user-chosen parameters: N a power of two from `SCRYPT_N_MIN` to
+    /// `SCRYPT_N_MAX`, r from 1 to `SCRYPT_R_MAX` and p from 1 to `SCRYPT_P_MAX`
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.n.is_power_of_two() || !(SCRYPT_N_MIN..=SCRYPT_N_MAX).contains(&self.n) {
+            return Err(format!(
+                "Invalid scrypt N {}: must be a power of 2 from {} to {}",
+                self.n, SCRYPT_N_MIN, SCRYPT_N_MAX
+            ));
+        }
+        if !(1..=SCRYPT_R_MAX).contains(&self.r) || !(1..=SCRYPT_P_MAX).contains(&self.p) {
+            return Err(format!(
+                "Invalid scrypt parameters (r={}, p={}): r must be 1 to {} and p 1 to {}",
+                self.r, self.p, SCRYPT_R_MAX, SCRYPT_P_MAX
+            ));
+        }
+        Params::new(self.n.trailing_zeros() as u8, self.r, self.p, SCRYPT_DKLEN)
+            .map(|_| ())
+            .map_err(|e| format!("Invalid scrypt parameters (r={}, p={}): {}", self.r, self.p, e))
+    }
+
+    /// Encode as 12 bytes: N(4) + p(4) + r(4) in little-endian
+    fn encode(&self) -> [u8; 12] {
+        let mut params = [0u8; 12];
+        params[0..4].copy_from_slice(&self.n.to_le_bytes());
+        params[4..8].copy_from_slice(&self.p.to_le_bytes());
+        params[8..12].copy_from_slice(&self.r.to_le_bytes());
+        params
+    }
+
+    /// Decode the 12-byte representation written by `encode`
+    fn decode(bytes: &[u8]) -> Self {
+        Self {
+            n: u32::from_le_bytes(bytes[0..4].try_into().unwrap()),
+            p: u32::from_le_bytes(bytes[4..8].try_into().unwrap()),
+            r: u32::from_le_bytes(bytes[8..12].try_into().unwrap()),
+        }
+    }
+
+    /// Reject parameters read from a wallet file that cost more than any this tool
+    /// writes, so a crafted file can't make verification run for hours
+    fn check_untrusted(&self) -> Result<(), String> {
+        if self.n > SCRYPT_N_MAX || self.r > SCRYPT_R_MAX || self.p > SCRYPT_P_MAX {
+            return Err(format!(
+                "Wallet scrypt parameters (N={}, r={}, p={}) exceed the supported maximum (N={}, r={}, p={})",
+                self.n, self.r, self.p, SCRYPT_N_MAX, SCRYPT_R_MAX, SCRYPT_P_MAX
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Argon2id cost parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2Params {
+    /// Memory cost in KiB
+    pub m_cost: u32,
+    /// Number of passes
+    pub t_cost: u32,
+    /// Degree of parallelism
+    pub p_cost: u32,
+}
+
+impl Default for Argon2Params {
+    fn default() -> Self {
+        Self {
+            m_cost: ARGON2_M_COST,
+            t_cost: ARGON2_T_COST,
+            p_cost: ARGON2_P_COST,
+        }
+    }
+}
+
+impl Argon2Params {
+    /// Encode as 12 bytes: m(4) + t(4) + p(4) in little-endian
+    fn encode(&self) -> [u8; 12] {
+        let mut params = [0u8; 12];
+        params[0..4].copy_from_slice(&self.m_cost.to_le_bytes());
+        params[4..8].copy_from_slice(&self.t_cost.to_le_bytes());
+        params[8..12].copy_from_slice(&self.p_cost.to_le_bytes());
+        params
+    }
+
+    /// Decode the 12-byte representation written by `encode`
+    fn decode(bytes: &[u8]) -> Self {
+        Self {
+            m_cost: u32::from_le_bytes(bytes[0..4].try_into().unwrap()),
+            t_cost: u32::from_le_bytes(bytes[4..8].try_into().unwrap()),
+            p_cost: u32::from_le_bytes(bytes[8..12].try_into().unwrap()),
+        }
+    }
+
+    /// Reject parameters read from a wallet file above the defaults, the only ones
+    /// this tool writes
+    fn check_untrusted(&self) -> Result<(), String> {
+        let max = Self::default();
+        if self.m_cost > max.m_cost || self.t_cost > max.t_cost || self.p_cost > max.p_cost {
+            return Err(format!(
+                "Wallet argon2 parameters (m={}, t={}, p={}) exceed the supported maximum (m={}, t={}, p={})",
+                self.m_cost, self.t_cost, self.p_cost, max.m_cost, max.t_cost, max.p_cost
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Derive a 32-byte encryption key with Argon2id
+pub fn derive_key_argon2(password: &str, salt: &[u8], params: &Argon2Params) -> Result<Vec<u8>, String> {
+    use argon2::{Algorithm, Argon2, Version};
+
+    let argon2_params = argon2::Params::new(params.m_cost, params.t_cost, params.p_cost, Some(ARGON2_KEYLEN))
+        .map_err(|e| format!("Invalid argon2 params: {}", e))?;
+    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, argon2_params);
+
+    let mut derived_key = vec![0u8; ARGON2_KEYLEN];
+    argon2.hash_password_into(password.as_bytes(), salt, &mut derived_key)
+        .map_err(|e| format!("Argon2 failed: {}", e))?;
+
+    Ok(derived_key)
+}
+
+/// Derive a 64-byte encryption key with scrypt (only the first 32 bytes are used)
+fn derive_key_scrypt(password: &str, salt: &[u8], n: u32, r: u32, p: u32) -> Result<Vec<u8>, String> {
+    if !n.is_power_of_two() {
+        return Err(format!("Invalid scrypt N parameter: {}", n));
+    }
+
+    let mut derived_key = vec![0u8; SCRYPT_DKLEN];
+    let params = Params::new(n.trailing_zeros() as u8, r, p, SCRYPT_DKLEN)
+        .map_err(|e| format!("Invalid scrypt params: {}", e))?;
+
+    scrypt(password.as_bytes(), salt, &params, &mut derived_key)
+        .map_err(|e| format!("Scrypt failed: {}", e))?;
+
+    Ok(derived_key)
+}
+
 
 /// Export a wallet to JSON format from a hex seed
 /// 
@@ -36,6 +233,8 @@ const SCRYPT_DKLEN: usize = 64;
 /// * `vanity_address` - The vanity address (with Autonomys prefix)
 /// * `password` - The password to encrypt the wallet
 /// * `output_path` - Path where the JSON wallet file will be saved
+/// * `kdf` - Key derivation function used for encryption
+/// * `genesis_hash` - Chain genesis hash for the `genesisHash` metadata field, if known
 /// 
 /// # Returns
 /// * `Ok(String)` - The JSON string of the exported wallet
@@ -46,6 +245,8 @@ fn wallet_json_from_seed(
     password: &str,
     output_path: &str,
     search_term: &str,
+    kdf: Kdf,
+    genesis_hash: Option<&str>,
 ) -> Result<String, String> {
     // Parse and validate the seed
     let seed = hex::decode(seed_hex)
@@ -76,13 +277,17 @@ fn wallet_json_from_seed(
     rng.fill_bytes(&mut salt);
     rng.fill_bytes(&mut nonce);
     
-    // Derive encryption key using scrypt
-    let mut derived_key = vec![0u8; SCRYPT_DKLEN];
-    let params = Params::new(15, SCRYPT_R, SCRYPT_P, SCRYPT_DKLEN)
-        .map_err(|e| format!("Invalid scrypt params: {}", e))?;
-    
-    scrypt(password.as_bytes(), &salt, &params, &mut derived_key)
-        .map_err(|e| format!("Scrypt failed: {}", e))?;
+    // Derive encryption key using the selected KDF
+    let (derived_key, kdf_params) = match kdf {
+        Kdf::Scrypt(params) => (
+            derive_key_scrypt(password, &salt, params.n, params.r, params.p)?,
+            params.encode(),
+        ),
+        Kdf::Argon2 => {
+            let params = Argon2Params::default();
+            (derive_key_argon2(password, &salt, &params)?, params.encode())
+        }
+    };
     
     // Encode keypair in PKCS8 format
     let pkcs8_data = encode_pkcs8(&secret_bytes, &public_bytes);
@@ -92,19 +297,19 @@ fn wallet_json_from_seed(
     let encrypted = cipher.encrypt(GenericArray::from_slice(&nonce), pkcs8_data.as_ref())
         .map_err(|e| format!("Encryption failed: {}", e))?;
     
-    // Build the encoded blob: salt + scrypt params + nonce + encrypted data
+    // Build the encoded blob: salt + KDF params + nonce + encrypted data
     let mut encoded = Vec::new();
     encoded.extend_from_slice(&salt);
-    encoded.extend_from_slice(&encode_scrypt_params());
+    encoded.extend_from_slice(&kdf_params);
     encoded.extend_from_slice(&nonce);
     encoded.extend_from_slice(&encrypted);
     
     // Create the JSON wallet
-    let wallet_json = json!({
+    let mut wallet_json = json!({
         "encoded": BASE64.encode(&encoded),
         "encoding": {
             "content": ["pkcs8", "sr25519"],
-            "type": ["scrypt", "xsalsa20-poly1305"],
+            "type": [kdf.encoding_name(), "xsalsa20-poly1305"],
             "version": "3"
         },
         "address": address,
@@ -112,12 +317,16 @@ fn wallet_json_from_seed(
             "name": create_wallet_name(vanity_address, search_term)
         }
     });
+    // Lets polkadot.js extension v3 tie the account to its chain
+    if let Some(genesis_hash) = genesis_hash {
+        wallet_json["meta"]["genesisHash"] = json!(genesis_hash);
+    }
     
     // Write to file
     let json_str = serde_json::to_string_pretty(&wallet_json)
         .map_err(|e| format!("Failed to serialize JSON: {}", e))?;
     
-    fs::write(output_path, &json_str)
+    super::write_file_durable(output_path, &json_str)
         .map_err(|e| format!("Failed to write file: {}", e))?;
     
     Ok(json_str)
@@ -157,13 +366,70 @@ fn encode_pkcs8(secret_key: &[u8; 64], public_key: &[u8; 32]) -> Vec<u8> {
     result
 }
 
-/// Encode scrypt parameters as 12 bytes: N(4) + p(4) + r(4) in little-endian
-fn encode_scrypt_params() -> [u8; 12] {
-    let mut params = [0u8; 12];
-    params[0..4].copy_from_slice(&SCRYPT_N.to_le_bytes());
-    params[4..8].copy_from_slice(&SCRYPT_P.to_le_bytes());
-    params[8..12].copy_from_slice(&SCRYPT_R.to_le_bytes());
-    params
+/// Offset of the public key inside the PKCS8 blob produced by `encode_pkcs8`
+const PKCS8_PUBLIC_KEY_OFFSET: usize = 85;
+/// Total length of the PKCS8 blob produced by `encode_pkcs8`
+const PKCS8_LENGTH: usize = 117;
+
+/// Decrypt a Polkadot JSON wallet and check that its key material matches its address
+///
+/// # Arguments
+/// * `wallet_json` - The JSON wallet contents
+/// * `password` - The password used to encrypt the wallet
+///
+/// # Returns
+/// * `Ok(true)` - The wallet decrypts and the embedded public key matches `address`
+/// * `Ok(false)` - The wallet decrypts but the public key does not match `address`
+/// * `Err(String)` - The wallet is malformed or the password is wrong
+pub fn verify_wallet_json(wallet_json: &str, password: &str) -> Result<bool, String> {
+    let wallet: serde_json::Value = serde_json::from_str(wallet_json)
+        .map_err(|e| format!("Invalid wallet JSON: {}", e))?;
+
+    let address = wallet["address"].as_str()
+        .ok_or_else(|| "Wallet JSON is missing the address field".to_string())?;
+    let encoded = wallet["encoded"].as_str()
+        .ok_or_else(|| "Wallet JSON is missing the encoded field".to_string())?;
+
+    if wallet["encoding"]["type"][1] != "xsalsa20-poly1305" {
+        return Err("Unsupported wallet encryption type".to_string());
+    }
+
+    let encoded = BASE64.decode(encoded)
+        .map_err(|e| format!("Invalid base64 in encoded field: {}", e))?;
+
+    // Layout: salt(32) + KDF params(12) + nonce(24) + ciphertext
+    if encoded.len() < 32 + 12 + 24 {
+        return Err("Encoded wallet data is too short".to_string());
+    }
+    let salt = &encoded[0..32];
+    let kdf_params = &encoded[32..44];
+    let nonce = &encoded[44..68];
+    let ciphertext = &encoded[68..];
+
+    let derived_key = match wallet["encoding"]["type"][0].as_str() {
+        Some("scrypt") => {
+            let params = ScryptParams::decode(kdf_params);
+            params.check_untrusted()?;
+            derive_key_scrypt(password, salt, params.n, params.r, params.p)?
+        }
+        Some("argon2") => {
+            let params = Argon2Params::decode(kdf_params);
+            params.check_untrusted()?;
+            derive_key_argon2(password, salt, &params)?
+        }
+        _ => return Err("Unsupported wallet key derivation type".to_string()),
+    };
+
+    let cipher = XSalsa20Poly1305::new(GenericArray::from_slice(&derived_key[..32]));
+    let pkcs8_data = cipher.decrypt(GenericArray::from_slice(nonce), ciphertext)
+        .map_err(|_| "Decryption failed (wrong password or corrupted wallet)".to_string())?;
+
+    if pkcs8_data.len() != PKCS8_LENGTH {
+        return Err(format!("Unexpected PKCS8 length: {}", pkcs8_data.len()));
+    }
+
+    let public_key = &pkcs8_data[PKCS8_PUBLIC_KEY_OFFSET..PKCS8_LENGTH];
+    Ok(create_ss58_address(public_key) == address)
 }
 
 /// Create an SS58 address from a public key (uses Substrate prefix 42)
@@ -194,8 +460,11 @@ pub fn save_wallet_json(
     password: &str,
     search_term: &str,
     output_dir: &str,
+    file_stem: &str,
+    kdf: Kdf,
+    genesis_hash: Option<&str>,
 ) -> Result<(), String> {
-    let filename = format!("{}.json", result.address);
+    let filename = format!("{}.json", file_stem);
     let output_path = format!("{}/{}", output_dir, filename);
     
     wallet_json_from_seed(
@@ -204,6 +473,8 @@ pub fn save_wallet_json(
         password,
         &output_path,
         search_term,
+        kdf,
+        genesis_hash,
     )?;
     
     Ok(())
@@ -213,13 +484,19 @@ pub fn save_wallet_json(
 pub fn save_wallet_mnemonic(
     result: &crate::runner::VanityResult,
     output_dir: &str,
+    file_stem: &str,
+    pbkdf2_rounds: u32,
 ) -> Result<(), String> {
-    let filename = format!("{}.txt", result.address);
+    let filename = format!("{}.txt", file_stem);
     let output_path = format!("{}/{}", output_dir, filename);
     
-    let content = format!("Address: {}\nMnemonic: {}\n", result.address, result.secret);
+    // Record the PBKDF2 round count so the derived key can be reproduced
+    let content = format!(
+        "PBKDF2-rounds: {}\nAddress: {}\nMnemonic: {}\n",
+        pbkdf2_rounds, result.address, result.secret
+    );
     
-    fs::write(&output_path, content)
+    super::write_file_durable(&output_path, content)
         .map_err(|e| format!("Failed to write mnemonic file: {}", e))?;
     
     Ok(())
@@ -239,7 +516,9 @@ mod tests {
             "suTestVanityAddress1234",  // Example vanity address
             "testpass123",
             test_path,
-            "test"
+            "test",
+            Kdf::default(),
+            Some(crate::networks::AUTONOMYS_GENESIS_HASH),
         );
         
         assert!(result.is_ok());
@@ -255,7 +534,131 @@ mod tests {
         assert_eq!(wallet["encoding"]["type"][1], "xsalsa20-poly1305");
         assert_eq!(wallet["encoding"]["content"][0], "pkcs8");
         assert_eq!(wallet["encoding"]["content"][1], "sr25519");
+        assert_eq!(wallet["meta"]["genesisHash"], crate::networks::AUTONOMYS_GENESIS_HASH);
         
         std::fs::remove_file(test_path).ok();
     }
+
+    #[test]
+    fn test_wallet_export_argon2() {
+        let test_path = ".devonly/test_wallet_argon2.json";
+        std::fs::create_dir_all(".devonly").ok();
+
+        let json = wallet_json_from_seed(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "suTestVanityAddress1234",
+            "testpass123",
+            test_path,
+            "test",
+            Kdf::Argon2,
+            None,
+        ).unwrap();
+
+        let wallet: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(wallet["address"], "5DP4qTec9XxffaALGWsEPhS1oWrDWMBjzhBmyzShREMJpymt");
+        assert_eq!(wallet["encoding"]["type"][0], "argon2");
+        assert!(wallet["meta"].get("genesisHash").is_none());
+        assert_eq!(wallet["encoding"]["type"][1], "xsalsa20-poly1305");
+
+        // Both KDFs must remain verifiable
+        assert_eq!(verify_wallet_json(&json, "testpass123"), Ok(true));
+        assert!(verify_wallet_json(&json, "wrongpass").is_err());
+
+        std::fs::remove_file(test_path).ok();
+    }
+
+    #[test]
+    fn test_wallet_export_custom_scrypt() {
+        let test_path = ".devonly/test_wallet_scrypt.json";
+        std::fs::create_dir_all(".devonly").ok();
+
+        let params = ScryptParams { n: 65536, r: 8, p: 2 };
+        let json = wallet_json_from_seed(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "suTestVanityAddress1234",
+            "testpass123",
+            test_path,
+            "test",
+            Kdf::Scrypt(params),
+            None,
+        ).unwrap();
+
+        // The chosen parameters travel with the wallet, so it still decrypts
+        let wallet: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let encoded = BASE64.decode(wallet["encoded"].as_str().unwrap()).unwrap();
+        assert_eq!(ScryptParams::decode(&encoded[32..44]), params);
+        assert_eq!(verify_wallet_json(&json, "testpass123"), Ok(true));
+
+        std::fs::remove_file(test_path).ok();
+    }
+
+    #[test]
+    fn test_scrypt_params_validate() {
+        assert!(ScryptParams::default().validate().is_ok());
+        assert!(ScryptParams { n: SCRYPT_N_MAX, ..ScryptParams::default() }.validate().is_ok());
+        assert!(ScryptParams { n: 16384, ..ScryptParams::default() }.validate().is_err());
+        assert!(ScryptParams { n: 8_388_608, ..ScryptParams::default() }.validate().is_err());
+        assert!(ScryptParams { n: 100_000, ..ScryptParams::default() }.validate().is_err());
+        assert!(ScryptParams { r: 0, ..ScryptParams::default() }.validate().is_err());
+        assert!(ScryptParams { p: 0, ..ScryptParams::default() }.validate().is_err());
+        assert!(ScryptParams { r: SCRYPT_R_MAX, p: SCRYPT_P_MAX, ..ScryptParams::default() }.validate().is_ok());
+        assert!(ScryptParams { r: SCRYPT_R_MAX + 1, ..ScryptParams::default() }.validate().is_err());
+        assert!(ScryptParams { p: SCRYPT_P_MAX + 1, ..ScryptParams::default() }.validate().is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_oversized_kdf_params() {
+        let test_path = ".devonly/test_wallet_oversized_kdf.json";
+        std::fs::create_dir_all(".devonly").ok();
+
+        // Overwrite the 12 parameter bytes after the salt, as a crafted file would
+        let with_params = |json: &str, params: [u8; 12]| {
+            let mut wallet: serde_json::Value = serde_json::from_str(json).unwrap();
+            let mut encoded = BASE64.decode(wallet["encoded"].as_str().unwrap()).unwrap();
+            encoded[32..44].copy_from_slice(&params);
+            wallet["encoded"] = BASE64.encode(encoded).into();
+            wallet.to_string()
+        };
+
+        let seed = "0000000000000000000000000000000000000000000000000000000000000001";
+        let json = wallet_json_from_seed(seed, "suTestVanityAddress1234", "testpass123", test_path, "test", Kdf::default(), None).unwrap();
+        for params in [
+            ScryptParams { n: 1 << 30, ..ScryptParams::default() },
+            ScryptParams { r: u32::MAX, ..ScryptParams::default() },
+            ScryptParams { p: SCRYPT_P_MAX + 1, ..ScryptParams::default() },
+        ] {
+            let err = verify_wallet_json(&with_params(&json, params.encode()), "testpass123").unwrap_err();
+            assert!(err.contains("exceed the supported maximum"), "{params:?}: {err}");
+        }
+
+        let json = wallet_json_from_seed(seed, "suTestVanityAddress1234", "testpass123", test_path, "test", Kdf::Argon2, None).unwrap();
+        let params = Argon2Params { m_cost: u32::MAX, ..Argon2Params::default() };
+        let err = verify_wallet_json(&with_params(&json, params.encode()), "testpass123").unwrap_err();
+        assert!(err.contains("exceed the supported maximum"), "{err}");
+
+        std::fs::remove_file(test_path).ok();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_wallet_files_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let output_dir = ".devonly/permissions";
+        std::fs::create_dir_all(output_dir).ok();
+        let result = crate::runner::VanityResult::for_test(
+            "suTestVanityAddress1234",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        );
+
+        save_wallet_json(&result, "testpass123", "test", output_dir, "wallet", Kdf::default(), None).unwrap();
+        save_wallet_mnemonic(&result, output_dir, "wallet", 2048).unwrap();
+
+        for file in ["wallet.json", "wallet.txt"] {
+            let mode = std::fs::metadata(format!("{output_dir}/{file}")).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600, "{file}");
+        }
+
+        std::fs::remove_dir_all(output_dir).ok();
+    }
 }
\ No newline at end of file
@@ -0,0 +1,96 @@
+//! Output directory lock so two runs never write to the same directory
+//!
+//! The lock is a `.autoseed_lock` file created with `create_new`, so creation fails
+//! while another instance holds it. It records the holder's PID to help clear a lock
+//! left behind by a crashed run.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// Lock file name inside a locked directory
+pub const LOCK_FILENAME: &str = ".autoseed_lock";
+
+/// Lock files held by this process; Ctrl+C exits without running `Drop`
+fn held_locks() -> &'static Mutex<Vec<PathBuf>> {
+    static HELD: OnceLock<Mutex<Vec<PathBuf>>> = OnceLock::new();
+    HELD.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+/// Exclusive hold on an output directory, released when dropped
+#[derive(Debug)]
+pub struct DirectoryLock {
+    path: PathBuf,
+}
+
+impl DirectoryLock {
+    /// Create the lock file in `dir`, failing if another instance already holds it
+    pub fn acquire(dir: &str) -> Result<Self, String> {
+        let path = Path::new(dir).join(LOCK_FILENAME);
+
+        let mut file = match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                let holder = std::fs::read_to_string(&path).unwrap_or_default();
+                let holder = holder.trim();
+                let holder = if holder.is_empty() { String::new() } else { format!(" (PID {holder})") };
+                return Err(format!(
+                    "Output directory '{}' is in use by another autoseed instance{}\nSUGGESTION: Wait for it to finish, or delete {} if no other instance is running",
+                    dir,
+                    holder,
+                    path.display()
+                ));
+            }
+            Err(e) => return Err(format!("Failed to create lock file {}: {}", path.display(), e)),
+        };
+
+        // The PID is only a hint for the error above; the lock holds without it
+        let _ = write!(file, "{}", std::process::id());
+        held_locks().lock().unwrap().push(path.clone());
+        Ok(Self { path })
+    }
+}
+
+impl Drop for DirectoryLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+        held_locks().lock().unwrap().retain(|held| held != &self.path);
+    }
+}
+
+/// Remove every lock this process holds; for exit paths that skip `Drop`
+pub fn release_all() {
+    // try_lock: never block a signal handler on a lock the main thread holds
+    if let Ok(mut held) = held_locks().try_lock() {
+        for path in held.drain(..) {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_is_exclusive_until_dropped() {
+        let dir = std::env::temp_dir().join(format!("autoseed_lock_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir_str = dir.to_str().unwrap();
+
+        let lock = DirectoryLock::acquire(dir_str).unwrap();
+        let lock_file = dir.join(LOCK_FILENAME);
+        assert_eq!(std::fs::read_to_string(&lock_file).unwrap(), std::process::id().to_string());
+
+        let err = DirectoryLock::acquire(dir_str).unwrap_err();
+        assert!(err.contains("in use by another autoseed instance"));
+        assert!(err.contains(&format!("PID {}", std::process::id())));
+
+        drop(lock);
+        assert!(!lock_file.exists());
+        drop(DirectoryLock::acquire(dir_str).unwrap());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
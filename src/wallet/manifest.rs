@@ -0,0 +1,183 @@
+//! Run manifest listing every saved wallet
+//!
+//! The manifest is written once all wallets are found and only carries public
+//! metadata (address, file name, attempts, luck, timing). Secrets never appear here.
+
+use crate::runner::VanityResult;
+use serde_json::json;
+use std::path::{Component, Path, PathBuf};
+
+/// Manifest file name inside the output directory
+pub const MANIFEST_FILENAME: &str = "manifest.json";
+
+/// Per-wallet statistics captured while processing each found wallet
+#[derive(Debug, Clone)]
+pub struct WalletStat {
+    /// 1-based position of this wallet in the run's results; a failed save leaves a gap
+    pub index: usize,
+    /// File the wallet was saved under, relative to the manifest's directory (see
+    /// `manifest_file_path`)
+    pub file: String,
+    /// Luck percentage for this wallet (expected / actual * 100)
+    pub luck_pct: f64,
+    /// Time taken to find this wallet in milliseconds
+    pub found_in_ms: u64,
+}
+
+/// Path of `file_name` in `wallet_dir` relative to `manifest_dir`, as the manifest lists it
+///
+/// Both directories are resolved first, so `out` and `./out/` compare equal. When they
+/// share no root (different drives on Windows), the resolved absolute path is used.
+pub fn manifest_file_path(manifest_dir: &str, wallet_dir: &str, file_name: &str) -> String {
+    let resolve = |dir: &str| Path::new(dir).canonicalize().unwrap_or_else(|_| PathBuf::from(dir));
+    let (from, to) = (resolve(manifest_dir), resolve(wallet_dir));
+
+    let common = from.components().zip(to.components()).take_while(|(a, b)| a == b).count();
+    let dir = if common == 0 {
+        to
+    } else {
+        from.components()
+            .skip(common)
+            .map(|_| Component::ParentDir)
+            .chain(to.components().skip(common))
+            .collect()
+    };
+    dir.join(file_name).display().to_string()
+}
+
+/// Build the manifest JSON array from the stats of the saved wallets
+fn build_manifest(results: &[VanityResult], stats: &[WalletStat]) -> serde_json::Value {
+    let entries: Vec<serde_json::Value> = stats
+        .iter()
+        .filter_map(|stat| Some((stat, results.get(stat.index.checked_sub(1)?)?)))
+        .map(|(stat, result)| {
+            json!({
+                "index": stat.index,
+                "address": result.address,
+                "file": stat.file,
+                "attempts": result.attempts,
+                "luck_pct": (stat.luck_pct * 10.0).round() / 10.0,
+                "found_in_ms": stat.found_in_ms,
+            })
+        })
+        .collect();
+
+    serde_json::Value::Array(entries)
+}
+
+/// Write `<output_dir>/manifest.json` describing all saved wallets
+///
+/// Only wallets that were saved have a stat, so a failed save leaves no entry and
+/// the remaining entries keep their index into `results`.
+pub fn write_manifest(results: &[VanityResult], stats: &[WalletStat], output_dir: &str) -> Result<(), String> {
+    let manifest = build_manifest(results, stats);
+    let json_str = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+
+    let output_path = format!("{}/{}", output_dir, MANIFEST_FILENAME);
+    super::write_file_durable(&output_path, json_str)
+        .map_err(|e| format!("Failed to write manifest: {}", e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vanity_result(address: &str, attempts: u64) -> VanityResult {
+        VanityResult {
+            address: address.to_string(),
+            secret: "abandon ".repeat(11) + "about",
+            matches: 2,
+            offset: 2,
+            attempts,
+            expected_attempts: 10_000,
+            ss58_prefix: 42,
+            network_name: "Substrate".to_string(),
+            match_detail: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_manifest_lists_saved_wallets() {
+        let results = vec![vanity_result("suTestAddress123", 12345), vanity_result("suTestAddress789", 678)];
+        let stats: Vec<WalletStat> = results
+            .iter()
+            .enumerate()
+            .map(|(i, result)| WalletStat {
+                index: i + 1,
+                file: format!("{}.txt", result.address),
+                luck_pct: 87.345,
+                found_in_ms: 43200,
+            })
+            .collect();
+
+        let manifest = build_manifest(&results, &stats);
+        assert_eq!(manifest[0]["index"], 1);
+        assert_eq!(manifest[0]["address"], "suTestAddress123");
+        assert_eq!(manifest[0]["file"], "suTestAddress123.txt");
+        assert_eq!(manifest[0]["attempts"], 12345);
+        assert_eq!(manifest[0]["luck_pct"], 87.3);
+        assert_eq!(manifest[0]["found_in_ms"], 43200);
+        assert_eq!(manifest[1]["index"], 2);
+        assert_eq!(manifest[1]["address"], "suTestAddress789");
+        assert_eq!(manifest[1]["file"], "suTestAddress789.txt");
+        assert_eq!(manifest[1]["attempts"], 678);
+        assert_eq!(manifest.as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_manifest_keeps_indices_after_failed_save() {
+        let dir = ".devonly/manifest_failed_save";
+        std::fs::create_dir_all(dir).unwrap();
+        // A directory where the second wallet's file should go makes that save fail
+        std::fs::create_dir_all(format!("{dir}/suSecond.txt")).unwrap();
+
+        let results = vec![
+            vanity_result("suFirst", 100),
+            vanity_result("suSecond", 200),
+            vanity_result("suThird", 300),
+        ];
+        let mut stats = Vec::new();
+        for (i, result) in results.iter().enumerate() {
+            if crate::wallet::save_wallet_mnemonic(result, dir, &result.address, 2048).is_ok() {
+                stats.push(WalletStat {
+                    index: i + 1,
+                    file: format!("{}.txt", result.address),
+                    luck_pct: 100.0,
+                    found_in_ms: 10,
+                });
+            }
+        }
+        write_manifest(&results, &stats, dir).unwrap();
+
+        let manifest: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(format!("{dir}/{MANIFEST_FILENAME}")).unwrap()).unwrap();
+        std::fs::remove_dir_all(dir).ok();
+
+        let entries = manifest.as_array().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["index"], 1);
+        assert_eq!(entries[0]["address"], "suFirst");
+        assert_eq!(entries[1]["index"], 3);
+        assert_eq!(entries[1]["address"], "suThird");
+        assert_eq!(entries[1]["attempts"], 300);
+    }
+
+    #[test]
+    fn test_manifest_file_path() {
+        let root = ".devonly/manifest_paths";
+        for dir in ["out/json", "mnemonics"] {
+            std::fs::create_dir_all(format!("{root}/{dir}")).unwrap();
+        }
+
+        let out = format!("{root}/out");
+        assert_eq!(manifest_file_path(&out, &out, "a.txt"), "a.txt");
+        assert_eq!(manifest_file_path(&out, &format!("./{root}/out/"), "a.txt"), "a.txt");
+        assert_eq!(manifest_file_path(&out, &format!("{root}/out/json"), "a.json"), "json/a.json");
+        assert_eq!(manifest_file_path(&out, &format!("{root}/mnemonics"), "a.txt"), "../mnemonics/a.txt");
+
+        std::fs::remove_dir_all(root).ok();
+    }
+}
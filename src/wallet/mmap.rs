@@ -0,0 +1,148 @@
+//! Append-only record log through a memory-mapped file
+//!
+//! Runs that find thousands of wallets pay an open/write/close per file. `MmapWriter`
+//! instead pre-allocates one large file, maps it, and copies each record to the next
+//! free offset. Offsets are handed out by an atomic cursor, so concurrent writers
+//! never overlap. When the mapping fills, the file is grown and remapped: with
+//! `mremap` on Linux, by mapping the grown file again on other platforms.
+//!
+//! The file is truncated to the bytes actually written when the writer is dropped.
+
+use memmap2::MmapMut;
+use std::fs::{File, OpenOptions};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Bytes pre-allocated when no size is given (256 MiB)
+pub const DEFAULT_MMAP_SIZE: usize = 256 * 1024 * 1024;
+
+/// Sequential writer over a memory-mapped file
+#[derive(Debug)]
+pub struct MmapWriter {
+    file: File,
+    /// Always `Some` until the writer is dropped
+    map: Mutex<Option<MmapMut>>,
+    /// End of the last reserved record
+    cursor: AtomicUsize,
+}
+
+impl MmapWriter {
+    /// Create (or truncate) `path` with [`DEFAULT_MMAP_SIZE`] bytes pre-allocated
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, String> {
+        Self::with_size(path, DEFAULT_MMAP_SIZE)
+    }
+
+    /// Create (or truncate) `path` with `size` bytes pre-allocated
+    pub fn with_size(path: impl AsRef<Path>, size: usize) -> Result<Self, String> {
+        let path = path.as_ref();
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        file.set_len(size.max(1) as u64)
+            .map_err(|e| format!("Failed to allocate {} bytes for {}: {}", size, path.display(), e))?;
+        // SAFETY: the file was just created by this process and is only written
+        // through this mapping
+        let map = unsafe { MmapMut::map_mut(&file) }
+            .map_err(|e| format!("Failed to map {}: {}", path.display(), e))?;
+
+        Ok(Self { file, map: Mutex::new(Some(map)), cursor: AtomicUsize::new(0) })
+    }
+
+    /// Append `record`, growing the mapping if it doesn't fit; returns its offset
+    pub fn write_record(&self, record: &[u8]) -> Result<usize, String> {
+        let offset = self.cursor.fetch_add(record.len(), Ordering::Relaxed);
+        let end = offset + record.len();
+
+        let mut guard = self.map.lock().unwrap();
+        let map = guard.as_mut().expect("mapping is only released on drop");
+        if end > map.len() {
+            // Double, so a long run remaps a logarithmic number of times
+            let new_len = end.max(map.len() * 2);
+            self.grow(map, new_len)?;
+        }
+        map[offset..end].copy_from_slice(record);
+        Ok(offset)
+    }
+
+    /// Bytes written so far
+    pub fn len(&self) -> usize {
+        self.cursor.load(Ordering::Relaxed)
+    }
+
+    /// Whether nothing has been written yet
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Flush written records to disk
+    pub fn flush(&self) -> Result<(), String> {
+        match self.map.lock().unwrap().as_ref() {
+            Some(map) => map.flush().map_err(|e| format!("Failed to flush mapped file: {}", e)),
+            None => Ok(()),
+        }
+    }
+
+    /// Extend the file to `new_len` bytes and remap it
+    fn grow(&self, map: &mut MmapMut, new_len: usize) -> Result<(), String> {
+        self.file
+            .set_len(new_len as u64)
+            .map_err(|e| format!("Failed to grow mapped file to {} bytes: {}", new_len, e))?;
+
+        #[cfg(target_os = "linux")]
+        {
+            // SAFETY: the file was extended to `new_len` above, and the lock held by
+            // the caller keeps every other user off the mapping while it moves
+            unsafe { map.remap(new_len, memmap2::RemapOptions::new().may_move(true)) }
+                .map_err(|e| format!("Failed to remap file to {} bytes: {}", new_len, e))
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            map.flush().map_err(|e| format!("Failed to flush mapped file: {}", e))?;
+            // SAFETY: as in `with_size`; the old mapping is replaced under the caller's lock
+            *map = unsafe { MmapMut::map_mut(&self.file) }
+                .map_err(|e| format!("Failed to remap file to {} bytes: {}", new_len, e))?;
+            Ok(())
+        }
+    }
+}
+
+impl Drop for MmapWriter {
+    fn drop(&mut self) {
+        // Best effort: drop the unused pre-allocated tail. Unmap first, since Windows
+        // won't shrink a mapped file
+        let _ = self.flush();
+        if let Ok(map) = self.map.get_mut() {
+            drop(map.take());
+        }
+        let _ = self.file.set_len(self.len() as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mmap_writer_grows_and_truncates() {
+        let path = std::env::temp_dir().join(format!("autoseed_mmap_{}.log", std::process::id()));
+
+        {
+            let writer = MmapWriter::with_size(&path, 8).unwrap();
+            assert!(writer.is_empty());
+            assert_eq!(writer.write_record(b"first\n").unwrap(), 0);
+            // Past the 8 pre-allocated bytes, so the mapping has to grow
+            assert_eq!(writer.write_record(b"second record\n").unwrap(), 6);
+            writer.flush().unwrap();
+            assert_eq!(writer.len(), 20);
+        }
+
+        assert_eq!(std::fs::read(&path).unwrap(), b"first\nsecond record\n");
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -1,21 +1,109 @@
 use crate::crypto::bip39::Mnemonic;
 use crate::crypto::pbkdf2::pbkdf2_hmac_sha512;
 
+/// PBKDF2 round count defined by BIP39 and used by every Substrate wallet
+pub const DEFAULT_PBKDF2_ROUNDS: u32 = 2048;
+
 pub fn generate_mnemonic() -> Mnemonic {
     crate::crypto::bip39::generate_mnemonic()
 }
 
-pub fn mnemonic_to_mini_secret(mnemonic: &Mnemonic, password: &str) -> [u8; 32] {
+pub fn generate_mnemonic_with_rng(rng: &mut crate::crypto::rng::ChaCha20Rng) -> Mnemonic {
+    crate::crypto::bip39::generate_mnemonic_with_rng(rng)
+}
+
+/// Generate `count` fresh mnemonics of `word_count` words (`--generate-mnemonic`)
+pub fn generate_mnemonics(count: usize, word_count: usize) -> Result<Vec<Mnemonic>, String> {
+    use crate::crypto::rng::{ChaCha20Rng, WALLET_NONCE};
+
+    let mut rng = ChaCha20Rng::from_system_entropy_with_nonce(WALLET_NONCE)
+        .map_err(|e| format!("Failed to initialize RNG: {}", e))?;
+    (0..count)
+        .map(|_| {
+            crate::crypto::bip39::generate_mnemonic_with_word_count(&mut rng, word_count)
+                .map_err(|_| format!("Mnemonics have 12, 15, 18, 21 or 24 words, not {}", word_count))
+        })
+        .collect()
+}
+
+pub fn mnemonic_to_mini_secret(mnemonic: &Mnemonic, password: &str, rounds: u32) -> [u8; 32] {
     let entropy = mnemonic.to_entropy();
-    mnemonic_to_mini_secret_from_entropy(&entropy, password)
+    mnemonic_to_mini_secret_from_entropy(&entropy, password, rounds)
 }
 
 /// High-performance PBKDF2 using our optimized local implementation
-pub fn mnemonic_to_mini_secret_from_entropy(entropy: &[u8], password: &str) -> [u8; 32] {
+pub fn mnemonic_to_mini_secret_from_entropy(entropy: &[u8], password: &str, rounds: u32) -> [u8; 32] {
     let salt = format!("mnemonic{password}");
     let mut output = [0u8; 32];
     
-    pbkdf2_hmac_sha512(entropy, salt.as_bytes(), 2048, &mut output);
+    pbkdf2_hmac_sha512(entropy, salt.as_bytes(), rounds, &mut output);
+    
+    output
+}
+
+/// Every intermediate value of a mnemonic's address derivation (`--show-derivation`)
+#[derive(Debug, Clone, zeroize::Zeroize, zeroize::ZeroizeOnDrop)]
+pub struct MnemonicDerivation {
+    /// BIP39 entropy the words encode (16 bytes for 12 words)
+    pub entropy: Vec<u8>,
+    /// PBKDF2 salt: "mnemonic" followed by the (empty) password
+    pub salt: String,
+    /// PBKDF2 output the sr25519 keypair expands from
+    pub mini_secret: [u8; 32],
+    pub public_key: [u8; 32],
+    pub address: String,
+}
+
+/// Derive `mnemonic`'s address step by step, keeping each intermediate value
+pub fn mnemonic_derivation(mnemonic: &Mnemonic, ss58_prefix: u16, rounds: u32) -> MnemonicDerivation {
+    let entropy = mnemonic.to_entropy();
+    let salt = "mnemonic".to_string();
+    let mini_secret = mnemonic_to_mini_secret_from_entropy(&entropy, "", rounds);
+    let pair = crate::crypto::substrate::sr25519::Pair::from_seed_slice(&mini_secret).expect("32-byte mini secret");
+    let mut public_key = [0u8; 32];
+    public_key.copy_from_slice(pair.public().as_ref());
+
+    MnemonicDerivation {
+        entropy,
+        salt,
+        mini_secret,
+        public_key,
+        address: crate::crypto::mini_secret_to_address(&mini_secret, ss58_prefix),
+    }
+}
+
+/// Standard BIP39 seed: PBKDF2-HMAC-SHA512 of the mnemonic *phrase*, salted with
+/// `"mnemonic" + passphrase`, both NFKD-normalized, 2048 rounds, all 64 bytes
+///
+/// This is the seed BIP32/BIP44 wallets derive from. Substrate wallets don't use it:
+/// [`mnemonic_to_mini_secret`] stretches the entropy bytes instead and keeps 32 bytes,
+/// so the two never agree.
+pub fn mnemonic_to_bip39_seed(mnemonic: &Mnemonic, passphrase: &str) -> [u8; 64] {
+    use unicode_normalization::UnicodeNormalization;
+    use zeroize::Zeroize;
+
+    let mut phrase: String = mnemonic.to_string().nfkd().collect();
+    let mut salt: String = format!("mnemonic{passphrase}").nfkd().collect();
+    let mut seed = [0u8; 64];
+    pbkdf2_hmac_sha512(phrase.as_bytes(), salt.as_bytes(), DEFAULT_PBKDF2_ROUNDS, &mut seed);
+    phrase.zeroize();
+    salt.zeroize();
+    seed
+}
+
+/// Four mnemonics through the AVX2 batched PBKDF2, one SIMD lane each
+#[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+pub fn mnemonics_to_mini_secrets_batch_4(mnemonics: &[Mnemonic; 4], password: &str, rounds: u32) -> [[u8; 32]; 4] {
+    let entropies = mnemonics.each_ref().map(Mnemonic::to_entropy);
+    let salt = format!("mnemonic{password}");
+    let mut output = [[0u8; 32]; 4];
+    
+    crate::crypto::pbkdf2::pbkdf2_hmac_sha512_batch_4(
+        entropies.each_ref().map(Vec::as_slice),
+        salt.as_bytes(),
+        rounds,
+        &mut output,
+    );
     
     output
 }
@@ -24,6 +112,20 @@ pub fn mnemonic_to_mini_secret_from_entropy(entropy: &[u8], password: &str) -> [
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_generate_mnemonics() {
+        let mnemonics = generate_mnemonics(3, 24).unwrap();
+        assert_eq!(mnemonics.len(), 3);
+        for mnemonic in &mnemonics {
+            assert_eq!(mnemonic.to_string().split_whitespace().count(), 24);
+            assert_eq!(mnemonic.to_entropy().len(), 32);
+        }
+        assert_ne!(mnemonics[0], mnemonics[1]);
+
+        assert_eq!(generate_mnemonics(1, 15).unwrap()[0].to_string().split_whitespace().count(), 15);
+        assert!(generate_mnemonics(1, 13).unwrap_err().contains("not 13"));
+    }
+
     #[test]
     fn test_generate_mnemonic() {
         let mnemonic1 = generate_mnemonic();
@@ -37,24 +139,69 @@ mod tests {
         assert_ne!(mnemonic1.to_string(), mnemonic2.to_string());
     }
 
+    #[test]
+    fn test_mnemonic_derivation() {
+        let mnemonic = Mnemonic::parse("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about").unwrap();
+        let derivation = mnemonic_derivation(&mnemonic, 6094, DEFAULT_PBKDF2_ROUNDS);
+
+        assert_eq!(derivation.entropy, [0u8; 16]);
+        assert_eq!(derivation.salt, "mnemonic");
+        assert_eq!(derivation.mini_secret, mnemonic_to_mini_secret(&mnemonic, "", DEFAULT_PBKDF2_ROUNDS));
+        // Same address as tests/known_vectors.rs
+        assert_eq!(derivation.address, "sudPaS7EUBJjjUJr6AyF6PYcpCf4B6GJKvoVKJh9kLdjPVsfE");
+        assert_eq!(derivation.address, crate::crypto::mnemonic_to_address_with_prefix(&mnemonic, 6094));
+        let pair = crate::crypto::substrate::sr25519::Pair::from_seed_slice(&derivation.mini_secret).unwrap();
+        assert_eq!(&derivation.public_key[..], pair.public().as_ref());
+    }
+
+    #[test]
+    fn test_mnemonic_to_bip39_seed() {
+        // Official BIP39 test vectors (passphrase "TREZOR")
+        let vectors = [
+            (
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+                "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+            ),
+            (
+                "legal winner thank year wave sausage worth useful legal winner thank yellow",
+                "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607",
+            ),
+            (
+                "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+                "ac27495480225222079d7be181583751e86f571027b0497b5b5d11218e0a8a13332572917f0f8e5a589620c6f15b11c61dee327651a14c34e18231052e48c069",
+            ),
+        ];
+        for (phrase, seed) in vectors {
+            let mnemonic = Mnemonic::parse(phrase).unwrap();
+            assert_eq!(hex::encode(mnemonic_to_bip39_seed(&mnemonic, "TREZOR")), seed, "{phrase}");
+        }
+
+        // The passphrase is NFKD-normalized: "が" decomposes to "か" + U+3099
+        let mnemonic = Mnemonic::parse(vectors[0].0).unwrap();
+        assert_eq!(mnemonic_to_bip39_seed(&mnemonic, "あが"), mnemonic_to_bip39_seed(&mnemonic, "あか\u{3099}"));
+
+        // Not the Substrate mini secret
+        assert_ne!(mnemonic_to_bip39_seed(&mnemonic, "")[..32], mnemonic_to_mini_secret(&mnemonic, "", DEFAULT_PBKDF2_ROUNDS));
+    }
+
     #[test]
     fn test_mnemonic_to_mini_secret() {
         let mnemonic = Mnemonic::parse("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about")
             .expect("Known valid mnemonic");
 
         // Test with empty password
-        let secret1 = mnemonic_to_mini_secret(&mnemonic, "");
+        let secret1 = mnemonic_to_mini_secret(&mnemonic, "", DEFAULT_PBKDF2_ROUNDS);
         assert_eq!(secret1.len(), 32);
 
         // Test with password
-        let secret2 = mnemonic_to_mini_secret(&mnemonic, "password");
+        let secret2 = mnemonic_to_mini_secret(&mnemonic, "password", DEFAULT_PBKDF2_ROUNDS);
         assert_eq!(secret2.len(), 32);
 
         // Different passwords should produce different secrets
         assert_ne!(secret1, secret2);
 
         // Same mnemonic and password should produce same secret
-        let secret3 = mnemonic_to_mini_secret(&mnemonic, "password");
+        let secret3 = mnemonic_to_mini_secret(&mnemonic, "password", DEFAULT_PBKDF2_ROUNDS);
         assert_eq!(secret2, secret3);
     }
 
@@ -66,19 +213,32 @@ mod tests {
         )
         .expect("Known valid mnemonic");
 
-        let secret1 = mnemonic_to_mini_secret(&mnemonic, "");
-        let secret2 = mnemonic_to_mini_secret(&mnemonic, "");
+        let secret1 = mnemonic_to_mini_secret(&mnemonic, "", DEFAULT_PBKDF2_ROUNDS);
+        let secret2 = mnemonic_to_mini_secret(&mnemonic, "", DEFAULT_PBKDF2_ROUNDS);
 
         assert_eq!(secret1, secret2);
     }
 
+    #[test]
+    fn test_mnemonic_custom_rounds() {
+        let mnemonic = Mnemonic::parse("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about")
+            .expect("Known valid mnemonic");
+
+        let standard = mnemonic_to_mini_secret(&mnemonic, "", DEFAULT_PBKDF2_ROUNDS);
+        let stretched = mnemonic_to_mini_secret(&mnemonic, "", 4096);
+
+        // Different round counts must derive different secrets
+        assert_ne!(standard, stretched);
+        assert_eq!(stretched, mnemonic_to_mini_secret(&mnemonic, "", 4096));
+    }
+
     #[test]
     fn test_mnemonic_entropy_cleared() {
         // This test verifies that we're properly handling sensitive data
         // by ensuring we can generate multiple mnemonics without issues
         for _ in 0..10 {
             let mnemonic = generate_mnemonic();
-            let _secret = mnemonic_to_mini_secret(&mnemonic, "");
+            let _secret = mnemonic_to_mini_secret(&mnemonic, "", DEFAULT_PBKDF2_ROUNDS);
             // If entropy wasn't properly cleared, we might see issues here
         }
     }
@@ -96,7 +256,7 @@ mod tests {
         
         for _ in 0..iterations {
             let start = Instant::now();
-            let _secret = mnemonic_to_mini_secret(&mnemonic, "");
+            let _secret = mnemonic_to_mini_secret(&mnemonic, "", DEFAULT_PBKDF2_ROUNDS);
             times.push(start.elapsed());
         }
         
@@ -113,7 +273,7 @@ mod tests {
         let mut local_times = Vec::new();
         for _ in 0..iterations {
             let start = Instant::now();
-            let _secret = mnemonic_to_mini_secret_from_entropy(&entropy, "");
+            let _secret = mnemonic_to_mini_secret_from_entropy(&entropy, "", DEFAULT_PBKDF2_ROUNDS);
             local_times.push(start.elapsed());
         }
         
@@ -173,7 +333,7 @@ mod tests {
         }
         
         // Verify our implementation matches external
-        let local_result = mnemonic_to_mini_secret_from_entropy(&entropy, "test");
+        let local_result = mnemonic_to_mini_secret_from_entropy(&entropy, "test", DEFAULT_PBKDF2_ROUNDS);
         let external_result = mnemonic_to_mini_secret_from_entropy_external(&entropy, "test");
         assert_eq!(local_result, external_result, "Local and external implementations produce different results!");
         
@@ -181,8 +341,8 @@ mod tests {
         assert!(avg.as_millis() < 150, "PBKDF2 took too long: {:?}", avg);
         
         // Test consistency
-        let secret1 = mnemonic_to_mini_secret(&mnemonic, "");
-        let secret2 = mnemonic_to_mini_secret(&mnemonic, "");
+        let secret1 = mnemonic_to_mini_secret(&mnemonic, "", DEFAULT_PBKDF2_ROUNDS);
+        let secret2 = mnemonic_to_mini_secret(&mnemonic, "", DEFAULT_PBKDF2_ROUNDS);
         assert_eq!(secret1, secret2, "PBKDF2 should be deterministic");
     }
 }
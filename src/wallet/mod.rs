@@ -1,7 +1,219 @@
 pub mod hex;
 pub mod mnemonic;
 pub mod json;
+pub mod lock;
+pub mod manifest;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod mmap;
+pub mod raw;
+pub mod results_file;
+pub mod shares;
 
 pub use hex::*;
 pub use mnemonic::*;
 pub use json::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use mmap::MmapWriter;
+
+use crate::runner::VanityResult;
+
+/// Write a wallet file so it is either complete on disk or absent
+///
+/// Contents go to `<path>.tmp`, are synced, then renamed into place. A run killed
+/// mid-write can leave a stray `.tmp` file but never a truncated wallet. The temp
+/// file is owner-only (see [`restrict_to_owner`]) before anything is written to it.
+pub fn write_file_durable(path: &str, contents: impl AsRef<[u8]>) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let tmp_path = format!("{}.tmp", path);
+    // A stray temp file from a killed run may have other permissions; start fresh
+    match std::fs::remove_file(&tmp_path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+        _ => {}
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&tmp_path)?;
+    #[cfg(windows)]
+    restrict_to_owner(&tmp_path)?;
+
+    file.write_all(contents.as_ref())?;
+    file.sync_all()?;
+    drop(file);
+    std::fs::rename(&tmp_path, path)
+}
+
+/// Make a wallet file readable and writable by its owner only
+///
+/// Unix sets mode 0600. Windows replaces the DACL with a protected one granting
+/// access to the owner alone, which drops inherited entries such as Everyone and
+/// NETWORK.
+pub fn restrict_to_owner(path: &str) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    }
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::ffi::OsStrExt;
+        use winapi::shared::sddl::{ConvertStringSecurityDescriptorToSecurityDescriptorW, SDDL_REVISION_1};
+        use winapi::um::securitybaseapi::SetFileSecurityW;
+        use winapi::um::winbase::LocalFree;
+        use winapi::um::winnt::{DACL_SECURITY_INFORMATION, PSECURITY_DESCRIPTOR};
+
+        let wide = |s: &str| std::ffi::OsStr::new(s).encode_wide().chain(Some(0)).collect::<Vec<u16>>();
+        // Protected DACL with a single full-access entry for the owner
+        let sddl = wide("D:P(A;;FA;;;OW)");
+        let path = wide(path);
+        let mut descriptor: PSECURITY_DESCRIPTOR = std::ptr::null_mut();
+
+        unsafe {
+            if ConvertStringSecurityDescriptorToSecurityDescriptorW(sddl.as_ptr(), u32::from(SDDL_REVISION_1), &mut descriptor, std::ptr::null_mut()) == 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            let applied = SetFileSecurityW(path.as_ptr(), DACL_SECURITY_INFORMATION, descriptor) != 0;
+            let error = std::io::Error::last_os_error();
+            LocalFree(descriptor);
+            if !applied {
+                return Err(error);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Tokens recognised by `--name-template`
+pub const NAME_TEMPLATE_TOKENS: &[&str] = &[
+    "{address}",
+    "{address_short}",
+    "{index}",
+    "{timestamp}",
+    "{network}",
+    "{term}",
+];
+
+/// Build a wallet file name (without extension) from a `--name-template` string
+///
+/// Supported tokens: `{address}`, `{address_short}` (first 8 chars), `{index}` (1-based),
+/// `{timestamp}` (Unix seconds), `{network}` and `{term}`.
+pub fn apply_name_template(
+    template: &str,
+    result: &VanityResult,
+    index: usize,
+    network: &str,
+    term: &str,
+) -> String {
+    let address_short: String = result.address.chars().take(8).collect();
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    template
+        .replace("{address_short}", &address_short)
+        .replace("{address}", &result.address)
+        .replace("{index}", &index.to_string())
+        .replace("{timestamp}", &timestamp.to_string())
+        .replace("{network}", network)
+        .replace("{term}", term)
+}
+
+/// Check that a `--name-template` is usable for the requested wallet count
+pub fn validate_name_template(template: &str, count: usize) -> Result<(), String> {
+    if template.trim().is_empty() {
+        return Err("Name template cannot be empty".to_string());
+    }
+
+    if template.contains(['/', '\\']) {
+        return Err(format!(
+            "Name template '{}' must not contain path separators. Use --output to choose the directory",
+            template
+        ));
+    }
+
+    // Strip known tokens; any remaining braces point at a typo such as `{adress}`
+    let mut stripped = template.to_string();
+    for token in NAME_TEMPLATE_TOKENS {
+        stripped = stripped.replace(token, "");
+    }
+    if stripped.contains(['{', '}']) {
+        return Err(format!(
+            "Name template '{}' contains an unknown token. Valid tokens are: {}",
+            template,
+            NAME_TEMPLATE_TOKENS.join(", ")
+        ));
+    }
+
+    // With several wallets (or unlimited, count 0), each file name must be distinct
+    if count != 1 && !template.contains("{index}") && !template.contains("{address}") {
+        return Err(format!(
+            "Name template '{}' would produce the same file name for every wallet\nSUGGESTION: Include {{index}} or {{address}} in the template when --count is not 1",
+            template
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_name_template() {
+        let result = VanityResult::for_test("suAbCdEfGh123456789", "secret");
+        let name = apply_name_template("wallet-{index}-{network}-{term}-{address_short}", &result, 2, "Autonomys", "ai3");
+        assert_eq!(name, "wallet-2-Autonomys-ai3-suAbCdEf");
+
+        let name = apply_name_template("{address}", &result, 1, "Autonomys", "ai3");
+        assert_eq!(name, result.address);
+
+        let name = apply_name_template("{timestamp}", &result, 1, "Autonomys", "ai3");
+        assert!(name.parse::<u64>().is_ok());
+    }
+
+    #[test]
+    fn test_validate_name_template() {
+        assert!(validate_name_template("wallet-{index}", 5).is_ok());
+        assert!(validate_name_template("{address}", 5).is_ok());
+        assert!(validate_name_template("my-wallet", 1).is_ok());
+
+        // Not unique across several wallets
+        assert!(validate_name_template("my-wallet-{term}", 2).is_err());
+        assert!(validate_name_template("my-wallet", 0).is_err());
+        // Unknown token
+        assert!(validate_name_template("{adress}", 1).is_err());
+        // Path separator
+        assert!(validate_name_template("dir/{index}", 1).is_err());
+        assert!(validate_name_template("", 1).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_file_durable_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let output_dir = ".devonly/durable";
+        std::fs::create_dir_all(output_dir).ok();
+        let path = format!("{output_dir}/wallet.txt");
+
+        // A world-readable temp file left behind by a killed run is replaced, not reused
+        std::fs::write(format!("{path}.tmp"), "stale").unwrap();
+        std::fs::set_permissions(format!("{path}.tmp"), std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_file_durable(&path, "secret").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "secret");
+        assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+
+        std::fs::remove_dir_all(output_dir).ok();
+    }
+}
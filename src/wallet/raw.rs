@@ -0,0 +1,97 @@
+//! Unencrypted keypair export for low-level tooling (`--export-raw`)
+//!
+//! Writes the 64-byte expanded secret key and 32-byte public key as hex, in the same
+//! ed25519-style layout the JSON export encrypts. Nothing protects these files
+//! beyond their owner-only permissions.
+
+use crate::crypto::schnorrkel::{ExpansionMode, MiniSecretKey};
+use crate::runner::VanityResult;
+use std::str::FromStr;
+use zeroize::Zeroize;
+
+/// First line of every raw key file
+pub const RAW_KEY_WARNING: &str = "# WARNING: UNENCRYPTED KEY MATERIAL. Anyone who reads this file controls the account.";
+
+/// Derive the 32-byte mini secret the wallet's keypair expands from
+fn mini_secret(result: &VanityResult, hex_mode: bool, pbkdf2_rounds: u32) -> Result<[u8; 32], String> {
+    if hex_mode {
+        let hex_clean = result.secret.strip_prefix("0x").unwrap_or(&result.secret);
+        let mut bytes = hex::decode(hex_clean)
+            .map_err(|e| format!("Failed to decode hex seed: {}", e))?;
+        let seed = <[u8; 32]>::try_from(bytes.as_slice())
+            .map_err(|_| format!("Hex seed must be exactly 32 bytes, got {}", bytes.len()));
+        bytes.zeroize();
+        seed
+    } else {
+        let mnemonic = crate::crypto::bip39::Mnemonic::from_str(&result.secret)
+            .map_err(|e| format!("Failed to parse mnemonic: {:?}", e))?;
+        Ok(super::mnemonic_to_mini_secret(&mnemonic, "", pbkdf2_rounds))
+    }
+}
+
+/// Save the wallet's raw keypair to `<file_stem>_raw.txt`
+pub fn save_wallet_raw(
+    result: &VanityResult,
+    output_dir: &str,
+    file_stem: &str,
+    hex_mode: bool,
+    pbkdf2_rounds: u32,
+) -> Result<(), String> {
+    let mut seed = mini_secret(result, hex_mode, pbkdf2_rounds)?;
+    let keypair = MiniSecretKey::from_bytes(&seed)
+        .map_err(|e| format!("Failed to create mini secret key: {}", e))?
+        .expand_to_keypair(ExpansionMode::Ed25519);
+    seed.zeroize();
+
+    let mut secret = keypair.secret.to_ed25519_bytes();
+    let mut content = format!(
+        "{}\nAddress: {}\nSECRET_HEX={}\nPUBLIC_HEX={}\n",
+        RAW_KEY_WARNING,
+        result.address,
+        hex::encode(secret),
+        hex::encode(keypair.public.to_bytes())
+    );
+    secret.zeroize();
+
+    let output_path = format!("{}/{}_raw.txt", output_dir, file_stem);
+    // write_file_durable creates the file owner-only, so the key is never readable by others
+    let written = super::write_file_durable(&output_path, &content)
+        .map_err(|e| format!("Failed to write raw key file: {}", e));
+    content.zeroize();
+    written
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_export_matches_address() {
+        let output_dir = ".devonly/raw";
+        std::fs::create_dir_all(output_dir).ok();
+        let seed = [7u8; 32];
+        let mut result = VanityResult::for_test(&crate::wallet::hex_to_address_with_prefix(&seed, 42), &hex::encode(seed));
+        (result.ss58_prefix, result.network_name) = (42, "Substrate".to_string());
+
+        save_wallet_raw(&result, output_dir, "wallet", true, crate::wallet::DEFAULT_PBKDF2_ROUNDS).unwrap();
+        let path = format!("{output_dir}/wallet_raw.txt");
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with(RAW_KEY_WARNING));
+
+        let field = |name: &str| {
+            let line = content.lines().find_map(|line| line.strip_prefix(name)).unwrap();
+            hex::decode(line).unwrap()
+        };
+        assert_eq!(field("SECRET_HEX=").len(), 64);
+        let pair = crate::crypto::substrate::sr25519::Pair::from_seed_slice(&seed).unwrap();
+        assert_eq!(field("PUBLIC_HEX="), pair.public().as_ref());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        std::fs::remove_dir_all(output_dir).ok();
+    }
+}
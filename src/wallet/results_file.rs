@@ -0,0 +1,109 @@
+//! JSON Lines results log (`--append-to`)
+//!
+//! Each found wallet becomes one line:
+//! `{"address":"su...","secret":"...","ss58_prefix":6094,"found_at_ms":1700000000000}`.
+//! The file is opened in append mode and never truncated, so it collects results
+//! across runs. Secrets are stored unencrypted; a newly created file is restricted to
+//! its owner.
+
+use crate::runner::VanityResult;
+use serde_json::json;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+
+/// Append-only JSON Lines file of found wallets
+#[derive(Debug)]
+pub struct ResultsFile {
+    path: String,
+    writer: BufWriter<File>,
+}
+
+impl ResultsFile {
+    /// Open `path` for appending, creating it if it doesn't exist
+    pub fn open(path: &str) -> Result<Self, String> {
+        #[cfg(windows)]
+        let existed = std::path::Path::new(path).exists();
+        let mut options = OpenOptions::new();
+        options.append(true).create(true);
+        // Owner-only from creation; the mode leaves a file the user already has alone
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let file = options
+            .open(path)
+            .map_err(|e| format!("Failed to open results file {}: {}", path, e))?;
+        // Windows has no create mode; restrict a new file before anything is written
+        #[cfg(windows)]
+        if !existed {
+            super::restrict_to_owner(path)
+                .map_err(|e| format!("Failed to restrict permissions on {}: {}", path, e))?;
+        }
+
+        Ok(Self { path: path.to_string(), writer: BufWriter::new(file) })
+    }
+
+    /// Path the results are appended to
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Write `result` as one JSON line; call [`flush`](Self::flush) to push it to disk
+    pub fn append(&mut self, result: &VanityResult) -> Result<(), String> {
+        let found_at_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        let line = json!({
+            "address": result.address,
+            "secret": result.secret,
+            "ss58_prefix": result.ss58_prefix,
+            "found_at_ms": found_at_ms,
+        });
+        writeln!(self.writer, "{}", line).map_err(|e| format!("Failed to write to {}: {}", self.path, e))
+    }
+
+    /// Flush buffered lines and sync them to disk
+    pub fn flush(&mut self) -> Result<(), String> {
+        self.writer
+            .flush()
+            .and_then(|()| self.writer.get_ref().sync_data())
+            .map_err(|e| format!("Failed to flush {}: {}", self.path, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_results_file_appends_across_opens() {
+        let path = std::env::temp_dir().join(format!("autoseed_results_{}.jsonl", std::process::id()));
+        let path = path.to_str().unwrap();
+        let result = VanityResult::for_test("suAbCdEfGh123456789", "secret");
+
+        // Two runs: the second must not truncate the first's line
+        for _ in 0..2 {
+            let mut results_file = ResultsFile::open(path).unwrap();
+            results_file.append(&result).unwrap();
+            results_file.flush().unwrap();
+        }
+
+        let contents = std::fs::read_to_string(path).unwrap();
+        let lines: Vec<serde_json::Value> = contents.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["address"], "suAbCdEfGh123456789");
+        assert_eq!(lines[0]["secret"], "secret");
+        assert_eq!(lines[0]["ss58_prefix"], 6094);
+        assert!(lines[1]["found_at_ms"].as_u64().unwrap() > 1_700_000_000_000);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        std::fs::remove_file(path).ok();
+    }
+}
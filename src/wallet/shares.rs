@@ -0,0 +1,108 @@
+//! Shamir share export for found wallets (`--split K/N`)
+//!
+//! Hex mode splits the 32-byte seed directly. Mnemonic mode splits the BIP39 entropy
+//! (16 to 32 bytes) zero-padded to 32 bytes, so recovering the number of bytes the
+//! share file lists and calling `Mnemonic::from_entropy` restores the original phrase.
+
+use crate::crypto::shamir::{share_to_phrase, split_secret};
+use crate::runner::VanityResult;
+use std::str::FromStr;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Extract the 32-byte secret to split from a wallet result
+fn secret_bytes(result: &VanityResult, hex_mode: bool) -> Result<([u8; 32], String), String> {
+    let mut secret = [0u8; 32];
+    if hex_mode {
+        let hex_clean = result.secret.strip_prefix("0x").unwrap_or(&result.secret);
+        let mut bytes = hex::decode(hex_clean)
+            .map_err(|e| format!("Failed to decode hex seed: {}", e))?;
+        if bytes.len() != 32 {
+            bytes.zeroize();
+            return Err(format!("Hex seed must be exactly 32 bytes, got {}", bytes.len()));
+        }
+        secret.copy_from_slice(&bytes);
+        bytes.zeroize();
+        Ok((secret, "hex seed (32 bytes)".to_string()))
+    } else {
+        let mnemonic = crate::crypto::bip39::Mnemonic::from_str(&result.secret)
+            .map_err(|e| format!("Failed to parse mnemonic: {:?}", e))?;
+        let mut entropy = mnemonic.to_entropy();
+        secret[..entropy.len()].copy_from_slice(&entropy);
+        let kind = if entropy.len() < secret.len() {
+            format!("mnemonic entropy ({} bytes, zero-padded)", entropy.len())
+        } else {
+            format!("mnemonic entropy ({} bytes)", entropy.len())
+        };
+        entropy.zeroize();
+        Ok((secret, kind))
+    }
+}
+
+/// Split a wallet secret into `n` shares and save each to `<file_stem>_share_<i>.txt`
+pub fn save_wallet_shares(
+    result: &VanityResult,
+    output_dir: &str,
+    file_stem: &str,
+    hex_mode: bool,
+    k: u8,
+    n: u8,
+) -> Result<(), String> {
+    let (mut secret, kind) = secret_bytes(result, hex_mode)?;
+    let mut shares = split_secret(&secret, k, n);
+    secret.zeroize();
+
+    for share in &shares {
+        let output_path = format!("{}/{}_share_{}.txt", output_dir, file_stem, share[0]);
+        let phrase = Zeroizing::new(share_to_phrase(share));
+        let content = Zeroizing::new(format!(
+            "Address: {}\nShare: {} of {} ({} required)\nSecret: {}\nPhrase: {}\n",
+            result.address,
+            share[0],
+            n,
+            k,
+            kind,
+            *phrase
+        ));
+        super::write_file_durable(&output_path, content.as_bytes())
+            .map_err(|e| format!("Failed to write share file: {}", e))?;
+    }
+
+    for share in &mut shares {
+        share.zeroize();
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_bytes_labels_entropy_length() {
+        for (len, kind) in [
+            (16, "mnemonic entropy (16 bytes, zero-padded)"),
+            (20, "mnemonic entropy (20 bytes, zero-padded)"),
+            (28, "mnemonic entropy (28 bytes, zero-padded)"),
+            (32, "mnemonic entropy (32 bytes)"),
+        ] {
+            let entropy: Vec<u8> = (1..=len).collect();
+            let result = VanityResult {
+                address: String::new(),
+                secret: crate::crypto::bip39::Mnemonic::from_entropy(&entropy).unwrap().to_string(),
+                matches: 0,
+                offset: 0,
+                attempts: 1,
+                expected_attempts: 1,
+                ss58_prefix: 42,
+                network_name: "Substrate".to_string(),
+                match_detail: Vec::new(),
+            };
+
+            let (secret, label) = secret_bytes(&result, false).unwrap();
+            assert_eq!(label, kind);
+            assert_eq!(&secret[..entropy.len()], &entropy[..]);
+            assert!(secret[entropy.len()..].iter().all(|&byte| byte == 0));
+        }
+    }
+}
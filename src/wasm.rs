@@ -0,0 +1,55 @@
+//! JavaScript bindings for browser builds (`wasm` feature)
+//!
+//! Exposes address derivation and pattern matching so a web front end can run the
+//! search loop itself; the threaded runner is swapped for `runner::single` on wasm32.
+
+use wasm_bindgen::prelude::*;
+
+/// Derive the SS58 address for a 32-byte hex seed (with or without `0x`)
+///
+/// Returns an empty string when the seed isn't valid hex or isn't 32 bytes long.
+#[wasm_bindgen]
+pub fn wasm_generate_address(seed_hex: &str, ss58_prefix: u16) -> String {
+    let hex_clean = seed_hex.strip_prefix("0x").unwrap_or(seed_hex);
+    let Ok(seed) = hex::decode(hex_clean) else {
+        return String::new();
+    };
+    let Ok(seed) = <[u8; 32]>::try_from(seed.as_slice()) else {
+        return String::new();
+    };
+
+    crate::wallet::hex_to_address_with_prefix(&seed, ss58_prefix)
+}
+
+/// Check whether `pattern` appears anywhere in `address` (`?` matches any character)
+#[wasm_bindgen]
+pub fn wasm_search_pattern(address: &str, pattern: &str, case_sensitive: bool) -> bool {
+    crate::search::contains_pattern(address, pattern, case_sensitive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wasm_generate_address_matches_native() {
+        let seed = [42u8; 32];
+        let expected = crate::wallet::hex_to_address_with_prefix(&seed, 6094);
+
+        assert_eq!(wasm_generate_address(&hex::encode(seed), 6094), expected);
+        assert_eq!(wasm_generate_address(&format!("0x{}", hex::encode(seed)), 6094), expected);
+        assert_eq!(wasm_generate_address("not hex", 6094), "");
+        assert_eq!(wasm_generate_address("abcd", 6094), "");
+    }
+
+    #[test]
+    fn test_wasm_search_pattern() {
+        let address = wasm_generate_address(&hex::encode([42u8; 32]), 6094);
+        let middle = &address[10..14];
+
+        assert!(wasm_search_pattern(&address, middle, true));
+        assert!(wasm_search_pattern(&address, &middle.to_ascii_uppercase(), false));
+        assert!(wasm_search_pattern(&address, "??", true));
+        assert!(!wasm_search_pattern(&address, "0000", false));
+    }
+}
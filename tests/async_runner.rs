@@ -0,0 +1,30 @@
+//! Streaming wallets from the async runner (`--features async-runner`)
+
+#![cfg(feature = "async-runner")]
+
+use autoseed::hex_to_address_with_prefix;
+use autoseed::runner::async_parallel::generate_vanity_addresses_async;
+use autoseed::SearchParams;
+use tokio_stream::StreamExt;
+
+#[tokio::test]
+async fn test_async_runner_streams_one_wallet() {
+    let params = SearchParams { hex_mode: true, within: 49, suffix: false, anywhere: true, threads: 2, ..SearchParams::new("a", 42) };
+    let stream = generate_vanity_addresses_async(&params).await;
+    let results: Vec<_> = stream.collect().await;
+
+    assert_eq!(results.len(), 1);
+    let result = &results[0];
+    assert!(result.address.to_lowercase().contains('a'));
+
+    let seed: [u8; 32] = hex::decode(&result.secret).unwrap().try_into().unwrap();
+    assert_eq!(hex_to_address_with_prefix(&seed, 42), result.address);
+}
+
+#[tokio::test]
+async fn test_async_runner_impossible_pattern_ends_stream() {
+    // Prefix mode can't fit "ab" ahead of the Autonomys "su" within 2 characters
+    let params = SearchParams { hex_mode: true, within: 2, suffix: false, threads: 1, ..SearchParams::new("ab", 6094) };
+    let stream = generate_vanity_addresses_async(&params).await;
+    assert_eq!(stream.collect::<Vec<_>>().await.len(), 0);
+}
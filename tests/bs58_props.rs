@@ -0,0 +1,52 @@
+//! Property tests for the Base58 codec in `crypto::bs58`
+//!
+//! Checks the round trip both ways and compares against the reference `bs58` crate.
+//! Runs 256 cases by default; CI sets `PROPTEST_CASES=10000`.
+
+use autoseed::crypto::bs58 as local;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn bytes_round_trip(input in prop::collection::vec(any::<u8>(), 0..=100)) {
+        let encoded = local::encode(&input).into_string();
+        prop_assert_eq!(local::decode(&encoded).into_vec(), Ok(input));
+    }
+
+    #[test]
+    fn leading_zeros_round_trip(zeros in 0usize..=10, rest in prop::collection::vec(any::<u8>(), 0..=20)) {
+        let input: Vec<u8> = std::iter::repeat_n(0, zeros).chain(rest).collect();
+        let encoded = local::encode(&input).into_string();
+        prop_assert!(encoded.starts_with(&"1".repeat(zeros)));
+        prop_assert_eq!(local::decode(&encoded).into_vec(), Ok(input));
+    }
+
+    #[test]
+    fn matches_reference_crate(input in prop::collection::vec(any::<u8>(), 0..=100)) {
+        let encoded = local::encode(&input).into_string();
+        prop_assert_eq!(&encoded, &bs58::encode(&input).into_string());
+        prop_assert_eq!(local::decode(&encoded).into_vec().ok(), bs58::decode(&encoded).into_vec().ok());
+    }
+
+    #[test]
+    fn arbitrary_strings_decode_or_reject(input in any::<String>()) {
+        match local::decode(&input).into_vec() {
+            Ok(bytes) => {
+                prop_assert_eq!(local::encode(&bytes).into_string(), input.clone());
+                prop_assert_eq!(bs58::decode(&input).into_vec().ok(), Some(bytes));
+            }
+            Err(local::Error::InvalidCharacter { character, index }) => {
+                prop_assert_eq!(input[index..].chars().next(), Some(character));
+                prop_assert!(bs58::decode(&input).into_vec().is_err());
+            }
+            Err(e) => prop_assert!(false, "unexpected error {e}"),
+        }
+    }
+
+    #[test]
+    fn alphabet_strings_decode(input in "[1-9A-HJ-NP-Za-km-z]{0,60}") {
+        let bytes = local::decode(&input).into_vec();
+        prop_assert!(bytes.is_ok());
+        prop_assert_eq!(local::encode(bytes.unwrap()).into_string(), input);
+    }
+}
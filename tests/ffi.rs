@@ -0,0 +1,43 @@
+//! Compiles `tests/ffi/test_ffi.c` against the generated header and the cdylib, then
+//! runs it. Needs a C compiler (`$CC`, or `cc` on the PATH).
+
+#![cfg(all(feature = "ffi", target_os = "linux"))]
+
+use std::path::PathBuf;
+use std::process::Command;
+
+#[test]
+fn c_program_exercises_the_api() {
+    // Integration tests run from target/<profile>/deps, where cargo also leaves the
+    // cdylib built with this test's features
+    let lib_dir = std::env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let source = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/ffi/test_ffi.c");
+    let binary = lib_dir.join("autoseed_ffi_test");
+
+    let compiler = std::env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(compiler)
+        .arg(&source)
+        .arg("-I")
+        .arg(env!("AUTOSEED_HEADER_DIR"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg("-lautoseed")
+        .arg("-o")
+        .arg(&binary)
+        .status()
+        .expect("failed to run the C compiler");
+    assert!(status.success(), "compiling test_ffi.c failed");
+
+    // cargo's LD_LIBRARY_PATH also lists target/<profile>, whose copy of the library
+    // may come from a build without the ffi feature, so point the loader at ours
+    let output = Command::new(&binary)
+        .env("LD_LIBRARY_PATH", &lib_dir)
+        .output()
+        .expect("failed to run the C test");
+    assert!(
+        output.status.success(),
+        "C test failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "ok");
+}
@@ -0,0 +1,46 @@
+/* Exercises the C API in src/ffi.rs; built and run by tests/ffi.rs */
+
+#include <stdint.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "autoseed.h"
+
+#define CHECK(cond)                                                        \
+    do {                                                                   \
+        if (!(cond)) {                                                     \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #cond); \
+            return 1;                                                      \
+        }                                                                  \
+    } while (0)
+
+int main(void) {
+    uint8_t seed[AUTOSEED_SEED_LEN];
+    char address[AUTOSEED_ADDRESS_BUF_LEN];
+
+    /* A generated address must match the one derived from its seed */
+    CHECK(autoseed_generate_hex(seed, address, 6094) == AUTOSEED_OK);
+    CHECK(strncmp(address, "su", 2) == 0);
+
+    char *derived = autoseed_address_from_seed(seed, 6094);
+    CHECK(derived != NULL);
+    CHECK(strcmp(derived, address) == 0);
+    autoseed_free_string(derived);
+    autoseed_free_string(NULL);
+
+    /* Search is anywhere in the address, with '?' as a wildcard */
+    CHECK(autoseed_search("su", address, 1) == 1);
+    CHECK(autoseed_search("SU", address, 0) == 1);
+    CHECK(autoseed_search("SU", address, 1) == 0);
+    CHECK(autoseed_search("s?", address, 1) == 1);
+    CHECK(autoseed_search("0000", address, 0) == 0);
+
+    /* Bad arguments are reported, not dereferenced */
+    CHECK(autoseed_generate_hex(NULL, address, 6094) == AUTOSEED_ERR_NULL);
+    CHECK(autoseed_search(NULL, address, 0) == AUTOSEED_ERR_NULL);
+    CHECK(autoseed_search("\xff", address, 0) == AUTOSEED_ERR_UTF8);
+    CHECK(autoseed_address_from_seed(NULL, 6094) == NULL);
+
+    printf("ok\n");
+    return 0;
+}
@@ -0,0 +1,56 @@
+//! Interrupting a run must never leave a partial wallet on disk
+//!
+//! Wallets are written from the wallet callback as soon as they are found. This kills
+//! the binary mid-search and checks every wallet file that made it to disk is complete.
+
+use autoseed::wallet::verify_wallet_json;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+const TEST_PASSWORD: &str = "testpass123";
+
+#[test]
+fn test_killed_run_leaves_complete_wallets() {
+    let output_dir = std::env::temp_dir().join(format!("autoseed_interrupted_run_{}", std::process::id()));
+    std::fs::create_dir_all(&output_dir).unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_autoseed"))
+        .args(["--term", "ab", "--anywhere", "--hex", "--count", "1000", "--threads", "2"])
+        .args(["--network", "substrate", "--pass", TEST_PASSWORD])
+        .arg("--output")
+        .arg(&output_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("Failed to start autoseed");
+
+    // Wait for the first wallet, then kill the run without any chance to clean up
+    let json_files = || -> Vec<std::path::PathBuf> {
+        std::fs::read_dir(&output_dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect()
+    };
+    let deadline = Instant::now() + Duration::from_secs(120);
+    while json_files().is_empty() && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    child.kill().unwrap();
+    child.wait().unwrap();
+
+    let wallets = json_files();
+    assert!(!wallets.is_empty(), "No wallet was written before the deadline");
+    for path in &wallets {
+        let content = std::fs::read_to_string(path).unwrap();
+        assert_eq!(
+            verify_wallet_json(&content, TEST_PASSWORD),
+            Ok(true),
+            "Incomplete or corrupt wallet after interruption: {}",
+            path.display()
+        );
+    }
+
+    std::fs::remove_dir_all(&output_dir).ok();
+}
@@ -0,0 +1,126 @@
+//! Known-answer tests for the whole derivation stack
+//!
+//! Five fixed seeds run through every path a found wallet takes: sr25519 expansion and
+//! SS58 encoding (hex mode), BIP39 encoding plus the Substrate PBKDF2 mini-secret
+//! (mnemonic mode), and the encrypted JSON export. The expected values were recorded
+//! from this crate; the first three mnemonics are the official BIP39 test vectors.
+//! Any change to PBKDF2, schnorrkel expansion or SS58 encoding shows up here.
+
+use autoseed::crypto::bip39::Mnemonic;
+use autoseed::runner::{VanityResult, verify_vanity_result};
+use autoseed::wallet::{Kdf, hex_to_address_with_prefix, save_wallet_json, verify_wallet_json};
+
+const TEST_PASSWORD: &str = "testpass123";
+const SS58_PREFIX: u16 = 6094;
+
+struct KnownVector {
+    /// 32-byte seed; its first 16 bytes are the mnemonic entropy
+    seed_hex: &'static str,
+    /// Hex-mode address for `SS58_PREFIX`
+    hex_address: &'static str,
+    /// Address stored in the JSON wallet (always prefix 42)
+    json_address: &'static str,
+    mnemonic: &'static str,
+    /// Mnemonic-mode address for `SS58_PREFIX` (2048 PBKDF2 rounds, empty password)
+    mnemonic_address: &'static str,
+}
+
+const KNOWN_VECTORS: [KnownVector; 5] = [
+    KnownVector {
+        seed_hex: "0000000000000000000000000000000000000000000000000000000000000001",
+        hex_address: "sucPSnqV7cZXnm5Csifi5GPuTEQP6Go7or4yUVo4W9ybitN8s",
+        json_address: "5DP4qTec9XxffaALGWsEPhS1oWrDWMBjzhBmyzShREMJpymt",
+        mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        mnemonic_address: "sudPaS7EUBJjjUJr6AyF6PYcpCf4B6GJKvoVKJh9kLdjPVsfE",
+    },
+    KnownVector {
+        seed_hex: "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+        hex_address: "suevMpwaPbiAGgCzgEEyJJmbtrwUKmG1nqTaS1w4vBiaxMs3J",
+        json_address: "5FuysZjt8gb9ahx8n68TS58TS3wSzp5iz5njW8T7SyLYJuPj",
+        mnemonic: "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        mnemonic_address: "sudJ4UJrg83Puu7ACTJySYTpaYPa5vzSCpDuiwUK94u3adbMp",
+    },
+    KnownVector {
+        seed_hex: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        hex_address: "suchVgcbnbbJcx3Ewhzo87QYEsnnHnTR8p2x2BnXc9bXMCK9x",
+        json_address: "5Dh7jEmH8ZjVrYCQFqxHEi4oSuFR21V4xfAKfyuoQrGw95Yj",
+        mnemonic: "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+        mnemonic_address: "sugqRAJVCZzv8KJ9GBr1JAqSbHtGydtgymHGdv3hYowwktu3e",
+    },
+    KnownVector {
+        seed_hex: "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+        hex_address: "sugBViCceVT1EzkCSxvySQ9PpvCnGSagTKd1KbgDjP1yw9LPw",
+        json_address: "5HB7kpn92RS7uF9uWn8bXSvPVKFPg8kPUFDd5sbveGjX6Dbi",
+        mnemonic: "abandon amount liar amount expire adjust cage candy arch gather drum buyer",
+        mnemonic_address: "subfuTcsDfWpfEdY9oMqN98VvkEfh4C4kWYHg1mJ1doQRuig8",
+    },
+    KnownVector {
+        seed_hex: "e5b1c1d0a9f64f3c2e8b7a6d5c4b3a2918f7e6d5c4b3a29180f1e2d3c4b5a697",
+        hex_address: "sugiwesHuCvi1Fi1eRP4euZSBKs3ACbiabNn9ocnF1sCTMghx",
+        json_address: "5HiZhVTPju8tACy6yEDp2rxjtyWHS9nWjzzTHpASH7x3JYV1",
+        mnemonic: "toss mix injury fault gossip own riot hungry hood time soldier fault",
+        mnemonic_address: "subaAyHKs7AmjsQoNi6QEe4KAScZwBFfL5r4Ls5hup3w5As91",
+    },
+];
+
+fn seed(vector: &KnownVector) -> [u8; 32] {
+    hex::decode(vector.seed_hex).unwrap().try_into().unwrap()
+}
+
+fn vanity_result(address: &str, secret: &str) -> VanityResult {
+    VanityResult {
+        address: address.to_string(),
+        secret: secret.to_string(),
+        matches: 0,
+        offset: 0,
+        attempts: 1,
+        expected_attempts: 0,
+        ss58_prefix: SS58_PREFIX,
+        network_name: "Autonomys".to_string(),
+        match_detail: Vec::new(),
+    }
+}
+
+#[test]
+fn test_hex_addresses() {
+    for vector in &KNOWN_VECTORS {
+        let seed = seed(vector);
+        assert_eq!(hex_to_address_with_prefix(&seed, SS58_PREFIX), vector.hex_address, "seed {}", vector.seed_hex);
+        assert_eq!(hex_to_address_with_prefix(&seed, 42), vector.json_address, "seed {}", vector.seed_hex);
+        assert!(verify_vanity_result(&vanity_result(vector.hex_address, vector.seed_hex), true));
+    }
+}
+
+#[test]
+fn test_mnemonics() {
+    for vector in &KNOWN_VECTORS {
+        let mnemonic = Mnemonic::from_entropy(&seed(vector)[..16]).unwrap();
+        assert_eq!(mnemonic.to_string(), vector.mnemonic, "seed {}", vector.seed_hex);
+        assert_eq!(
+            autoseed::mnemonic_to_address_with_prefix(&mnemonic, SS58_PREFIX),
+            vector.mnemonic_address,
+            "mnemonic {}",
+            vector.mnemonic
+        );
+        assert!(verify_vanity_result(&vanity_result(vector.mnemonic_address, vector.mnemonic), false));
+    }
+}
+
+#[test]
+fn test_json_wallets_decrypt() {
+    let output_dir = std::env::temp_dir().join(format!("autoseed_known_vectors_{}", std::process::id()));
+    std::fs::create_dir_all(&output_dir).unwrap();
+    let output_dir_str = output_dir.to_str().unwrap();
+
+    for vector in &KNOWN_VECTORS {
+        let result = vanity_result(vector.hex_address, vector.seed_hex);
+        save_wallet_json(&result, TEST_PASSWORD, "test", output_dir_str, vector.hex_address, Kdf::default(), None).unwrap();
+        let wallet_json = std::fs::read_to_string(output_dir.join(format!("{}.json", vector.hex_address))).unwrap();
+
+        assert_eq!(verify_wallet_json(&wallet_json, TEST_PASSWORD), Ok(true), "seed {}", vector.seed_hex);
+        let wallet: serde_json::Value = serde_json::from_str(&wallet_json).unwrap();
+        assert_eq!(wallet["address"], vector.json_address, "seed {}", vector.seed_hex);
+    }
+
+    std::fs::remove_dir_all(&output_dir).ok();
+}
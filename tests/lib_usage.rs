@@ -0,0 +1,118 @@
+//! Using autoseed as a library, without the CLI
+//!
+//! Everything here goes through the items re-exported at the crate root.
+
+use autoseed::crypto::bip39::Mnemonic;
+use autoseed::{
+    OptimizedSearchConfig, SearchParams, VanityResult, find_network_by_prefix, generate_vanity_addresses,
+    hex_to_address_with_prefix, mnemonic_to_address_with_prefix,
+};
+
+#[test]
+fn test_address_derivation() {
+    let autonomys = find_network_by_prefix(6094).expect("Autonomys is a known network");
+
+    let seed = [7u8; 32];
+    let address = hex_to_address_with_prefix(&seed, autonomys.ss58_prefix);
+    assert!(address.starts_with(autonomys.address_prefixes[0]));
+    assert_eq!(address, hex_to_address_with_prefix(&seed, autonomys.ss58_prefix));
+
+    let mnemonic = Mnemonic::parse("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about")
+        .expect("Known valid mnemonic");
+    assert!(mnemonic_to_address_with_prefix(&mnemonic, 0).starts_with('1'));
+}
+
+#[test]
+fn test_search_config() {
+    let config = OptimizedSearchConfig::new_with_prefix("ab", false, false, true, 49, 6094);
+    assert!(config.is_valid());
+    assert_eq!(config.search_range, Some((2, 47)));
+
+    // A prefix search for a term that conflicts with "su" can never match
+    let impossible = OptimizedSearchConfig::new_with_prefix("ab", false, false, false, 2, 6094);
+    assert!(!impossible.is_valid());
+}
+
+#[test]
+fn test_generate_vanity_addresses() {
+    let mut found = Vec::new();
+    let params = SearchParams {
+        count: 2,
+        hex_mode: true,
+        within: 49,
+        suffix: false,
+        anywhere: true,
+        threads: 2,
+        ..SearchParams::new("ab", 42)
+    };
+    let (results, stats) = generate_vanity_addresses(
+        &params,
+        |_progress| {},
+        |result: &VanityResult| found.push(result.address.clone()),
+    );
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(found, results.iter().map(|r| r.address.clone()).collect::<Vec<_>>());
+    assert!(stats.total_attempts >= results.iter().map(|r| r.attempts).sum::<u64>());
+
+    for result in &results {
+        assert!(result.address.to_lowercase().contains("ab"));
+
+        // Each result's secret derives its address
+        let seed: [u8; 32] = hex::decode(&result.secret).unwrap().try_into().unwrap();
+        assert_eq!(hex_to_address_with_prefix(&seed, 42), result.address);
+    }
+}
+
+#[test]
+fn test_seeded_run_is_reproducible() {
+    let run = || {
+        let params = SearchParams {
+            count: 2,
+            hex_mode: true,
+            within: 49,
+            suffix: false,
+            anywhere: true,
+            threads: 1, // one worker, so the order of finds is fixed too
+            rng_seed: Some([3u8; 32]),
+            ..SearchParams::new("ab", 42)
+        };
+        let (results, _stats) = generate_vanity_addresses(
+            &params,
+            |_progress| {},
+            |_result: &VanityResult| {},
+        );
+        results.iter().map(|r| (r.address.clone(), r.secret.clone(), r.attempts)).collect::<Vec<_>>()
+    };
+
+    let (first, second) = (run(), run());
+
+    assert_eq!(first.len(), 2);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_stop_ends_unlimited_search() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let params = SearchParams {
+        count: 0, // unlimited: only `stop` ends it
+        hex_mode: true,
+        within: 49,
+        suffix: false,
+        anywhere: true,
+        threads: 2,
+        stop: Some(stop.clone()),
+        ..SearchParams::new("ab", 42)
+    };
+    let (results, _stats) = generate_vanity_addresses(
+        &params,
+        |_progress| {},
+        |_result: &VanityResult| stop.store(true, Ordering::Relaxed),
+    );
+
+    // Returns soon after the first find instead of searching forever
+    assert!(!results.is_empty());
+}
@@ -0,0 +1,24 @@
+//! Every built-in network's example address must be what the all-zeros seed derives
+//!
+//! Catches a wrong SS58 prefix or prefix encoding for a network before a user's
+//! wallets do.
+
+use autoseed::networks::NETWORKS;
+use autoseed::{find_network_by_address_prefix, hex_to_address_with_prefix};
+
+#[test]
+fn test_network_address_examples() {
+    for network in NETWORKS {
+        let example = network
+            .address_example
+            .unwrap_or_else(|| panic!("{} has no address_example", network.name));
+        assert_eq!(hex_to_address_with_prefix(&[0u8; 32], network.ss58_prefix), example, "{}", network.name);
+        assert!(
+            network.address_prefixes.iter().any(|prefix| example.starts_with(prefix)),
+            "{}: {example} doesn't start with {:?}",
+            network.name,
+            network.address_prefixes
+        );
+        assert_eq!(find_network_by_address_prefix(example).map(|found| found.name), Some(network.name));
+    }
+}
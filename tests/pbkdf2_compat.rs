@@ -0,0 +1,33 @@
+//! Cross-check the local PBKDF2-HMAC-SHA512 against the RustCrypto `pbkdf2` crate
+//!
+//! Every wallet's mini secret comes out of this function, so any optimization that
+//! changes its output would silently break recovery of existing wallets.
+
+use autoseed::crypto::pbkdf2::pbkdf2_hmac_sha512;
+use proptest::prelude::*;
+use sha2::Sha512;
+
+const OUTPUT_LENGTHS: [usize; 4] = [16, 32, 64, 128];
+const ROUNDS: [u32; 3] = [1, 100, 2048];
+
+proptest! {
+    // Each case runs all twelve length/round combinations
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn matches_reference_pbkdf2(
+        password in prop::collection::vec(any::<u8>(), 0..=200),
+        salt in prop::collection::vec(any::<u8>(), 0..=200),
+    ) {
+        for len in OUTPUT_LENGTHS {
+            for rounds in ROUNDS {
+                let mut expected = vec![0u8; len];
+                pbkdf2::pbkdf2_hmac::<Sha512>(&password, &salt, rounds, &mut expected);
+
+                let mut local = vec![0u8; len];
+                pbkdf2_hmac_sha512(&password, &salt, rounds, &mut local);
+                prop_assert_eq!(&local, &expected, "len {} rounds {}", len, rounds);
+            }
+        }
+    }
+}
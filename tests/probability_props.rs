@@ -0,0 +1,84 @@
+//! Property tests for `calculate_expected_attempts`
+//!
+//! Expected attempts are 1 / P(match at any valid position), so they must never drop
+//! below one, must grow as the pattern gets longer over the same positions, and
+//! must shrink (or stay put) as `--within` opens up more positions.
+
+use autoseed::cli::calculate_expected_attempts;
+use autoseed::networks::NETWORKS;
+use autoseed::search::OptimizedSearchConfig;
+use proptest::prelude::*;
+use proptest::sample::select;
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn base58_pattern(len: std::ops::RangeInclusive<usize>) -> impl Strategy<Value = String> {
+    let alphabet: Vec<char> = BASE58_ALPHABET.chars().collect();
+    prop::collection::vec(select(alphabet), len).prop_map(|chars| chars.into_iter().collect())
+}
+
+fn known_prefix() -> impl Strategy<Value = u16> {
+    select(NETWORKS.iter().map(|n| n.ss58_prefix).collect::<Vec<_>>())
+}
+
+/// Search range the CLI would use for prefix mode (`suffix == false`) or suffix mode
+fn search_range(pattern: &str, case_sensitive: bool, suffix: bool, within: usize, ss58_prefix: u16) -> Option<(usize, usize)> {
+    OptimizedSearchConfig::new_with_prefix(pattern, case_sensitive, suffix, false, within, ss58_prefix).search_range
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn expected_attempts_at_least_one(
+        pattern in base58_pattern(1..=8),
+        case_sensitive in any::<bool>(),
+        suffix in any::<bool>(),
+        within in 0usize..=49,
+        ss58_prefix in known_prefix(),
+    ) {
+        let range = search_range(&pattern, case_sensitive, suffix, within, ss58_prefix);
+        prop_assert!(calculate_expected_attempts(&pattern, case_sensitive, range, ss58_prefix) >= 1);
+    }
+
+    #[test]
+    fn longer_pattern_is_never_easier(
+        pattern in base58_pattern(1..=7),
+        extra in base58_pattern(1..=1),
+        case_sensitive in any::<bool>(),
+        start in 0usize..=20,
+        span in 0usize..=20,
+        ss58_prefix in known_prefix(),
+    ) {
+        let longer = format!("{pattern}{extra}");
+        let range = Some((start, start + span));
+
+        let shorter_attempts = calculate_expected_attempts(&pattern, case_sensitive, range, ss58_prefix);
+        let longer_attempts = calculate_expected_attempts(&longer, case_sensitive, range, ss58_prefix);
+        prop_assert!(
+            longer_attempts >= shorter_attempts,
+            "'{}' needs {} attempts but '{}' only {} over {:?}",
+            pattern, shorter_attempts, longer, longer_attempts, range
+        );
+    }
+
+    #[test]
+    fn wider_within_is_never_harder(
+        pattern in base58_pattern(1..=8),
+        case_sensitive in any::<bool>(),
+        suffix in any::<bool>(),
+        within in 0usize..=48,
+        ss58_prefix in known_prefix(),
+    ) {
+        let narrow = search_range(&pattern, case_sensitive, suffix, within, ss58_prefix);
+        let wide = search_range(&pattern, case_sensitive, suffix, within + 1, ss58_prefix);
+
+        let narrow_attempts = calculate_expected_attempts(&pattern, case_sensitive, narrow, ss58_prefix);
+        let wide_attempts = calculate_expected_attempts(&pattern, case_sensitive, wide, ss58_prefix);
+        prop_assert!(
+            wide_attempts <= narrow_attempts,
+            "'{}' within {} needs {} attempts but within {} needs {}",
+            pattern, within + 1, wide_attempts, within, narrow_attempts
+        );
+    }
+}
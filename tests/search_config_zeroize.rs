@@ -0,0 +1,47 @@
+//! Dropping an `OptimizedSearchConfig` must wipe the search term
+//!
+//! Reading memory after it is freed is undefined behaviour, so this binary installs a
+//! global allocator that inspects the pattern's allocation as it is handed back.
+
+use autoseed::OptimizedSearchConfig;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Allocator that records whether the block at `WATCHED` was all zeros when freed
+struct InspectingAllocator;
+
+static WATCHED: AtomicUsize = AtomicUsize::new(0);
+static FREED: AtomicBool = AtomicBool::new(false);
+static FREED_ZEROED: AtomicBool = AtomicBool::new(false);
+
+unsafe impl GlobalAlloc for InspectingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ptr as usize == WATCHED.load(Ordering::SeqCst) {
+            // SAFETY: `ptr` is still a live allocation of `layout.size()` bytes here
+            let bytes = unsafe { std::slice::from_raw_parts(ptr, layout.size()) };
+            FREED_ZEROED.store(bytes.iter().all(|&b| b == 0), Ordering::SeqCst);
+            FREED.store(true, Ordering::SeqCst);
+        }
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: InspectingAllocator = InspectingAllocator;
+
+#[test]
+fn test_pattern_zeroed_on_drop() {
+    let config = OptimizedSearchConfig::new("secretterm", false, false, true, 49);
+    assert_eq!(config.pattern, "secretterm");
+    WATCHED.store(config.pattern.as_ptr() as usize, Ordering::SeqCst);
+
+    drop(config);
+
+    WATCHED.store(0, Ordering::SeqCst);
+    assert!(FREED.load(Ordering::SeqCst), "pattern allocation was not freed");
+    assert!(FREED_ZEROED.load(Ordering::SeqCst), "pattern was freed without being zeroed");
+}
@@ -0,0 +1,64 @@
+//! Compatibility checks for the Polkadot JSON wallet format
+//!
+//! Wallets written by `wallet::json` must decrypt and carry a public key that matches
+//! the address stored alongside it, the same check `subkey`/polkadot.js perform on import.
+
+use autoseed::runner::VanityResult;
+use autoseed::wallet::{Kdf, generate_hex_seed, hex_to_address_with_prefix, save_wallet_json, seed_to_hex_string, verify_wallet_json};
+
+const TEST_PASSWORD: &str = "testpass123";
+
+/// Seed 0x00..01 encrypted with `TEST_PASSWORD`
+const GOLDEN_WALLET: &str = include_str!("fixtures/golden_wallet.json");
+
+#[test]
+fn test_generated_wallet_round_trip() {
+    let seed = generate_hex_seed();
+    let result = VanityResult {
+        address: hex_to_address_with_prefix(&seed, 6094),
+        secret: seed_to_hex_string(&seed),
+        matches: 0,
+        offset: 0,
+        attempts: 1,
+        expected_attempts: 0,
+        ss58_prefix: 6094,
+        network_name: "Autonomys".to_string(),
+        match_detail: Vec::new(),
+    };
+
+    let output_dir = std::env::temp_dir().join(format!("autoseed_subkey_compat_{}", std::process::id()));
+    std::fs::create_dir_all(&output_dir).unwrap();
+    let output_dir_str = output_dir.to_str().unwrap();
+
+    save_wallet_json(&result, TEST_PASSWORD, "test", output_dir_str, &result.address, Kdf::default(), None).unwrap();
+    let wallet_json = std::fs::read_to_string(output_dir.join(format!("{}.json", result.address))).unwrap();
+
+    assert_eq!(verify_wallet_json(&wallet_json, TEST_PASSWORD), Ok(true));
+
+    // The JSON address is always encoded with the generic Substrate prefix (42)
+    let wallet: serde_json::Value = serde_json::from_str(&wallet_json).unwrap();
+    assert_eq!(wallet["address"], hex_to_address_with_prefix(&seed, 42));
+
+    // A wrong password must not decrypt
+    assert!(verify_wallet_json(&wallet_json, "wrong password").is_err());
+
+    std::fs::remove_dir_all(&output_dir).ok();
+}
+
+#[test]
+fn test_golden_wallet() {
+    assert_eq!(verify_wallet_json(GOLDEN_WALLET, TEST_PASSWORD), Ok(true));
+
+    let mut seed = [0u8; 32];
+    seed[31] = 1;
+    let wallet: serde_json::Value = serde_json::from_str(GOLDEN_WALLET).unwrap();
+    assert_eq!(wallet["address"], hex_to_address_with_prefix(&seed, 42));
+}
+
+#[test]
+fn test_golden_wallet_tampered_address() {
+    let mut wallet: serde_json::Value = serde_json::from_str(GOLDEN_WALLET).unwrap();
+    wallet["address"] = serde_json::Value::String(hex_to_address_with_prefix(&[7u8; 32], 42));
+
+    assert_eq!(verify_wallet_json(&wallet.to_string(), TEST_PASSWORD), Ok(false));
+}
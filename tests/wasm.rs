@@ -0,0 +1,69 @@
+//! Browser tests for the `wasm` bindings, run with
+//! `wasm-pack test --headless --chrome --features wasm --test wasm`
+
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use autoseed::wasm::{wasm_generate_address, wasm_search_pattern};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn generate_address_matches_native_derivation() {
+    let seed = [42u8; 32];
+    let address = wasm_generate_address(&hex::encode(seed), 6094);
+
+    assert_eq!(address, autoseed::hex_to_address_with_prefix(&seed, 6094));
+    assert!(wasm_search_pattern(&address, "su", true));
+}
+
+#[wasm_bindgen_test]
+fn single_threaded_runner_finds_a_wallet() {
+    let params = autoseed::SearchParams {
+        hex_mode: true,
+        within: 49,
+        suffix: false,
+        anywhere: true,
+        threads: 1,
+        ..autoseed::SearchParams::new("a", 42)
+    };
+    let (results, stats) = autoseed::generate_vanity_addresses(
+        &params,
+        |_progress| {},
+        |_result| {},
+    );
+
+    assert_eq!(results.len(), 1);
+    assert!(stats.total_attempts >= 1);
+}
+
+#[wasm_bindgen_test]
+fn single_threaded_runner_searches_until_stopped() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let params = autoseed::SearchParams {
+        count: 0, // unlimited: only `stop` ends it
+        hex_mode: true,
+        within: 49,
+        suffix: false,
+        anywhere: true,
+        threads: 1,
+        stop: Some(stop.clone()),
+        ..autoseed::SearchParams::new("a", 42)
+    };
+    let mut found = 0;
+    let (results, _stats) = autoseed::generate_vanity_addresses(
+        &params,
+        |_progress| {},
+        |_result| {
+            found += 1;
+            if found == 3 {
+                stop.store(true, Ordering::Relaxed);
+            }
+        },
+    );
+
+    assert_eq!(results.len(), 3);
+}